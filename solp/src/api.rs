@@ -1,15 +1,25 @@
-use std::collections::{BTreeSet, HashMap, HashSet};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Component, Path, PathBuf, MAIN_SEPARATOR, MAIN_SEPARATOR_STR};
 
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 
-use crate::{ast::Sol, msbuild};
+use crate::{
+    ast::{ProjectConfigTag, Sol},
+    msbuild,
+};
+
+const NESTED_PROJECTS: &str = "NestedProjects";
+const HEADER_PREFIX: &str = "Microsoft Visual Studio Solution File, Format Version ";
 
 /// Represents Visual Studio solution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Solution<'a> {
     /// Full path to solution file
     pub path: &'a str,
+    /// Verbatim first line of solution file i.e. `Microsoft Visual Studio Solution File, Format Version 12.00`
+    pub header: &'a str,
     /// Solution format
     pub format: &'a str,
     /// Solution product like Visual Studio 15 etc
@@ -23,6 +33,51 @@ pub struct Solution<'a> {
     /// Dangling (projects with such ids not exist in the solution file) projects configurations inside solution
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dangling_project_configurations: Option<Vec<String>>,
+    /// Legacy TFS source control bindings if solution still has them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_control_bindings: Option<SourceControlBindings<'a>>,
+    /// Names and verbatim source text of all global sections
+    #[serde(skip)]
+    global_sections: Vec<(Vec<&'a str>, &'a str)>,
+    /// Whether any line of solution file is indented using spaces instead of tabs.
+    /// Set only if indentation detection requested while parsing
+    #[serde(skip)]
+    pub space_indented: bool,
+    /// Whether solution file starts with UTF-8 signature i.e. BOM that Visual Studio writes
+    #[serde(skip)]
+    pub utf8_bom: bool,
+}
+
+/// Team Foundation Server source control bindings i.e. `TeamFoundationVersionControl` section content
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SourceControlBindings<'a> {
+    /// Number of bound projects declared by `SccNumberOfProjects`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub number_of_projects: Option<usize>,
+    /// Source control provider GUID declared by `SccEnterpriseProvider`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<&'a str>,
+    /// Server URL declared by `SccTeamFoundationServer`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server: Option<&'a str>,
+    /// Per-project bindings ordered by their index
+    pub projects: Vec<SourceControlProject<'a>>,
+}
+
+/// Single project binding i.e. all `Scc*N` keys with the same index N
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SourceControlProject<'a> {
+    /// Index N of the binding keys. Index 0 usually binds solution itself
+    pub index: usize,
+    /// Bound project path declared by `SccProjectUniqueNameN`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unique_name: Option<&'a str>,
+    /// Bound project name declared by `SccProjectNameN`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    /// Local path declared by `SccLocalPathN`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_path: Option<&'a str>,
 }
 
 /// Represents [`Solution`] version. NOTE: [`Solution`] may have several versions.
@@ -46,6 +101,36 @@ pub struct Project<'a> {
     pub items: Option<Vec<&'a str>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub depends_from: Option<Vec<&'a str>>,
+    /// 1-based line of the project declaration. Set only if positions requested while parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// 1-based column of the project declaration. Set only if positions requested while parsing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+}
+
+/// Result of resolving project path against solution directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathResolution<'a> {
+    /// Project file exists at the path
+    Exists(PathBuf),
+    /// Project file expected at the path doesn't exist
+    Missing(PathBuf),
+    /// Project is referenced by URI like web sites hosted by IIS
+    Uri(&'a str),
+    /// Project has no file at all like solution folders
+    Unresolvable,
+}
+
+impl PathResolution<'_> {
+    /// Local path of the file whether it exists or not. None for URIs and unresolvable paths
+    #[must_use]
+    pub fn into_local_path(self) -> Option<PathBuf> {
+        match self {
+            PathResolution::Exists(path) | PathResolution::Missing(path) => Some(path),
+            PathResolution::Uri(_) | PathResolution::Unresolvable => None,
+        }
+    }
 }
 
 /// Represents solution configuration/platform pair
@@ -66,8 +151,14 @@ pub struct ProjectConfiguration<'a> {
     pub solution_configuration: &'a str,
     /// Platform i.e. Any CPU, Win32, x86 etc.
     pub platform: &'a str,
+    /// Project platform the solution platform is mapped to
+    #[serde(skip)]
+    pub project_platform: &'a str,
     /// Configuration tag
     pub tags: Vec<Tag>,
+    /// Whether configuration is selected by `ActiveCfg` line
+    #[serde(skip)]
+    pub active: bool,
 }
 
 /// Represents project configuration tag
@@ -80,21 +171,109 @@ pub enum Tag {
     Deploy,
 }
 
+impl From<Tag> for ProjectConfigTag {
+    fn from(tag: Tag) -> Self {
+        match tag {
+            Tag::Build => ProjectConfigTag::Build,
+            Tag::Deploy => ProjectConfigTag::Deploy,
+        }
+    }
+}
+
+impl TryFrom<ProjectConfigTag> for Tag {
+    type Error = ProjectConfigTag;
+
+    /// `ActiveCfg` line only selects project configuration so it has no [`Tag`] counterpart
+    /// and it's returned back as error
+    fn try_from(tag: ProjectConfigTag) -> Result<Self, Self::Error> {
+        match tag {
+            ProjectConfigTag::Build => Ok(Tag::Build),
+            ProjectConfigTag::Deploy => Ok(Tag::Deploy),
+            ProjectConfigTag::ActiveCfg => Err(tag),
+        }
+    }
+}
+
+impl<'a> SourceControlBindings<'a> {
+    /// Creates bindings from `TeamFoundationVersionControl` section key/value pairs.
+    /// Returns `None` if there are no such pairs
+    fn from_pairs(pairs: &[(&'a str, &'a str)]) -> Option<Self> {
+        if pairs.is_empty() {
+            return None;
+        }
+        let mut bindings = Self::default();
+        let mut projects: BTreeMap<usize, SourceControlProject<'a>> = BTreeMap::new();
+        for &(key, value) in pairs {
+            match key {
+                "SccNumberOfProjects" => bindings.number_of_projects = value.parse().ok(),
+                "SccEnterpriseProvider" => bindings.provider = Some(value),
+                "SccTeamFoundationServer" => bindings.server = Some(value),
+                _ => {
+                    // Per-project keys end with binding index
+                    let name = key.trim_end_matches(|c: char| c.is_ascii_digit());
+                    let Ok(index) = key[name.len()..].parse() else {
+                        continue;
+                    };
+                    let project = projects.entry(index).or_insert(SourceControlProject {
+                        index,
+                        ..Default::default()
+                    });
+                    match name {
+                        "SccProjectUniqueName" => project.unique_name = Some(value),
+                        "SccProjectName" => project.name = Some(value),
+                        "SccLocalPath" => project.local_path = Some(value),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        bindings.projects = projects.into_values().collect();
+        Some(bindings)
+    }
+}
+
+impl ProjectConfiguration<'_> {
+    /// Whether the project is built when solution configuration is built i.e. it has `Build.0` line.
+    /// Configuration with `ActiveCfg` line only is selected but not built
+    #[must_use]
+    pub fn is_buildable(&self) -> bool {
+        self.tags.contains(&Tag::Build)
+    }
+
+    /// Whether the project is deployed when solution configuration is deployed i.e. it has `Deploy.0` line
+    #[must_use]
+    pub fn is_deployable(&self) -> bool {
+        self.tags.contains(&Tag::Deploy)
+    }
+}
+
 impl<'a> Solution<'a> {
     /// Creates new [`Solution`] instance from [`ast::Sol`] instance
     #[must_use]
     pub fn from(solution: &Sol<'a>) -> Self {
         Self {
             path: solution.path,
+            header: solution.header,
             format: solution.format,
             product: solution.product,
             versions: Self::versions(solution),
             projects: Self::projects(solution),
             configurations: Self::configurations(solution),
             dangling_project_configurations: Self::danglings(solution),
+            source_control_bindings: SourceControlBindings::from_pairs(&solution.source_control),
+            global_sections: solution.global_sections.clone(),
+            space_indented: solution.space_indented,
+            utf8_bom: false,
         }
     }
 
+    /// Whether solution file starts with `Microsoft Visual Studio Solution File, Format Version` line.
+    /// Files with other first line are likely not solutions at all
+    #[must_use]
+    pub fn has_valid_header(&self) -> bool {
+        self.header.starts_with(HEADER_PREFIX)
+    }
+
     /// Iterates all but solution folder projects inside [`Solution`]
     pub fn iterate_projects(&'a self) -> impl Iterator<Item = &'a Project<'a>> {
         self.projects
@@ -108,6 +287,357 @@ impl<'a> Solution<'a> {
             .filter(|p| !msbuild::is_web_site_project(p.type_id))
     }
 
+    /// Returns paths of all but solution folder projects with separators normalized to the host OS ones.
+    /// Website projects paths are returned unchanged because they are usually URIs
+    #[must_use]
+    pub fn project_paths(&self) -> Vec<Cow<'a, str>> {
+        self.projects
+            .iter()
+            .filter(|p| !msbuild::is_solution_folder(p.type_id))
+            .map(|p| {
+                if msbuild::is_web_site_project(p.type_id) {
+                    Cow::Borrowed(p.path_or_uri)
+                } else {
+                    normalize_separators(p.path_or_uri)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns projects that don't depend on any other solution project i.e. ones that can be built first.
+    /// Dependencies on projects that aren't in the solution are ignored
+    #[must_use]
+    pub fn root_projects(&'a self) -> Vec<&'a Project<'a>> {
+        let ids = self
+            .iterate_projects()
+            .map(Project::guid_normalized)
+            .collect::<HashSet<String>>();
+        self.iterate_projects()
+            .filter(|p| {
+                !p.depends_from
+                    .iter()
+                    .flatten()
+                    .any(|d| ids.contains(&normalize_guid(d)))
+            })
+            .collect()
+    }
+
+    /// Returns projects that no other solution project depends on i.e. top level build targets
+    #[must_use]
+    pub fn leaf_projects(&'a self) -> Vec<&'a Project<'a>> {
+        let dependencies = self
+            .iterate_projects()
+            .flat_map(|p| p.depends_from.iter().flatten())
+            .map(|d| normalize_guid(d))
+            .collect::<HashSet<String>>();
+        self.iterate_projects()
+            .filter(|p| !dependencies.contains(&p.guid_normalized()))
+            .collect()
+    }
+
+    /// Returns the number of configuration entries i.e. `ActiveCfg` lines and all their tags
+    /// of every project that has configurations. Result is sorted by count descending
+    #[must_use]
+    pub fn configuration_count_per_project(&self) -> Vec<(&Project<'a>, usize)> {
+        self.projects
+            .iter()
+            .filter_map(|p| {
+                let count = p
+                    .configurations
+                    .as_ref()?
+                    .iter()
+                    .map(|c| 1 + c.tags.len())
+                    .sum();
+                Some((p, count))
+            })
+            .sorted_by(|(_, a), (_, b)| b.cmp(a))
+            .collect()
+    }
+
+    /// Returns platforms every solution configuration is defined for
+    /// i.e. groups `SolutionConfigurationPlatforms` entries by configuration name
+    #[must_use]
+    pub fn platforms_per_configuration(&self) -> BTreeMap<&'a str, BTreeSet<&'a str>> {
+        self.configurations
+            .iter()
+            .fold(BTreeMap::new(), |mut acc: BTreeMap<_, BTreeSet<_>>, c| {
+                acc.entry(c.configuration).or_default().insert(c.platform);
+                acc
+            })
+    }
+
+    /// Returns solution configurations with the configuration name and platform specified.
+    /// `None` matches any configuration or platform. Names compared case-insensitive
+    #[must_use]
+    pub fn configurations_matching(
+        &self,
+        configuration: Option<&str>,
+        platform: Option<&str>,
+    ) -> Vec<&SolutionConfiguration<'a>> {
+        self.configurations
+            .iter()
+            .filter(|c| configuration.is_none_or(|n| c.configuration.eq_ignore_ascii_case(n)))
+            .filter(|c| platform.is_none_or(|p| c.platform.eq_ignore_ascii_case(p)))
+            .collect()
+    }
+
+    /// Returns every GUID referenced anywhere in the solution i.e. project ids,
+    /// project type ids, dependencies, project configuration ids and nested projects ids.
+    /// GUIDs are normalized using [`normalize_guid`]
+    #[must_use]
+    pub fn all_guids(&self) -> HashSet<String> {
+        let projects = self.projects.iter().flat_map(|p| {
+            [p.id, p.type_id]
+                .into_iter()
+                .chain(p.depends_from.iter().flatten().copied())
+        });
+        let danglings = self
+            .dangling_project_configurations
+            .iter()
+            .flatten()
+            .map(String::as_str);
+        let nested = self
+            .nested_pairs()
+            .flat_map(|(child, parent)| [child, parent]);
+
+        projects
+            .chain(danglings)
+            .chain(nested)
+            .map(normalize_guid)
+            .collect()
+    }
+
+    /// Returns original source text of the global section specified by name
+    /// i.e. all lines from `GlobalSection(name)` to `EndGlobalSection` inclusive.
+    /// Returns `None` if there is no such section or solution wasn't created by parsing
+    #[must_use]
+    pub fn raw_section(&self, name: &str) -> Option<&'a str> {
+        self.global_sections
+            .iter()
+            .find(|(names, _)| names.iter().any(|n| *n == name))
+            .map(|(_, raw)| *raw)
+    }
+
+    /// Iterates solution folders only
+    pub fn iterate_folders(&self) -> impl Iterator<Item = &Project<'a>> {
+        self.projects.iter().filter(|p| p.is_folder())
+    }
+
+    /// Iterates projects and folders nested directly into the folder with id specified
+    /// according to `NestedProjects` section. Ids compared normalized using [`normalize_guid`]
+    pub fn nested_children<'b>(
+        &'b self,
+        parent_id: &'b str,
+    ) -> impl Iterator<Item = &'b Project<'a>> + 'b {
+        let parent_id = normalize_guid(parent_id);
+        self.nested_pairs()
+            .filter(move |(_, parent)| normalize_guid(parent) == parent_id)
+            .filter_map(move |(child, _)| {
+                let child = normalize_guid(child);
+                self.projects.iter().find(|p| p.guid_normalized() == child)
+            })
+    }
+
+    /// Returns solution folders and projects hierarchy built from `NestedProjects` section
+    /// as plain text. Every item is on its own line indented by two spaces per nesting level,
+    /// folder names end with `/`. Top level items are kept in declaration order
+    /// and nested ones in `NestedProjects` section order. Items unreachable from top level ones
+    /// because of nesting cycles are printed at top level after them
+    #[must_use]
+    pub fn project_tree_text(&self) -> String {
+        let ids = self
+            .projects
+            .iter()
+            .map(Project::guid_normalized)
+            .collect::<HashSet<String>>();
+        let nested = self
+            .nested_pairs()
+            .filter(|(_, parent)| ids.contains(&normalize_guid(parent)))
+            .map(|(child, _)| normalize_guid(child))
+            .collect::<HashSet<String>>();
+        let mut text = String::new();
+        let mut visited = HashSet::new();
+        for root in self
+            .projects
+            .iter()
+            .filter(|p| !nested.contains(&p.guid_normalized()))
+        {
+            self.write_tree(root, 0, &mut visited, &mut text);
+        }
+        for unreachable in &self.projects {
+            self.write_tree(unreachable, 0, &mut visited, &mut text);
+        }
+        text
+    }
+
+    fn write_tree(
+        &self,
+        project: &Project<'a>,
+        depth: usize,
+        visited: &mut HashSet<String>,
+        text: &mut String,
+    ) {
+        if !visited.insert(project.guid_normalized()) {
+            return;
+        }
+        text.push_str(&"  ".repeat(depth));
+        text.push_str(project.name);
+        if project.is_folder() {
+            text.push('/');
+        }
+        text.push('\n');
+        for child in self.nested_children(project.id) {
+            self.write_tree(child, depth + 1, visited, text);
+        }
+    }
+
+    /// Returns solution folders items (like `.editorconfig`) that don't exist on disk.
+    /// Items are resolved relative to the solution directory specified, URIs are skipped
+    #[must_use]
+    pub fn unreferenced_solution_items(&self, solution_dir: &Path) -> Vec<&'a str> {
+        self.iterate_folders()
+            .flat_map(|f| f.items.iter().flatten())
+            .filter(|item| {
+                matches!(
+                    resolve_local_path(solution_dir, item),
+                    PathResolution::Missing(_)
+                )
+            })
+            .copied()
+            .collect()
+    }
+
+    /// Returns (child id, parent id) pairs from `NestedProjects` section
+    /// which child has no project with such id in the solution. Ids compared normalized using [`normalize_guid`]
+    #[must_use]
+    pub fn orphaned_nested_projects(&self) -> Vec<(&'a str, &'a str)> {
+        let ids = self
+            .projects
+            .iter()
+            .map(Project::guid_normalized)
+            .collect::<HashSet<String>>();
+        self.nested_pairs()
+            .filter(|(child, _)| !ids.contains(&normalize_guid(child)))
+            .collect()
+    }
+
+    /// Returns (child id, parent id) pairs from `NestedProjects` section
+    pub(crate) fn nested_pairs(&self) -> impl Iterator<Item = (&'a str, &'a str)> {
+        self.raw_section(NESTED_PROJECTS)
+            .into_iter()
+            .flat_map(str::lines)
+            .filter_map(|line| {
+                let (child, parent) = line.split_once('=')?;
+                Some((child.trim(), parent.trim()))
+            })
+            .filter(|(child, _)| child.starts_with('{'))
+    }
+
+    /// Iterates projects together with project configurations the solution configuration specified is mapped to.
+    /// Projects without such mapping are skipped
+    pub fn project_configs_for<'b>(
+        &'b self,
+        sol_conf: &'b SolutionConfiguration<'b>,
+    ) -> impl Iterator<Item = (&'b Project<'a>, &'b ProjectConfiguration<'a>)> + 'b {
+        self.projects.iter().flat_map(move |p| {
+            p.configurations
+                .iter()
+                .flatten()
+                .filter(move |c| {
+                    c.solution_configuration == sol_conf.configuration
+                        && c.platform == sol_conf.platform
+                })
+                .map(move |c| (p, c))
+        })
+    }
+
+    /// Whether solution has at least one project of the type specified
+    /// either by type id (GUID) or by human readable description like `C#`. Matching is case-insensitive
+    #[must_use]
+    pub fn contains_project_type(&self, id_or_name: &str) -> bool {
+        self.iterate_projects_of_type(id_or_name).next().is_some()
+    }
+
+    /// Iterates projects of the type specified either by type id (GUID)
+    /// or by human readable description like `C#`. Matching is case-insensitive
+    pub fn iterate_projects_of_type<'b>(
+        &'b self,
+        id_or_name: &'b str,
+    ) -> impl Iterator<Item = &'b Project<'a>> + 'b {
+        self.projects
+            .iter()
+            .filter(move |p| p.is_of_type(id_or_name))
+    }
+
+    /// Whether solutions have the same meaning i.e. the same projects (matched by id),
+    /// configurations and dependencies regardless of ordering, GUIDs case and formatting
+    #[must_use]
+    pub fn semantically_equal(&self, other: &Solution) -> bool {
+        self.semantic_differences(other).is_empty()
+    }
+
+    /// Lists human readable differences that make solutions not semantically equal.
+    /// Empty list means solutions are semantically equal
+    #[must_use]
+    pub fn semantic_differences(&self, other: &Solution) -> Vec<String> {
+        let mut differences = vec![];
+
+        for c in self.configurations.difference(&other.configurations) {
+            differences.push(format!(
+                "configuration {}|{} removed",
+                c.configuration, c.platform
+            ));
+        }
+        for c in other.configurations.difference(&self.configurations) {
+            differences.push(format!(
+                "configuration {}|{} added",
+                c.configuration, c.platform
+            ));
+        }
+
+        let ours = projects_by_id(self);
+        let theirs = projects_by_id(other);
+        for (id, project) in &ours {
+            let Some(other_project) = theirs.get(id) else {
+                differences.push(format!("project {} {id} removed", project.name));
+                continue;
+            };
+            if normalize_guid(project.type_id) != normalize_guid(other_project.type_id) {
+                differences.push(format!("project {} {id} type changed", project.name));
+            }
+            if project.name != other_project.name {
+                differences.push(format!(
+                    "project {id} renamed from {} to {}",
+                    project.name, other_project.name
+                ));
+            }
+            if project.path_or_uri != other_project.path_or_uri {
+                differences.push(format!(
+                    "project {} {id} path changed from {} to {}",
+                    project.name, project.path_or_uri, other_project.path_or_uri
+                ));
+            }
+            if project.dependencies() != other_project.dependencies() {
+                differences.push(format!(
+                    "project {} {id} dependencies changed",
+                    project.name
+                ));
+            }
+            if project.configuration_set() != other_project.configuration_set() {
+                differences.push(format!(
+                    "project {} {id} configurations changed",
+                    project.name
+                ));
+            }
+        }
+        for (id, project) in &theirs {
+            if !ours.contains_key(id) {
+                differences.push(format!("project {} {id} added", project.name));
+            }
+        }
+        differences
+    }
+
     fn versions(solution: &Sol<'a>) -> Vec<Version<'a>> {
         solution
             .versions
@@ -131,30 +661,38 @@ impl<'a> Solution<'a> {
     }
 
     fn projects(solution: &Sol<'a>) -> Vec<Project<'a>> {
+        // Configuration lines of the same project may be scattered across the section
+        // so that all of them are merged by project id first
         let project_configs = solution
             .project_configs
             .iter()
-            .map(|c| {
+            .flat_map(|c| c.configs.iter().map(move |pc| (c.project_id, pc)))
+            .into_group_map()
+            .into_iter()
+            .map(|(id, configs)| {
                 (
-                    c.project_id,
-                    c.configs
-                        .iter()
+                    id,
+                    configs
+                        .into_iter()
                         .into_grouping_map_by(|pc| {
-                            (pc.project_config, pc.solution_config, pc.platform)
+                            (
+                                pc.project_config,
+                                pc.solution_config,
+                                pc.platform,
+                                pc.project_platform,
+                            )
                         })
                         .fold(
                             ProjectConfiguration::default(),
-                            |mut pc, (p, s, plat), val| {
+                            |mut pc, (p, s, plat, project_plat), val| {
                                 pc.configuration = p;
                                 pc.solution_configuration = s;
                                 pc.platform = plat;
-                                match val.tag {
-                                    crate::ast::ProjectConfigTag::ActiveCfg => {}
-                                    crate::ast::ProjectConfigTag::Build => pc.tags.push(Tag::Build),
-                                    crate::ast::ProjectConfigTag::Deploy => {
-                                        pc.tags.push(Tag::Deploy);
-                                    }
-                                };
+                                pc.project_platform = project_plat;
+                                match Tag::try_from(val.tag.clone()) {
+                                    Ok(tag) => pc.tags.push(tag),
+                                    Err(_) => pc.active = true,
+                                }
                                 pc
                             },
                         )
@@ -186,6 +724,8 @@ impl<'a> Solution<'a> {
                     configurations: project_configs.get(p.id).cloned(),
                     items,
                     depends_from,
+                    line: None,
+                    column: None,
                 }
             })
             .collect()
@@ -196,13 +736,13 @@ impl<'a> Solution<'a> {
             .projects
             .iter()
             .filter(|p| !msbuild::is_solution_folder(p.type_id))
-            .map(|p| p.id.to_uppercase())
+            .map(|p| normalize_guid(p.id))
             .collect();
 
         let dangilings = solution
             .project_configs
             .iter()
-            .map(|p| p.project_id.to_uppercase())
+            .map(|p| normalize_guid(p.project_id))
             .collect::<HashSet<String>>()
             .difference(&project_ids)
             .cloned()
@@ -214,3 +754,232 @@ impl<'a> Solution<'a> {
         }
     }
 }
+
+impl<'a> Project<'a> {
+    /// Shows whether project is a test project judging by its type id only.
+    /// Projects that became test ones because of package references
+    /// cannot be recognized without reading project file
+    #[must_use]
+    pub fn is_test_project(&self) -> bool {
+        msbuild::is_test_project(self.type_id)
+    }
+
+    /// Shows whether project is a solution folder
+    #[must_use]
+    pub fn is_folder(&self) -> bool {
+        msbuild::is_solution_folder(self.type_id)
+    }
+
+    /// Returns project id in canonical `{UPPERCASE}` form so that ids
+    /// written using different case or without braces can be compared
+    #[must_use]
+    pub fn guid_normalized(&self) -> String {
+        normalize_guid(self.id)
+    }
+
+    /// Finds solution configurations where `ActiveCfg` line selects one project configuration
+    /// but `Build.0` line builds another one. Returns (solution configuration, platform,
+    /// active project configuration, built project configuration) tuples
+    #[must_use]
+    pub fn diverging_build_configurations(&self) -> Vec<(&'a str, &'a str, &'a str, &'a str)> {
+        let configurations = self.configurations.iter().flatten();
+        configurations
+            .clone()
+            .filter(|c| c.active && !c.is_buildable())
+            .flat_map(|active| {
+                configurations
+                    .clone()
+                    .filter(move |c| {
+                        !c.active
+                            && c.is_buildable()
+                            && c.solution_configuration == active.solution_configuration
+                            && c.platform == active.platform
+                    })
+                    .map(move |built| {
+                        (
+                            active.solution_configuration,
+                            active.platform,
+                            active.configuration,
+                            built.configuration,
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Whether project directly depends on the project with id specified.
+    /// Ids compared normalized using [`normalize_guid`]
+    #[must_use]
+    pub fn depends_on(&self, other_id: &str) -> bool {
+        let other_id = normalize_guid(other_id);
+        self.depends_from
+            .iter()
+            .flatten()
+            .any(|id| normalize_guid(id) == other_id)
+    }
+
+    /// Resolves project path relative to the solution directory specified
+    /// and checks whether project file exists. See [`resolve_local_path`] for details
+    #[must_use]
+    pub fn resolve_existing(&self, solution_dir: &Path) -> PathResolution<'a> {
+        if msbuild::is_solution_folder(self.type_id) || self.path_or_uri.trim().is_empty() {
+            return PathResolution::Unresolvable;
+        }
+        resolve_local_path(solution_dir, self.path_or_uri)
+    }
+
+    fn dependencies(&self) -> BTreeSet<String> {
+        self.depends_from
+            .iter()
+            .flatten()
+            .map(|id| normalize_guid(id))
+            .collect()
+    }
+
+    /// Project configurations with tags order ignored
+    fn configuration_set(&self) -> BTreeSet<(&str, &str, &str, &str, BTreeSet<&Tag>)> {
+        self.configurations
+            .iter()
+            .flatten()
+            .map(|c| {
+                (
+                    c.configuration,
+                    c.solution_configuration,
+                    c.platform,
+                    c.project_platform,
+                    c.tags.iter().collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn is_of_type(&self, id_or_name: &str) -> bool {
+        normalize_guid(self.type_id) == normalize_guid(id_or_name)
+            || self.type_description.eq_ignore_ascii_case(id_or_name)
+    }
+}
+
+/// Maps solution projects by their normalized ids
+fn projects_by_id<'s, 'a>(solution: &'s Solution<'a>) -> BTreeMap<String, &'s Project<'a>> {
+    solution
+        .projects
+        .iter()
+        .map(|p| (p.guid_normalized(), p))
+        .collect()
+}
+
+/// Converts GUID into canonical `{UPPERCASE}` form adding braces if they're missing
+#[must_use]
+pub fn normalize_guid(guid: &str) -> String {
+    let guid = guid.trim().trim_start_matches('{').trim_end_matches('}');
+    format!("{{{}}}", guid.to_uppercase())
+}
+
+/// Whether path looks like URI i.e. starts with `scheme://`
+fn is_uri(path: &str) -> bool {
+    path.split_once("://").is_some_and(|(scheme, _)| {
+        scheme.len() > 1
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
+
+/// Joins path relative to the solution directory specified.
+/// Both `\` and `/` are treated as separators because generators like `CMake` use forward slashes.
+/// Absolute, rooted (`\x`, UNC) and drive prefixed (`C:x`) paths are kept as is
+#[must_use]
+pub fn local_path(solution_dir: &Path, path: &str) -> PathBuf {
+    let normalized = normalize_separators(path);
+    let candidate = Path::new(normalized.as_ref());
+    let rooted =
+        candidate.has_root() || matches!(candidate.components().next(), Some(Component::Prefix(_)));
+    if rooted {
+        PathBuf::from(normalized.as_ref())
+    } else {
+        normalized
+            .split(MAIN_SEPARATOR)
+            .filter(|s| !s.is_empty())
+            .fold(solution_dir.to_path_buf(), |pb, s| pb.join(s))
+    }
+}
+
+/// Resolves path relative to the solution directory specified and checks whether file exists.
+/// Both `\` and `/` are treated as separators. Generators may write URL-escaped paths
+/// like `My%20Project\a.csproj` so decoded path is used if only it exists.
+/// Genuine `%` in file names is kept as is
+#[must_use]
+pub fn resolve_local_path<'p>(solution_dir: &Path, path: &'p str) -> PathResolution<'p> {
+    if is_uri(path) {
+        return PathResolution::Uri(path);
+    }
+    let local = local_path(solution_dir, path);
+    if local.canonicalize().is_ok() {
+        return PathResolution::Exists(local);
+    }
+    match percent_decode(path).map(|decoded| local_path(solution_dir, &decoded)) {
+        Some(decoded) if decoded.canonicalize().is_ok() => PathResolution::Exists(decoded),
+        _ => PathResolution::Missing(local),
+    }
+}
+
+/// Decodes `%XX` escape sequences. Returns None if there are no valid escapes
+/// or decoded bytes aren't valid UTF-8
+fn percent_decode(s: &str) -> Option<String> {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut escaped = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        if let Some(b) = hex {
+            decoded.push(b);
+            escaped = true;
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    if escaped {
+        String::from_utf8(decoded).ok()
+    } else {
+        None
+    }
+}
+
+/// Replaces separators that aren't native for the host OS with native ones
+fn normalize_separators(path: &str) -> Cow<'_, str> {
+    let foreign = if MAIN_SEPARATOR == '/' { '\\' } else { '/' };
+    if path.contains(foreign) {
+        Cow::Owned(path.replace(foreign, MAIN_SEPARATOR_STR))
+    } else {
+        Cow::Borrowed(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("My%20Project", Some("My Project"))]
+    #[case("a%2fb%C3%A9", Some("a/bé"))]
+    #[case("100%", None)]
+    #[case("100%zz", None)]
+    #[case("plain", None)]
+    #[case("%FF", None)]
+    #[trace]
+    fn percent_decode_tests(#[case] s: &str, #[case] expected: Option<&str>) {
+        // Act
+        let actual = percent_decode(s);
+
+        // Assert
+        assert_eq!(actual.as_deref(), expected);
+    }
+}
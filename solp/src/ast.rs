@@ -18,11 +18,14 @@ const DEPLOY_TAG: &str = ".Deploy.0";
 pub enum Node<'a> {
     Comment(&'a str),
     Version(&'a str, &'a str),
-    FirstLine(&'a str),
+    /// Verbatim first line text and format version
+    FirstLine(&'a str, &'a str),
     Global(Vec<Node<'a>>),
-    Project(Box<Node<'a>>, Vec<Node<'a>>),
+    /// Project head, sections and byte offset of the project declaration
+    Project(Box<Node<'a>>, Vec<Node<'a>>, usize),
     ProjectBegin(&'a str, &'a str, &'a str, &'a str),
-    Section(Box<Node<'a>>, Vec<Node<'a>>),
+    /// Section head, content and verbatim source text of the whole section
+    Section(Box<Node<'a>>, Vec<Node<'a>>, &'a str),
     SectionBegin(Vec<&'a str>, &'a str),
     SectionContent(&'a str, &'a str),
     Solution(Box<Node<'a>>, Vec<Node<'a>>),
@@ -34,12 +37,20 @@ pub struct Sol<'a> {
     /// Path to solution file. Maybe empty string
     /// because solution can be parsed using memory data.
     pub path: &'a str,
+    /// Verbatim first line i.e. `Microsoft Visual Studio Solution File, Format Version 12.00`
+    pub header: &'a str,
     pub format: &'a str,
     pub product: &'a str,
     pub projects: Vec<Prj<'a>>,
     pub versions: Vec<Ver<'a>>,
     pub solution_configs: Vec<Conf<'a>>,
     pub project_configs: Vec<PrjConfAggregate<'a>>,
+    /// Key/value pairs of `TeamFoundationVersionControl` section
+    pub source_control: Vec<(&'a str, &'a str)>,
+    /// Names and verbatim source text of all global sections
+    pub global_sections: Vec<(Vec<&'a str>, &'a str)>,
+    /// Whether any line is indented using spaces instead of tabs
+    pub space_indented: bool,
 }
 
 /// Solution version descriptor
@@ -73,6 +84,8 @@ pub struct Prj<'a> {
     pub path_or_uri: &'a str,
     pub items: Vec<&'a str>,
     pub depends_from: Vec<&'a str>,
+    /// Byte offset of the project declaration within parsed content
+    pub start: usize,
 }
 
 impl<'a> Prj<'a> {
@@ -154,6 +167,8 @@ pub struct PrjConf<'a> {
     pub solution_config: &'a str,
     pub project_config: &'a str,
     pub platform: &'a str,
+    /// Project platform the solution platform is mapped to
+    pub project_platform: &'a str,
     pub tag: ProjectConfigTag,
 }
 
@@ -230,6 +245,7 @@ impl<'a> PrjConfAggregate<'a> {
                 solution_config,
                 project_config: project_conf.config,
                 platform,
+                project_platform: project_conf.platform,
                 tag: define_tag(key),
             }
         })(key)
@@ -251,6 +267,7 @@ impl<'a> PrjConfAggregate<'a> {
             solution_config,
             project_config: project_conf.config,
             platform: project_conf.platform,
+            project_platform: project_conf.platform,
             tag: define_tag(key),
         })(key)
     }
@@ -433,10 +450,11 @@ mod tests {
     }
 
     #[rstest]
-    #[case("{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}.Release|.NET.Build.0", "Release|.NET", PrjConf { id: "{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}", solution_config: "Release", project_config: "Release", platform: ".NET", tag: ProjectConfigTag::Build })]
-    #[case("{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}.SolutionRelease|.NET.Build.0", "ProjectRelease|.NET", PrjConf { id: "{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}", solution_config: "SolutionRelease", project_config: "ProjectRelease", platform: ".NET", tag: ProjectConfigTag::Build })]
-    #[case("{60BB14A5-0871-4656-BC38-4F0958230F9A}.Debug|ARM.Deploy.0", "Debug|ARM", PrjConf { id: "{60BB14A5-0871-4656-BC38-4F0958230F9A}", solution_config: "Debug", project_config: "Debug", platform: "ARM", tag: ProjectConfigTag::Deploy })]
-    #[case("{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}.Release|.NET.ActiveCfg", "Release|.NET", PrjConf { id: "{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}", solution_config: "Release", project_config: "Release", platform: ".NET", tag: ProjectConfigTag::ActiveCfg })]
+    #[case("{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}.Release|.NET.Build.0", "Release|.NET", PrjConf { id: "{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}", solution_config: "Release", project_config: "Release", platform: ".NET", project_platform: ".NET", tag: ProjectConfigTag::Build })]
+    #[case("{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}.SolutionRelease|.NET.Build.0", "ProjectRelease|.NET", PrjConf { id: "{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}", solution_config: "SolutionRelease", project_config: "ProjectRelease", platform: ".NET", project_platform: ".NET", tag: ProjectConfigTag::Build })]
+    #[case("{60BB14A5-0871-4656-BC38-4F0958230F9A}.Debug|Any CPU.Build.0", "Debug|x64", PrjConf { id: "{60BB14A5-0871-4656-BC38-4F0958230F9A}", solution_config: "Debug", project_config: "Debug", platform: "Any CPU", project_platform: "x64", tag: ProjectConfigTag::Build })]
+    #[case("{60BB14A5-0871-4656-BC38-4F0958230F9A}.Debug|ARM.Deploy.0", "Debug|ARM", PrjConf { id: "{60BB14A5-0871-4656-BC38-4F0958230F9A}", solution_config: "Debug", project_config: "Debug", platform: "ARM", project_platform: "ARM", tag: ProjectConfigTag::Deploy })]
+    #[case("{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}.Release|.NET.ActiveCfg", "Release|.NET", PrjConf { id: "{7C2EF610-BCA0-4D1F-898A-DE9908E4970C}", solution_config: "Release", project_config: "Release", platform: ".NET", project_platform: ".NET", tag: ProjectConfigTag::ActiveCfg })]
     #[trace]
     fn project_configs_parse_project_configuration_platform_tests(
         #[case] k: &str,
@@ -454,7 +472,7 @@ mod tests {
     }
 
     #[rstest]
-    #[case("{5228E9CE-A216-422F-A5E6-58E95E2DD71D}.DLL Debug.ActiveCfg", "Debug|x64", PrjConf { id: "{5228E9CE-A216-422F-A5E6-58E95E2DD71D}", solution_config: "DLL Debug", project_config: "Debug", platform: "x64", tag: ProjectConfigTag::ActiveCfg })]
+    #[case("{5228E9CE-A216-422F-A5E6-58E95E2DD71D}.DLL Debug.ActiveCfg", "Debug|x64", PrjConf { id: "{5228E9CE-A216-422F-A5E6-58E95E2DD71D}", solution_config: "DLL Debug", project_config: "Debug", platform: "x64", project_platform: "x64", tag: ProjectConfigTag::ActiveCfg })]
     #[trace]
     fn project_configs_parse_project_configuration_tests(
         #[case] k: &str,
@@ -0,0 +1,657 @@
+//! Programmatic solution construction and emission of Visual Studio compatible solution text
+
+use crate::api::Solution;
+use crate::msbuild;
+
+const DEFAULT_FORMAT: &str = "12.00";
+const DEFAULT_PRODUCT: &str = "Visual Studio Version 17";
+const ID_CSHARP: &str = "{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}";
+const NEWLINE: &str = "\r\n";
+
+/// Builds solution from projects, configurations and dependencies added
+/// and emits it as solution file text.
+///
+/// # Example
+/// ```
+/// use solp::builder::SolutionBuilder;
+///
+/// let text = SolutionBuilder::new()
+///     .add_project("a", "a\\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}")
+///     .add_configuration("Debug", "Any CPU")
+///     .build_string();
+/// let solution = solp::parse_str(&text).unwrap();
+/// assert_eq!(1, solution.projects.len());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolutionBuilder {
+    format: String,
+    product: String,
+    versions: Vec<(String, String)>,
+    projects: Vec<BuilderProject>,
+    configurations: Vec<(String, String)>,
+    /// (child id, parent id) pairs of `NestedProjects` section
+    nested: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct BuilderProject {
+    type_id: String,
+    name: String,
+    path: String,
+    id: String,
+    dependencies: Vec<String>,
+    /// Solution items of solution folder
+    items: Vec<String>,
+    /// If there are no explicit configurations project is built
+    /// in every solution configuration using the same name
+    configurations: Option<Vec<BuilderConfiguration>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct BuilderConfiguration {
+    solution_configuration: String,
+    platform: String,
+    configuration: String,
+    project_platform: String,
+    build: bool,
+    deploy: bool,
+}
+
+impl SolutionBuilder {
+    /// Creates builder of an empty Visual Studio 2022 solution
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            format: DEFAULT_FORMAT.to_owned(),
+            product: DEFAULT_PRODUCT.to_owned(),
+            versions: vec![],
+            projects: vec![],
+            configurations: vec![],
+            nested: vec![],
+        }
+    }
+
+    /// Sets solution format i.e. `12.00` and product comment i.e. `Visual Studio Version 17`
+    #[must_use]
+    pub fn with_format(mut self, format: &str, product: &str) -> Self {
+        self.format = format.to_owned();
+        self.product = product.to_owned();
+        self
+    }
+
+    /// Adds version line like `VisualStudioVersion = 17.0.31903.59`
+    #[must_use]
+    pub fn add_version(mut self, name: &str, version: &str) -> Self {
+        self.versions.push((name.to_owned(), version.to_owned()));
+        self
+    }
+
+    /// Adds C# project
+    #[must_use]
+    pub fn add_project(self, name: &str, path: &str, id: &str) -> Self {
+        self.add_project_of_type(ID_CSHARP, name, path, id)
+    }
+
+    /// Adds project of the type specified by type id
+    #[must_use]
+    pub fn add_project_of_type(mut self, type_id: &str, name: &str, path: &str, id: &str) -> Self {
+        self.projects.push(BuilderProject {
+            type_id: type_id.to_owned(),
+            name: name.to_owned(),
+            path: path.to_owned(),
+            id: id.to_owned(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Makes project with id specified depend on another project.
+    /// Does nothing if there is no such project added
+    #[must_use]
+    pub fn add_dependency(mut self, project_id: &str, dependency_id: &str) -> Self {
+        if let Some(p) = self.project_mut(project_id) {
+            p.dependencies.push(dependency_id.to_owned());
+        }
+        self
+    }
+
+    /// Adds solution item like `.editorconfig` into solution folder with id specified.
+    /// Does nothing if there is no such project added
+    #[must_use]
+    pub fn add_solution_item(mut self, folder_id: &str, item: &str) -> Self {
+        if let Some(p) = self.project_mut(folder_id) {
+            p.items.push(item.to_owned());
+        }
+        self
+    }
+
+    /// Nests project or solution folder into solution folder with parent id specified
+    #[must_use]
+    pub fn add_nested_project(mut self, child_id: &str, parent_id: &str) -> Self {
+        self.nested
+            .push((child_id.to_owned(), parent_id.to_owned()));
+        self
+    }
+
+    /// Adds solution configuration/platform pair
+    #[must_use]
+    pub fn add_configuration(mut self, configuration: &str, platform: &str) -> Self {
+        self.configurations
+            .push((configuration.to_owned(), platform.to_owned()));
+        self
+    }
+
+    /// Maps solution configuration/platform pair to the project configuration explicitly.
+    /// Project is built for the same platform as solution one.
+    /// Projects without explicit mappings are built in every solution configuration
+    /// using the project configuration with the same name.
+    /// Does nothing if there is no such project added
+    #[must_use]
+    pub fn add_project_configuration(
+        mut self,
+        project_id: &str,
+        solution_configuration: &str,
+        platform: &str,
+        configuration: &str,
+        build: bool,
+        deploy: bool,
+    ) -> Self {
+        if let Some(p) = self.project_mut(project_id) {
+            p.configurations
+                .get_or_insert_with(Vec::new)
+                .push(BuilderConfiguration {
+                    solution_configuration: solution_configuration.to_owned(),
+                    platform: platform.to_owned(),
+                    configuration: configuration.to_owned(),
+                    project_platform: platform.to_owned(),
+                    build,
+                    deploy,
+                });
+        }
+        self
+    }
+
+    /// Emits solution text. Sections go in the same order as Visual Studio writes them
+    #[must_use]
+    pub fn build_string(&self) -> String {
+        let mut s = String::new();
+        let mut line = |text: &str| {
+            s.push_str(text);
+            s.push_str(NEWLINE);
+        };
+        line("");
+        line(&format!(
+            "Microsoft Visual Studio Solution File, Format Version {}",
+            self.format
+        ));
+        line(&format!("# {}", self.product));
+        for (name, version) in &self.versions {
+            line(&format!("{name} = {version}"));
+        }
+        for p in &self.projects {
+            line(&format!(
+                r#"Project("{}") = "{}", "{}", "{}""#,
+                p.type_id, p.name, p.path, p.id
+            ));
+            if !p.dependencies.is_empty() {
+                line("\tProjectSection(ProjectDependencies) = postProject");
+                for d in &p.dependencies {
+                    line(&format!("\t\t{d} = {d}"));
+                }
+                line("\tEndProjectSection");
+            }
+            if !p.items.is_empty() {
+                line("\tProjectSection(SolutionItems) = preProject");
+                for item in &p.items {
+                    line(&format!("\t\t{item} = {item}"));
+                }
+                line("\tEndProjectSection");
+            }
+            line("EndProject");
+        }
+        line("Global");
+        line("\tGlobalSection(SolutionConfigurationPlatforms) = preSolution");
+        for (configuration, platform) in &self.configurations {
+            line(&format!(
+                "\t\t{configuration}|{platform} = {configuration}|{platform}"
+            ));
+        }
+        line("\tEndGlobalSection");
+        line("\tGlobalSection(ProjectConfigurationPlatforms) = postSolution");
+        for p in self.projects.iter().filter(|p| !is_folder(p)) {
+            for c in self.project_configurations(p) {
+                let key = format!("\t\t{}.{}|{}", p.id, c.solution_configuration, c.platform);
+                let value = format!("{}|{}", c.configuration, c.project_platform);
+                line(&format!("{key}.ActiveCfg = {value}"));
+                if c.build {
+                    line(&format!("{key}.Build.0 = {value}"));
+                }
+                if c.deploy {
+                    line(&format!("{key}.Deploy.0 = {value}"));
+                }
+            }
+        }
+        line("\tEndGlobalSection");
+        line("\tGlobalSection(SolutionProperties) = preSolution");
+        line("\t\tHideSolutionNode = FALSE");
+        line("\tEndGlobalSection");
+        if !self.nested.is_empty() {
+            line("\tGlobalSection(NestedProjects) = preSolution");
+            for (child, parent) in &self.nested {
+                line(&format!("\t\t{child} = {parent}"));
+            }
+            line("\tEndGlobalSection");
+        }
+        line("EndGlobal");
+        s
+    }
+
+    fn project_mut(&mut self, id: &str) -> Option<&mut BuilderProject> {
+        self.projects
+            .iter_mut()
+            .find(|p| p.id.eq_ignore_ascii_case(id))
+    }
+
+    fn project_configurations(&self, project: &BuilderProject) -> Vec<BuilderConfiguration> {
+        project.configurations.clone().unwrap_or_else(|| {
+            self.configurations
+                .iter()
+                .map(|(configuration, platform)| BuilderConfiguration {
+                    solution_configuration: configuration.clone(),
+                    platform: platform.clone(),
+                    configuration: configuration.clone(),
+                    project_platform: platform.clone(),
+                    build: true,
+                    deploy: false,
+                })
+                .collect()
+        })
+    }
+}
+
+impl Default for SolutionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solution<'_> {
+    /// Creates [`SolutionBuilder`] filled with the solution's projects, dependencies,
+    /// solution items, versions, configurations and projects nesting so that it can be changed and emitted again.
+    /// Other global sections like `ExtensibilityGlobals` or source control bindings are dropped
+    #[must_use]
+    pub fn to_builder(&self) -> SolutionBuilder {
+        let product = if self.product.is_empty() {
+            DEFAULT_PRODUCT
+        } else {
+            self.product
+        };
+        let mut builder = SolutionBuilder::new().with_format(self.format, product);
+        for v in &self.versions {
+            builder = builder.add_version(v.name, v.version);
+        }
+        for c in &self.configurations {
+            builder = builder.add_configuration(c.configuration, c.platform);
+        }
+        for p in &self.projects {
+            builder = builder.add_project_of_type(p.type_id, p.name, p.path_or_uri, p.id);
+            for d in p.depends_from.iter().flatten() {
+                builder = builder.add_dependency(p.id, d);
+            }
+            for item in p.items.iter().flatten() {
+                builder = builder.add_solution_item(p.id, item);
+            }
+            if !msbuild::is_solution_folder(p.type_id) {
+                // Project without configurations must stay so instead of being built everywhere
+                if let Some(bp) = builder.project_mut(p.id) {
+                    bp.configurations = Some(vec![]);
+                }
+            }
+            if let Some(bp) = builder.project_mut(p.id) {
+                for c in p.configurations.iter().flatten() {
+                    bp.configurations
+                        .get_or_insert_with(Vec::new)
+                        .push(BuilderConfiguration {
+                            solution_configuration: c.solution_configuration.to_owned(),
+                            platform: c.platform.to_owned(),
+                            configuration: c.configuration.to_owned(),
+                            project_platform: c.project_platform.to_owned(),
+                            build: c.is_buildable(),
+                            deploy: c.is_deployable(),
+                        });
+                }
+            }
+        }
+        for (child, parent) in self.nested_pairs() {
+            builder = builder.add_nested_project(child, parent);
+        }
+        builder
+    }
+}
+
+fn is_folder(project: &BuilderProject) -> bool {
+    msbuild::is_solution_folder(&project.type_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_string_parsed_back() {
+        // Arrange
+        let builder = SolutionBuilder::new()
+            .add_project("a", "a\\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}")
+            .add_project("b", "b\\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}")
+            .add_dependency(
+                "{D9523F4D-6CB7-4431-85F6-8122F55EB144}",
+                "{78965571-A6C2-4161-95B1-813B46610EA7}",
+            )
+            .add_configuration("Debug", "Any CPU")
+            .add_configuration("Release", "Any CPU");
+
+        // Act
+        let text = builder.build_string();
+
+        // Assert
+        let solution = crate::parse_str(&text).unwrap();
+        assert!(solution.has_valid_header());
+        assert_eq!("12.00", solution.format);
+        assert_eq!(DEFAULT_PRODUCT, solution.product);
+        assert_eq!(2, solution.projects.len());
+        assert_eq!(2, solution.configurations.len());
+        let b = &solution.projects[1];
+        assert_eq!("b", b.name);
+        assert!(b.depends_on("{78965571-A6C2-4161-95B1-813B46610EA7}"));
+        assert!(solution
+            .projects
+            .iter()
+            .flat_map(|p| p.configurations.iter().flatten())
+            .all(|c| c.is_buildable() && c.active));
+        assert!(solution.dangling_project_configurations.is_none());
+    }
+
+    #[test]
+    fn to_builder_round_trip() {
+        // Arrange
+        let solution = crate::parse_str(REAL_SOLUTION).unwrap();
+
+        // Act
+        let text = solution.to_builder().build_string();
+
+        // Assert
+        let emitted = crate::parse_str(&text).unwrap();
+        assert_eq!(
+            Vec::<String>::new(),
+            solution.semantic_differences(&emitted)
+        );
+        assert_eq!(solution.product, emitted.product);
+        assert_eq!(solution.versions.len(), emitted.versions.len());
+    }
+
+    #[test]
+    fn to_builder_keeps_projects_without_configurations() {
+        // Arrange
+        let solution = crate::parse_str(
+            &SolutionBuilder::new()
+                .add_project("a", "a\\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}")
+                .build_string(),
+        )
+        .unwrap();
+
+        // Act
+        let text = solution
+            .to_builder()
+            .add_configuration("Debug", "Any CPU")
+            .build_string();
+
+        // Assert
+        let emitted = crate::parse_str(&text).unwrap();
+        assert!(emitted.projects[0].configurations.is_none());
+    }
+
+    #[test]
+    fn to_builder_keeps_project_platform() {
+        // Arrange
+        let solution = crate::parse_str(SOLUTION_WITH_MISMATCHED_PLATFORM).unwrap();
+
+        // Act
+        let text = solution.to_builder().build_string();
+
+        // Assert
+        assert!(text.contains("Debug|Any CPU.ActiveCfg = Debug|x86"));
+        let emitted = crate::parse_str(&text).unwrap();
+        let configuration = emitted.projects[0]
+            .configurations
+            .iter()
+            .flatten()
+            .next()
+            .unwrap();
+        assert_eq!("Any CPU", configuration.platform);
+        assert_eq!("x86", configuration.project_platform);
+        assert!(solution.semantically_equal(&emitted));
+    }
+
+    #[test]
+    fn to_builder_keeps_items_and_nesting() {
+        // Arrange
+        let solution = crate::parse_str(SOLUTION_WITH_FOLDER).unwrap();
+
+        // Act
+        let text = solution.to_builder().build_string();
+
+        // Assert
+        let emitted = crate::parse_str(&text).unwrap();
+        assert_eq!(
+            Some(&vec![".editorconfig"]),
+            emitted.projects[0].items.as_ref()
+        );
+        assert_eq!(solution.project_tree_text(), emitted.project_tree_text());
+        assert_eq!("folder/\n  a\n", emitted.project_tree_text());
+    }
+
+    #[test]
+    fn to_builder_drops_other_global_sections() {
+        // Arrange
+        let solution = crate::parse_str(SOLUTION_WITH_FOLDER).unwrap();
+
+        // Act
+        let text = solution.to_builder().build_string();
+
+        // Assert
+        assert!(solution.raw_section("ExtensibilityGlobals").is_some());
+        let emitted = crate::parse_str(&text).unwrap();
+        assert!(emitted.raw_section("ExtensibilityGlobals").is_none());
+        assert!(!text.contains("SolutionGuid"));
+    }
+
+    const SOLUTION_WITH_MISMATCHED_PLATFORM: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|x86
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_FOLDER: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "folder", "folder", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+	ProjectSection(SolutionItems) = preProject
+		.editorconfig = .editorconfig
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(NestedProjects) = preSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}
+	EndGlobalSection
+	GlobalSection(ExtensibilityGlobals) = postSolution
+		SolutionGuid = {A3C4E6D1-2B7F-4E5A-9C8D-1F0E2D3C4B5A}
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const REAL_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 15
+VisualStudioVersion = 15.0.26403.0
+MinimumVisualStudioVersion = 10.0.40219.1
+Project("{930C7802-8A8C-48F9-8165-68863BCCD9DD}") = "logviewer.install", "logviewer.install\logviewer.install.wixproj", "{27060CA7-FB29-42BC-BA66-7FC80D498354}"
+	ProjectSection(ProjectDependencies) = postProject
+		{405827CB-84E1-46F3-82C9-D889892645AC} = {405827CB-84E1-46F3-82C9-D889892645AC}
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D} = {CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}
+	EndProjectSection
+EndProject
+Project("{930C7802-8A8C-48F9-8165-68863BCCD9DD}") = "logviewer.install.bootstrap", "logviewer.install.bootstrap\logviewer.install.bootstrap.wixproj", "{1C0ED62B-D506-4E72-BBC2-A50D3926466E}"
+	ProjectSection(ProjectDependencies) = postProject
+		{27060CA7-FB29-42BC-BA66-7FC80D498354} = {27060CA7-FB29-42BC-BA66-7FC80D498354}
+	EndProjectSection
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "solution items", "solution items", "{3B960F8F-AD5D-45E7-92C0-05B65E200AC4}"
+	ProjectSection(SolutionItems) = preProject
+		.editorconfig = .editorconfig
+		appveyor.yml = appveyor.yml
+		logviewer.xml = logviewer.xml
+		WiX.msbuild = WiX.msbuild
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.tests", "logviewer.tests\logviewer.tests.csproj", "{939DD379-CDC8-47EF-8D37-0E5E71D99D30}"
+	ProjectSection(ProjectDependencies) = postProject
+		{383C08FC-9CAC-42E5-9B02-471561479A74} = {383C08FC-9CAC-42E5-9B02-471561479A74}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.logic", "logviewer.logic\logviewer.logic.csproj", "{383C08FC-9CAC-42E5-9B02-471561479A74}"
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = ".nuget", ".nuget", "{B720ED85-58CF-4840-B1AE-55B0049212CC}"
+	ProjectSection(SolutionItems) = preProject
+		.nuget\NuGet.Config = .nuget\NuGet.Config
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.engine", "logviewer.engine\logviewer.engine.csproj", "{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.install.mca", "logviewer.install.mca\logviewer.install.mca.csproj", "{405827CB-84E1-46F3-82C9-D889892645AC}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.ui", "logviewer.ui\logviewer.ui.csproj", "{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.bench", "logviewer.bench\logviewer.bench.csproj", "{75E0C034-44C8-461B-A677-9A19566FE393}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Debug|Mixed Platforms = Debug|Mixed Platforms
+		Debug|x86 = Debug|x86
+		Release|Any CPU = Release|Any CPU
+		Release|Mixed Platforms = Release|Mixed Platforms
+		Release|x86 = Release|x86
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Any CPU.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Mixed Platforms.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Mixed Platforms.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|x86.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|x86.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Any CPU.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Any CPU.Build.0 = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Mixed Platforms.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Mixed Platforms.Build.0 = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|x86.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|x86.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Any CPU.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Mixed Platforms.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Mixed Platforms.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|x86.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|x86.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Any CPU.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Any CPU.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Mixed Platforms.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Mixed Platforms.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|x86.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|x86.Build.0 = Release|x86
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Any CPU.Build.0 = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|x86.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Any CPU.Build.0 = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|x86.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Any CPU.Build.0 = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|x86.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Any CPU.Build.0 = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|x86.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Any CPU.Build.0 = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|x86.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|x86.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Any CPU.Build.0 = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|x86.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|x86.Build.0 = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(SolutionProperties) = preSolution
+		HideSolutionNode = FALSE
+	EndGlobalSection
+EndGlobal
+"#;
+}
@@ -0,0 +1,35 @@
+//! Solutions and projects shared by tests of this crate and of its consumers.
+//! Available to other crates only with `fixtures` feature enabled
+
+/// Solution that nests unknown project into solution folder
+pub const SOLUTION_WITH_ORPHANED_NESTED_PROJECT: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "src", "src", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "src\a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(NestedProjects) = preSolution
+		{78965571-a6c2-4161-95b1-813b46610ea7} = {F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}
+		{3AF54C8A-10BF-4332-9147-F68ED9862032} = {F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}
+	EndGlobalSection
+EndGlobal
+"#;
+
+/// Web project with two `None` and three `Content` items
+pub const PROJECT_WITH_ASSETS: &str = r#"<Project Sdk="Microsoft.NET.Sdk.Web">
+  <ItemGroup>
+    <None Include="appsettings.json" />
+    <None Include="nlog.config" />
+    <None Update="README.md" CopyToOutputDirectory="PreserveNewest" />
+  </ItemGroup>
+  <ItemGroup>
+    <Content Include="wwwroot\index.html" />
+    <Content Include="wwwroot\site.css" />
+    <Content Include="wwwroot\site.js" />
+  </ItemGroup>
+</Project>"#;
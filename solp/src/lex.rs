@@ -235,7 +235,8 @@ impl<'a> Lexer<'a> {
                 while let Some((j, c)) = self.chars.peek() {
                     match *c {
                         '\r' | '\n' => {
-                            let finish = *j;
+                            // trim trailing whitespaces left by hand editing
+                            let finish = Lexer::trim_end(self.input, *j).max(start);
                             return Ok((
                                 start,
                                 Tok::SectionValue(&self.input[start..finish]),
@@ -360,6 +361,30 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[rstest]
+    #[case("\tProjectSection(ProjectDependencies) = postProject\n\t\t{A} = {A}  \t\n\tEndProjectSection\n", "{A}")]
+    #[case("\tProjectSection(ProjectDependencies) = postProject\n\n\t\t{A} = {A}\r\n\n\tEndProjectSection\n", "{A}")]
+    #[case(
+        "\tProjectSection(ProjectDependencies) = postProject\n\t\t{A} =  \n\tEndProjectSection\n",
+        ""
+    )]
+    #[trace]
+    fn section_value_trailing_whitespaces_tests(#[case] content: &str, #[case] expected: &str) {
+        // Arrange
+        let lexer = Lexer::new(content);
+
+        // Act
+        let actual = lexer
+            .filter_map(Result::ok)
+            .find_map(|(_, tok, _)| match tok {
+                Tok::SectionValue(v) => Some(v),
+                _ => None,
+            });
+
+        // Assert
+        assert_eq!(actual, Some(expected));
+    }
+
     const REAL_SOLUTION: &str = r#"
 Microsoft Visual Studio Solution File, Format Version 12.00
 # Visual Studio 15
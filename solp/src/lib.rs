@@ -37,17 +37,30 @@ assert_eq!(solution.format, "12.00");
 
 #![warn(unused_extern_crates)]
 #![allow(clippy::missing_errors_doc)]
+use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::fs;
+use std::time::{Duration, Instant};
 
 use api::Solution;
-use color_eyre::{eyre::Context, Result};
+use color_eyre::{
+    eyre::{self, Context},
+    Report, Result,
+};
+use encoding_rs::Encoding;
 use jwalk::{Parallelism, WalkDir};
 
 pub mod api;
 mod ast;
+pub mod builder;
+pub mod edit;
+#[cfg(any(test, feature = "fixtures"))]
+#[doc(hidden)]
+pub mod fixtures;
 mod lex;
 pub mod msbuild;
 mod parser;
+mod slnf;
 
 #[macro_use]
 extern crate lalrpop_util;
@@ -70,12 +83,57 @@ lalrpop_mod!(
     solp
 );
 
+/// Options that control solution parsing
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    /// Calculate line and column of each project declaration. False by default
+    /// because it requires additional pass over solution content
+    pub positions: bool,
+    /// Parse solution and project configurations. True by default.
+    /// Disable it to speed up analysis that needs projects listing only
+    pub configurations: bool,
+    /// Parse projects dependencies. True by default
+    pub dependencies: bool,
+    /// Parse solution items of solution folders. True by default
+    pub items: bool,
+    /// Detect whether solution is indented using spaces instead of tabs. False by default
+    /// because it requires additional pass over solution content
+    pub indentation: bool,
+    /// Encoding label like `windows-1251` used to decode solution files in legacy codepages.
+    /// None by default i.e. files are expected to be UTF-8
+    pub encoding: Option<String>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            positions: false,
+            configurations: true,
+            dependencies: true,
+            items: true,
+            indentation: false,
+            encoding: None,
+        }
+    }
+}
+
 /// Consume provides parsed [`Solution`] consumer
 pub trait Consume {
     /// Called in case of success parsing
     fn ok(&mut self, solution: &Solution);
     /// Called on error
     fn err(&self, path: &str);
+    /// Called on error with the report describing it. Calls [`Consume::err`] by default
+    fn err_with(&self, path: &str, _error: &Report) {
+        self.err(path);
+    }
+    /// Whether scanning should be stopped before the next solution. Never stops by default
+    fn should_stop(&self) -> bool {
+        false
+    }
+    /// Called before [`Consume::ok`] with the time spent on reading and parsing the solution.
+    /// Does nothing by default
+    fn timing(&self, _path: &str, _elapsed: Duration) {}
 }
 
 /// Parses a solution file at the specified path and notifies the consumer of the result.
@@ -83,8 +141,8 @@ pub trait Consume {
 /// This function reads the content of the file at the given path and attempts to parse it
 /// as a Microsoft Visual Studio solution file. If the file is successfully read and parsed,
 /// the consumer's `ok` method is called with the parsed `Solution`. If any errors occur during
-/// reading or parsing, the consumer's `err` method is called with the path of the file, and an
-/// error is returned.
+/// reading or parsing, the consumer's `err_with` method is called with the path of the file
+/// and the error report, and an error is returned.
 ///
 /// # Parameters
 ///
@@ -100,8 +158,8 @@ pub trait Consume {
 /// # Errors
 ///
 /// This function will return an error if the file cannot be read or if the content cannot be parsed
-/// as a valid solution file. In both cases, the consumer's `err` method will be called with the path
-/// of the file.
+/// as a valid solution file. In both cases, the consumer's `err_with` method will be called with the path
+/// of the file and the error report.
 ///
 /// # Example
 ///
@@ -130,20 +188,193 @@ pub trait Consume {
 /// }
 /// ```
 pub fn parse_file(path: &str, consumer: &mut dyn Consume) -> Result<()> {
-    let contents = fs::read_to_string(path).wrap_err_with(|| {
-        consumer.err(path);
-        format!("Failed to read content from path: {path}")
-    })?;
-    let mut solution = parse_str(&contents).wrap_err_with(|| {
-        consumer.err(path);
-        format!("Failed to parse solution from path: {path}")
-    })?;
-
-    solution.path = path;
+    parse_file_with_options(path, consumer, &ParseOptions::default())
+}
+
+/// Parses a solution file at the specified path using [`ParseOptions`] specified
+/// and notifies the consumer of the result. See [`parse_file`] for details.
+pub fn parse_file_with_options(
+    path: &str,
+    consumer: &mut dyn Consume,
+    options: &ParseOptions,
+) -> Result<()> {
+    parse_with(
+        path,
+        path,
+        || read_contents(path, options.encoding.as_deref()).map(|(c, bom)| (Cow::Owned(c), bom)),
+        consumer,
+        options,
+        |_| {},
+    )
+}
+
+/// Reads solution contents using the function specified, parses them and notifies the consumer.
+/// Consumer is notified about errors and timing using the path specified while solution path
+/// is used in error messages and set into parsed solution. Filter may modify the solution
+/// before it's passed to the consumer
+fn parse_with<'c>(
+    path: &str,
+    solution_path: &str,
+    read: impl FnOnce() -> Result<(Cow<'c, str>, bool)>,
+    consumer: &mut dyn Consume,
+    options: &ParseOptions,
+    filter: impl FnOnce(&mut Solution),
+) -> Result<()> {
+    let started = Instant::now();
+    let (contents, bom) = read()
+        .wrap_err_with(|| format!("Failed to read content from path: {solution_path}"))
+        .inspect_err(|e| consumer.err_with(path, e))?;
+    let mut solution = parse_str_with_options(&contents, options)
+        .wrap_err_with(|| format!("Failed to parse solution from path: {solution_path}"))
+        .inspect_err(|e| consumer.err_with(path, e))?;
+    consumer.timing(path, started.elapsed());
+
+    solution.path = solution_path;
+    solution.utf8_bom = bom;
+    filter(&mut solution);
     consumer.ok(&solution);
     Ok(())
 }
 
+/// Parses a solution filter (`.slnf`) file at the specified path, loads the base solution it references
+/// and notifies the consumer of the solution that contains only filtered projects and solution folders.
+/// Solution path passed to the consumer is the base solution path.
+///
+/// # Errors
+///
+/// This function will return an error if the filter or the base solution cannot be read or parsed
+pub fn parse_slnf(path: &str, consumer: &mut dyn Consume) -> Result<()> {
+    parse_slnf_with_options(path, consumer, &ParseOptions::default())
+}
+
+/// Parses a solution filter (`.slnf`) file using [`ParseOptions`] specified.
+/// Base solution is read and parsed using the options. See [`parse_slnf`] for details.
+///
+/// # Errors
+///
+/// This function will return an error if the filter or the base solution cannot be read or parsed
+pub fn parse_slnf_with_options(
+    path: &str,
+    consumer: &mut dyn Consume,
+    options: &ParseOptions,
+) -> Result<()> {
+    let filter = fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read content from path: {path}"))
+        .and_then(|contents| {
+            serde_json::from_str::<slnf::SolutionFilter>(&contents)
+                .wrap_err_with(|| format!("Failed to parse solution filter from path: {path}"))
+        })
+        .inspect_err(|e| consumer.err_with(path, e))?;
+    let solution_path = filter.solution_path(path);
+    parse_with(
+        path,
+        &solution_path,
+        || {
+            read_contents(&solution_path, options.encoding.as_deref())
+                .map(|(c, bom)| (Cow::Owned(c), bom))
+        },
+        consumer,
+        options,
+        |solution| filter.apply(solution),
+    )
+}
+
+/// UTF-8 signature bytes
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Whether content starts with UTF-8 signature
+fn has_utf8_bom(content: &[u8]) -> bool {
+    content.starts_with(UTF8_BOM)
+}
+
+/// Reads file as UTF-8 string or decodes it using the encoding label specified.
+/// Also returns whether file starts with UTF-8 signature
+fn read_contents(path: &str, encoding: Option<&str>) -> Result<(String, bool)> {
+    match encoding {
+        None => {
+            let contents = fs::read_to_string(path)?;
+            let bom = has_utf8_bom(contents.as_bytes());
+            Ok((contents, bom))
+        }
+        Some(label) => {
+            let bytes = fs::read(path)?;
+            let bom = has_utf8_bom(&bytes);
+            Ok((decode(&bytes, Some(label))?.into_owned(), bom))
+        }
+    }
+}
+
+/// Decodes solution bytes into a string using the encoding label specified, for example `utf-16le`.
+/// If no encoding specified, BOM is sniffed and UTF-8 is used in case of BOM absence.
+///
+/// # Errors
+///
+/// This function will return an error if the encoding label is unknown
+/// or bytes contain sequences malformed for the encoding.
+pub fn decode<'a>(bytes: &'a [u8], encoding: Option<&str>) -> Result<Cow<'a, str>> {
+    let (encoding, bytes) = match encoding {
+        Some(label) => {
+            let encoding = Encoding::for_label(label.trim().as_bytes())
+                .ok_or_else(|| eyre::eyre!("Unknown encoding: {label}"))?;
+            let bom_length = Encoding::for_bom(bytes)
+                .filter(|(sniffed, _)| *sniffed == encoding)
+                .map_or(0, |(_, length)| length);
+            (encoding, &bytes[bom_length..])
+        }
+        None => Encoding::for_bom(bytes)
+            .map_or((encoding_rs::UTF_8, bytes), |(sniffed, length)| {
+                (sniffed, &bytes[length..])
+            }),
+    };
+    let (decoded, malformed) = encoding.decode_without_bom_handling(bytes);
+    if malformed {
+        return Err(eyre::eyre!(
+            "Content contains malformed {} sequences",
+            encoding.name()
+        ));
+    }
+    Ok(decoded)
+}
+
+/// Parses solution from bytes using the encoding specified and notifies the consumer
+/// in case of success. See [`decode`] for details about encodings.
+///
+/// # Errors
+///
+/// This function will return an error if bytes cannot be decoded or if the content cannot be parsed
+/// as a valid solution file.
+pub fn parse_bytes(bytes: &[u8], encoding: Option<&str>, consumer: &mut dyn Consume) -> Result<()> {
+    parse_bytes_with_options("", bytes, encoding, consumer, &ParseOptions::default())
+}
+
+/// Parses solution from bytes using the encoding and [`ParseOptions`] specified
+/// and notifies the consumer of the result like [`parse_file_with_options`] does.
+/// Path is the name bytes were read from, for example an archive entry name, that is passed
+/// to the consumer as solution path. Encoding of the options is used if no encoding specified.
+/// See [`parse_bytes`] for details.
+///
+/// # Errors
+///
+/// This function will return an error if bytes cannot be decoded or if the content cannot be parsed
+/// as a valid solution file. In both cases, the consumer's `err_with` method will be called with the path.
+pub fn parse_bytes_with_options(
+    path: &str,
+    bytes: &[u8],
+    encoding: Option<&str>,
+    consumer: &mut dyn Consume,
+    options: &ParseOptions,
+) -> Result<()> {
+    let encoding = encoding.or(options.encoding.as_deref());
+    parse_with(
+        path,
+        path,
+        || Ok((decode(bytes, encoding)?, has_utf8_bom(bytes))),
+        consumer,
+        options,
+        |_| {},
+    )
+}
+
 /// Parses a solution file content from a string slice and returns a [`Solution`] object.
 ///
 /// This function takes the content of a solution file as a string slice, attempts to parse it,
@@ -197,8 +428,51 @@ pub fn parse_file(path: &str, consumer: &mut dyn Consume) -> Result<()> {
 /// This function uses the `parser::parse_str` function to perform the actual parsing and then
 /// constructs a [`Solution`] object from the parsed data.
 pub fn parse_str(contents: &str) -> Result<Solution> {
-    let parsed = parser::parse_str(contents)?;
-    Ok(Solution::from(&parsed))
+    parse_str_with_options(contents, &ParseOptions::default())
+}
+
+/// Parses a solution file content from a string slice using [`ParseOptions`] specified.
+/// See [`parse_str`] for details.
+///
+/// # Example
+///
+/// ```rust
+/// use solp::{parse_str_with_options, ParseOptions};
+///
+/// let solution_content = r#"
+/// Microsoft Visual Studio Solution File, Format Version 12.00
+/// Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "MyProject", "MyProject.csproj", "{A61CD222-0F3B-47B6-9F7F-25D658368EEC}"
+/// EndProject
+/// Global
+/// EndGlobal
+/// "#;
+///
+/// let options = ParseOptions {
+///     positions: true,
+///     ..Default::default()
+/// };
+/// let solution = parse_str_with_options(solution_content, &options).unwrap();
+/// assert_eq!(solution.projects[0].line, Some(3));
+/// assert_eq!(solution.projects[0].column, Some(1));
+/// ```
+pub fn parse_str_with_options<'a>(
+    contents: &'a str,
+    options: &ParseOptions,
+) -> Result<Solution<'a>> {
+    let parsed = parser::parse_str_with_options(contents, options)?;
+    let mut solution = Solution::from(&parsed);
+    solution.utf8_bom = has_utf8_bom(contents.as_bytes());
+    if options.positions {
+        // Offsets are calculated by lexer over content without UTF-8 signature
+        let text = contents.strip_prefix('\u{feff}').unwrap_or(contents);
+        let line_starts = line_starts(text);
+        for (project, prj) in solution.projects.iter_mut().zip(parsed.projects.iter()) {
+            let (line, column) = line_column(text, &line_starts, prj.start);
+            project.line = Some(line);
+            project.column = Some(column);
+        }
+    }
+    Ok(solution)
 }
 
 /// `parse_dir` parses only directory specified by path.
@@ -209,8 +483,7 @@ pub fn parse_str(contents: &str) -> Result<Solution> {
 /// Any errors occurred during parsing of found files will be ignored (so parsing won't stopped)
 /// but error paths will be added into error files list (using err function of [`Consume`] trait)
 pub fn parse_dir(path: &str, extension: &str, consumer: &mut dyn Consume) -> usize {
-    let iter = create_dir_iterator(path).max_depth(1);
-    parse_dir_or_tree(iter, extension, consumer)
+    SolpWalker::new(consumer, extension).walk_and_parse(path, false)
 }
 
 /// `parse_dir_tree` parses directory specified by path. recursively
@@ -221,9 +494,198 @@ pub fn parse_dir(path: &str, extension: &str, consumer: &mut dyn Consume) -> usi
 /// Any errors occurred during parsing of found files will be ignored (so parsing won't stopped)
 /// but error paths will be added into error files list (using err function of [`Consume`] trait)
 pub fn parse_dir_tree(path: &str, extension: &str, consumer: &mut dyn Consume) -> usize {
-    let parallelism = Parallelism::RayonNewPool(num_cpus::get_physical());
-    let iter = create_dir_iterator(path).parallelism(parallelism);
-    parse_dir_or_tree(iter, extension, consumer)
+    SolpWalker::new(consumer, extension).walk_and_parse(path, true)
+}
+
+/// `SolpWalker` finds all files with extension specified within directory (or directory tree)
+/// and parses them using [`ParseOptions`] specified. Parsing results passed into [`Consume`] implementation.
+///
+/// # Example
+///
+/// ```rust
+/// use solp::api::Solution;
+/// use solp::{Consume, ParseOptions, SolpWalker};
+///
+/// struct Consumer;
+///
+/// impl Consume for Consumer {
+///   fn ok(&mut self, solution: &Solution) {}
+///
+///   fn err(&self, path: &str) {}
+/// }
+///
+/// let mut consumer = Consumer{};
+/// let options = ParseOptions {
+///     positions: true,
+///     ..Default::default()
+/// };
+/// let scanned = SolpWalker::new(&mut consumer, "sln")
+///     .with_options(options)
+///     .walk_and_parse("path/to/directory", true);
+/// ```
+pub struct SolpWalker<'a> {
+    consumer: &'a mut dyn Consume,
+    extension: String,
+    options: ParseOptions,
+    limit: Option<Limit>,
+}
+
+/// Limits the number of files found that are parsed
+#[derive(Debug, Clone, Copy)]
+enum Limit {
+    /// Only the first files found
+    Head(usize),
+    /// Only the last files found
+    Tail(usize),
+}
+
+impl<'a> SolpWalker<'a> {
+    /// Creates new walker that will parse files with extension specified
+    pub fn new(consumer: &'a mut dyn Consume, extension: &str) -> Self {
+        Self {
+            consumer,
+            extension: extension.trim_start_matches('.').to_owned(),
+            options: ParseOptions::default(),
+            limit: None,
+        }
+    }
+
+    /// Parses only the first files found. Files are walked sorted by name so the same files are chosen every run.
+    /// Walking stops as soon as the number of files specified found
+    #[must_use]
+    pub fn with_head(mut self, count: usize) -> Self {
+        self.limit = Some(Limit::Head(count));
+        self
+    }
+
+    /// Parses only the last files found. Files are walked sorted by name so the same files are chosen every run.
+    /// All files are found first but only paths of the last ones are kept
+    #[must_use]
+    pub fn with_tail(mut self, count: usize) -> Self {
+        self.limit = Some(Limit::Tail(count));
+        self
+    }
+
+    /// Sets options used to parse all found files
+    #[must_use]
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Walks directory specified (recursively if necessary) and parses all found files.
+    /// returns the number of scanned solutions
+    ///
+    /// ## Remarks
+    /// Any errors occurred during parsing of found files will be ignored (so parsing won't stopped)
+    /// but error paths will be added into error files list (using err function of [`Consume`] trait)
+    pub fn walk_and_parse(&mut self, path: &str, recursively: bool) -> usize {
+        let walker = create_walker(path, recursively);
+        // Head and tail make sense only when files come in the same order every run
+        // so walk is sorted when limited
+        let walker = if self.limit.is_some() {
+            walker.sort(true)
+        } else {
+            walker
+        };
+        let paths = solution_paths(walker, &self.extension);
+        match self.limit {
+            None => parse_paths(paths, &self.options, self.consumer),
+            Some(Limit::Head(count)) => {
+                parse_paths(paths.take(count), &self.options, self.consumer)
+            }
+            Some(Limit::Tail(count)) => {
+                let mut last = VecDeque::with_capacity(count);
+                for path in paths {
+                    if last.len() == count {
+                        last.pop_front();
+                    }
+                    if count > 0 {
+                        last.push_back(path);
+                    }
+                }
+                parse_paths(last.into_iter(), &self.options, self.consumer)
+            }
+        }
+    }
+}
+
+/// Solution file read from disk that can be parsed on demand.
+/// It owns file's contents because parsed [`Solution`] borrows them
+pub struct SolutionSource {
+    /// Path to the solution file
+    pub path: String,
+    /// Solution file contents
+    pub contents: String,
+    options: ParseOptions,
+    utf8_bom: bool,
+}
+
+impl SolutionSource {
+    /// Parses solution contents using options the source was created with.
+    /// Solution path is set to the path of the source
+    pub fn solution(&self) -> Result<Solution<'_>> {
+        let mut solution = parse_str_with_options(&self.contents, &self.options)
+            .wrap_err_with(|| format!("Failed to parse solution from path: {}", self.path))?;
+        solution.path = &self.path;
+        solution.utf8_bom = self.utf8_bom;
+        Ok(solution)
+    }
+}
+
+/// Walks directory specified (recursively if necessary) and lazily reads all found files
+/// with the extension specified. It's pull based alternative of [`SolpWalker`]
+/// so that standard iterator combinators can be used.
+/// Files are decoded using encoding of the options specified like [`parse_file_with_options`] does.
+/// Files that cannot be read are yielded as errors
+///
+/// # Example
+///
+/// ```rust
+/// use solp::{iter_solutions, ParseOptions};
+///
+/// let big = iter_solutions("path/to/directory", "sln", true, &ParseOptions::default())
+///     .filter_map(Result::ok)
+///     .filter(|source| source.solution().is_ok_and(|s| s.projects.len() > 10))
+///     .map(|source| source.path)
+///     .collect::<Vec<String>>();
+/// ```
+pub fn iter_solutions(
+    path: &str,
+    extension: &str,
+    recursively: bool,
+    options: &ParseOptions,
+) -> impl Iterator<Item = Result<SolutionSource>> {
+    let options = options.clone();
+    solution_paths(create_walker(path, recursively), extension).map(move |path| {
+        let (contents, utf8_bom) = read_contents(&path, options.encoding.as_deref())
+            .wrap_err_with(|| format!("Failed to read content from path: {path}"))?;
+        Ok(SolutionSource {
+            path,
+            contents,
+            options: options.clone(),
+            utf8_bom,
+        })
+    })
+}
+
+/// Walks directory specified (recursively if necessary) and finds paths of all files
+/// with the extension specified without reading them
+pub fn find_solutions(
+    path: &str,
+    extension: &str,
+    recursively: bool,
+) -> impl Iterator<Item = String> {
+    solution_paths(create_walker(path, recursively), extension)
+}
+
+fn create_walker(path: &str, recursively: bool) -> WalkDir {
+    if recursively {
+        let parallelism = Parallelism::RayonNewPool(num_cpus::get_physical());
+        create_dir_iterator(path).parallelism(parallelism)
+    } else {
+        create_dir_iterator(path).max_depth(1)
+    }
 }
 
 fn create_dir_iterator(path: &str) -> WalkDir {
@@ -231,18 +693,23 @@ fn create_dir_iterator(path: &str) -> WalkDir {
     WalkDir::new(root).skip_hidden(false).follow_links(false)
 }
 
-/// Parses the directory or directory tree and processes files with the specified extension.
-///
-/// This function takes an iterator over directory entries (`WalkDir`), a file extension to filter by,
-/// and a consumer that implements the `Consume` trait. It filters the directory entries to only include
-/// files with the specified extension, attempts to parse each file, and counts how many files were
-/// successfully parsed.
+/// Iterates paths of all files with extension specified
+fn solution_paths(iter: WalkDir, extension: &str) -> impl Iterator<Item = String> {
+    let ext = extension.trim_start_matches('.').to_owned();
+    iter.into_iter()
+        .filter_map(std::result::Result::ok)
+        .filter(|f| f.file_type().is_file())
+        .map(|f| f.path())
+        .filter(move |p| p.extension().is_some_and(|s| s == ext.as_str()))
+        .map(|f| f.to_str().unwrap_or("").to_string())
+}
+
+/// Parses files with paths specified and notifies the consumer about each of them.
 ///
 /// # Parameters
 ///
-/// - `iter`: An iterator over directory entries (`WalkDir`). This can be configured to either walk a
-///   single directory or recursively walk a directory tree.
-/// - `extension`: The file extension to filter by. Files must have this extension to be processed.
+/// - `paths`: Paths of files to parse. Usually found by walking a directory or a directory tree.
+/// - `options`: Options used to parse each file found.
 /// - `consumer`: A mutable reference to an object that implements the `Consume` trait. This consumer
 ///   will be notified of successful and failed parse attempts.
 ///
@@ -253,18 +720,39 @@ fn create_dir_iterator(path: &str) -> WalkDir {
 /// # Remarks
 ///
 /// Any errors that occur during the parsing of files will be ignored, but the paths of the files that
-/// caused errors will be added to the error files list using the `err` function of the `Consume` trait.
-fn parse_dir_or_tree(iter: WalkDir, extension: &str, consumer: &mut dyn Consume) -> usize {
-    let ext = extension.trim_start_matches('.');
+/// caused errors will be added to the error files list using the `err_with` function of the `Consume` trait.
+fn parse_paths(
+    paths: impl Iterator<Item = String>,
+    options: &ParseOptions,
+    consumer: &mut dyn Consume,
+) -> usize {
+    let mut parsed = 0;
+    for fp in paths {
+        if consumer.should_stop() {
+            break;
+        }
+        if parse_file_with_options(&fp, consumer, options).is_ok() {
+            parsed += 1;
+        }
+    }
+    parsed
+}
 
-    iter.into_iter()
-        .filter_map(std::result::Result::ok)
-        .filter(|f| f.file_type().is_file())
-        .map(|f| f.path())
-        .filter(|p| p.extension().is_some_and(|s| s == ext))
-        .map(|f| f.to_str().unwrap_or("").to_string())
-        .filter_map(|fp| parse_file(&fp, consumer).ok())
-        .count()
+/// Returns byte offsets of all lines starts within text
+fn line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+/// Converts byte offset into 1-based line and column pair
+fn line_column(text: &str, line_starts: &[usize], offset: usize) -> (usize, usize) {
+    let line = line_starts.partition_point(|start| *start <= offset);
+    let line_start = line_starts[line.saturating_sub(1)];
+    let column = text
+        .get(line_start..offset)
+        .map_or(0, |s| s.chars().count());
+    (line, column + 1)
 }
 
 /// On Windows trailing backslash (\) to be added if volume and colon passed (like c:).
@@ -287,7 +775,10 @@ fn decorate_path(path: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::fixtures::SOLUTION_WITH_ORPHANED_NESTED_PROJECT;
     use rstest::rstest;
+    use std::collections::{BTreeMap, BTreeSet};
+    use std::path::{Path, PathBuf};
 
     #[cfg(not(target_os = "windows"))]
     #[rstest]
@@ -322,4 +813,1815 @@ mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn parse_bytes_default_utf8() {
+        // Arrange
+        let mut consumer = ProjectsCounter::default();
+
+        // Act
+        let result = parse_bytes(SOLUTION.as_bytes(), None, &mut consumer);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(2, consumer.projects);
+    }
+
+    #[test]
+    fn parse_bytes_utf8_with_bom() {
+        // Arrange
+        let mut consumer = ProjectsCounter::default();
+        let bytes = [&[0xEF, 0xBB, 0xBF], SOLUTION.as_bytes()].concat();
+
+        // Act
+        let result = parse_bytes(&bytes, None, &mut consumer);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(2, consumer.projects);
+    }
+
+    #[test]
+    fn parse_bytes_explicit_utf16le() {
+        // Arrange
+        let mut consumer = ProjectsCounter::default();
+        let bytes = SOLUTION
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<u8>>();
+
+        // Act
+        let result = parse_bytes(&bytes, Some("utf-16le"), &mut consumer);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(2, consumer.projects);
+    }
+
+    #[test]
+    fn parse_file_with_legacy_codepage() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cyrillic.sln");
+        let (bytes, _, _) = encoding_rs::WINDOWS_1251.encode(CYRILLIC_SOLUTION);
+        fs::write(&path, bytes).unwrap();
+        let path = path.to_str().unwrap();
+        let options = ParseOptions {
+            encoding: Some("windows-1251".to_owned()),
+            ..Default::default()
+        };
+        let mut consumer = ProjectsCounter::default();
+
+        // Act
+        let result = parse_file_with_options(path, &mut consumer, &options);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(vec!["Проект"], consumer.names);
+    }
+
+    #[test]
+    fn parse_file_with_legacy_codepage_without_encoding() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cyrillic.sln");
+        let (bytes, _, _) = encoding_rs::WINDOWS_1251.encode(CYRILLIC_SOLUTION);
+        fs::write(&path, bytes).unwrap();
+        let path = path.to_str().unwrap();
+        let mut consumer = ProjectsCounter::default();
+
+        // Act
+        let result = parse_file(path, &mut consumer);
+
+        // Assert
+        assert!(result.is_err());
+        assert!(consumer.names.is_empty());
+    }
+
+    #[test]
+    fn iter_solutions_with_legacy_codepage() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let (bytes, _, _) = encoding_rs::WINDOWS_1251.encode(CYRILLIC_SOLUTION);
+        fs::write(dir.path().join("cyrillic.sln"), bytes).unwrap();
+        let options = ParseOptions {
+            encoding: Some("windows-1251".to_owned()),
+            ..Default::default()
+        };
+
+        // Act
+        let sources = iter_solutions(dir.path().to_str().unwrap(), "sln", false, &options)
+            .collect::<Result<Vec<SolutionSource>>>()
+            .unwrap();
+
+        // Assert
+        assert_eq!(1, sources.len());
+        let solution = sources[0].solution().unwrap();
+        assert_eq!("Проект", solution.projects[0].name);
+    }
+
+    #[test]
+    fn walk_and_parse_empty_dir() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.csproj"), "").unwrap();
+        let mut consumer = ProjectsCounter::default();
+
+        // Act
+        let actual = parse_dir_tree(dir.path().to_str().unwrap(), "sln", &mut consumer);
+
+        // Assert
+        assert_eq!(0, actual);
+    }
+
+    #[rstest]
+    #[case(false, 1, 1)]
+    #[case(false, 5, 3)]
+    #[case(false, 0, 0)]
+    #[case(true, 1, 1)]
+    #[case(true, 5, 3)]
+    #[case(true, 0, 0)]
+    #[trace]
+    fn walk_and_parse_limited(#[case] tail: bool, #[case] count: usize, #[case] expected: usize) {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["a.sln", "b.sln", "c.sln"] {
+            fs::write(dir.path().join(name), SOLUTION).unwrap();
+        }
+        let mut consumer = ProjectsCounter::default();
+        let walker = SolpWalker::new(&mut consumer, "sln");
+        let mut walker = if tail {
+            walker.with_tail(count)
+        } else {
+            walker.with_head(count)
+        };
+
+        // Act
+        let actual = walker.walk_and_parse(dir.path().to_str().unwrap(), false);
+
+        // Assert
+        assert_eq!(expected, actual);
+        assert_eq!(expected * 2, consumer.projects);
+    }
+
+    #[rstest]
+    #[case(false, "a.sln")]
+    #[case(true, "c.sln")]
+    #[trace]
+    fn walk_and_parse_limited_sorted(#[case] tail: bool, #[case] expected: &str) {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["c.sln", "a.sln", "b.sln"] {
+            fs::write(dir.path().join(name), SOLUTION).unwrap();
+        }
+        let mut consumer = ProjectsCounter::default();
+        let walker = SolpWalker::new(&mut consumer, "sln");
+        let mut walker = if tail {
+            walker.with_tail(1)
+        } else {
+            walker.with_head(1)
+        };
+
+        // Act
+        walker.walk_and_parse(dir.path().to_str().unwrap(), true);
+
+        // Assert
+        assert_eq!(1, consumer.paths.len());
+        assert!(consumer.paths[0].ends_with(expected));
+    }
+
+    #[test]
+    fn parse_slnf_keeps_filtered_projects() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("filters")).unwrap();
+        fs::write(dir.path().join("all.sln"), SOLUTION_FOR_FILTER).unwrap();
+        let path = dir.path().join("filters").join("ac.slnf");
+        fs::write(&path, SOLUTION_FILTER).unwrap();
+        let path = path.to_str().unwrap();
+        let mut consumer = ProjectsCounter::default();
+
+        // Act
+        let result = parse_slnf(path, &mut consumer);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(vec!["src", "a", "c"], consumer.names);
+    }
+
+    #[test]
+    fn parse_slnf_with_legacy_codepage() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let (bytes, _, _) = encoding_rs::WINDOWS_1251.encode(CYRILLIC_SOLUTION);
+        fs::write(dir.path().join("all.sln"), bytes).unwrap();
+        let path = dir.path().join("cyrillic.slnf");
+        fs::write(&path, CYRILLIC_SOLUTION_FILTER).unwrap();
+        let path = path.to_str().unwrap();
+        let options = ParseOptions {
+            encoding: Some("windows-1251".to_owned()),
+            ..Default::default()
+        };
+        let mut consumer = ProjectsCounter::default();
+
+        // Act
+        let result = parse_slnf_with_options(path, &mut consumer, &options);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(vec!["Проект"], consumer.names);
+    }
+
+    #[test]
+    fn parse_slnf_missing_base_solution() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("ac.slnf");
+        fs::write(&path, SOLUTION_FILTER).unwrap();
+        let path = path.to_str().unwrap();
+        let mut consumer = ProjectsCounter::default();
+
+        // Act
+        let result = parse_slnf(path, &mut consumer);
+
+        // Assert
+        assert!(result.is_err());
+        assert!(consumer.names.is_empty());
+    }
+
+    #[test]
+    fn parse_bytes_with_options_sets_path() {
+        // Arrange
+        let mut consumer = ProjectsCounter::default();
+
+        // Act
+        let result = parse_bytes_with_options(
+            "entry.sln",
+            SOLUTION.as_bytes(),
+            None,
+            &mut consumer,
+            &ParseOptions::default(),
+        );
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(vec!["entry.sln"], consumer.paths);
+        assert!(consumer.failed.borrow().is_empty());
+    }
+
+    #[test]
+    fn parse_bytes_with_options_reports_error() {
+        // Arrange
+        let mut consumer = ProjectsCounter::default();
+
+        // Act
+        let result = parse_bytes_with_options(
+            "entry.sln",
+            b"not a solution",
+            None,
+            &mut consumer,
+            &ParseOptions::default(),
+        );
+
+        // Assert
+        assert!(result.is_err());
+        assert!(consumer.paths.is_empty());
+        assert_eq!(vec!["entry.sln"], *consumer.failed.borrow());
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some("utf-8"))]
+    #[case(Some("unknown-encoding"))]
+    #[trace]
+    fn parse_bytes_utf16le_without_proper_encoding(#[case] encoding: Option<&str>) {
+        // Arrange
+        let mut consumer = ProjectsCounter::default();
+        let bytes = SOLUTION
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<u8>>();
+
+        // Act
+        let result = parse_bytes(&bytes, encoding, &mut consumer);
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(0, consumer.projects);
+    }
+
+    #[rstest]
+    #[case(false, false)]
+    #[case(true, true)]
+    #[trace]
+    fn parse_str_space_indentation(#[case] indentation: bool, #[case] expected: bool) {
+        // Arrange
+        let contents = SOLUTION.replace('\t', "    ");
+        let options = ParseOptions {
+            indentation,
+            ..Default::default()
+        };
+
+        // Act
+        let solution = parse_str_with_options(&contents, &options).unwrap();
+
+        // Assert
+        assert_eq!(expected, solution.space_indented);
+    }
+
+    #[test]
+    fn parse_str_without_positions() {
+        // Arrange
+
+        // Act
+        let solution = parse_str(SOLUTION).unwrap();
+
+        // Assert
+        assert!(solution.projects.iter().all(|p| p.line.is_none()));
+        assert!(solution.projects.iter().all(|p| p.column.is_none()));
+    }
+
+    #[test]
+    fn parse_str_with_positions() {
+        // Arrange
+        let options = ParseOptions {
+            positions: true,
+            ..Default::default()
+        };
+
+        // Act
+        let solution = parse_str_with_options(SOLUTION, &options).unwrap();
+
+        // Assert
+        assert_eq!(2, solution.projects.len());
+        assert_eq!(Some(4), solution.projects[0].line);
+        assert_eq!(Some(1), solution.projects[0].column);
+        assert_eq!(Some(6), solution.projects[1].line);
+        assert_eq!(Some(3), solution.projects[1].column);
+    }
+
+    #[rstest]
+    #[case("\u{feff}", true)]
+    #[case("", false)]
+    #[trace]
+    fn parse_file_utf8_bom(#[case] signature: &str, #[case] expected: bool) {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.sln");
+        fs::write(&path, format!("{signature}{SOLUTION}")).unwrap();
+        let path = path.to_str().unwrap();
+        let mut consumer = BomDetector::default();
+
+        // Act
+        let result = parse_file(path, &mut consumer);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(Some(expected), consumer.bom);
+    }
+
+    #[rstest]
+    #[case("\u{feff}", true)]
+    #[case("", false)]
+    #[trace]
+    fn parse_bytes_utf8_bom(#[case] signature: &str, #[case] expected: bool) {
+        // Arrange
+        let contents = format!("{signature}{SOLUTION}");
+        let mut consumer = BomDetector::default();
+
+        // Act
+        let result = parse_bytes(contents.as_bytes(), None, &mut consumer);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(Some(expected), consumer.bom);
+    }
+
+    #[test]
+    fn parse_str_with_positions_and_utf8_signature() {
+        // Arrange
+        let options = ParseOptions {
+            positions: true,
+            ..Default::default()
+        };
+        let contents = format!("\u{feff}{SOLUTION}");
+
+        // Act
+        let solution = parse_str_with_options(&contents, &options).unwrap();
+
+        // Assert
+        assert_eq!(Some(4), solution.projects[0].line);
+        assert_eq!(Some(1), solution.projects[0].column);
+    }
+
+    #[rstest]
+    #[case("", 0, (1, 1))]
+    #[case("ab\ncd", 1, (1, 2))]
+    #[case("ab\ncd", 3, (2, 1))]
+    #[case("ab\ncd", 4, (2, 2))]
+    #[case("ф\nф", 3, (2, 1))]
+    #[trace]
+    fn line_column_tests(
+        #[case] text: &str,
+        #[case] offset: usize,
+        #[case] expected: (usize, usize),
+    ) {
+        // Arrange
+        let starts = line_starts(text);
+
+        // Act
+        let actual = line_column(text, &starts, offset);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn all_guids_real_solution() {
+        // Arrange
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.all_guids();
+
+        // Assert
+        // 10 project ids and 3 project type ids
+        assert_eq!(13, actual.len());
+        assert!(actual.contains("{2150E333-8FDC-42A3-9474-1A3956D46DE8}"));
+        assert!(actual.contains("{27060CA7-FB29-42BC-BA66-7FC80D498354}"));
+    }
+
+    #[test]
+    fn all_guids_include_nested_projects() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_ORPHANED_NESTED_PROJECT).unwrap();
+
+        // Act
+        let actual = solution.all_guids();
+
+        // Assert
+        // 2 project ids, 2 project type ids and orphaned nested child id
+        assert_eq!(5, actual.len());
+        assert!(actual.contains("{3AF54C8A-10BF-4332-9147-F68ED9862032}"));
+        assert!(actual.contains("{78965571-A6C2-4161-95B1-813B46610EA7}"));
+    }
+
+    #[test]
+    fn configuration_count_per_project_apr_solution() {
+        // Arrange
+        let solution = parse_str(APR_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.configuration_count_per_project();
+
+        // Assert
+        // 4 configurations with ActiveCfg and Build.0 each but INSTALL that isn't built
+        assert_eq!(9, actual.len());
+        assert!(actual[..8].iter().all(|(_, count)| *count == 8));
+        assert_eq!("INSTALL", actual[8].0.name);
+        assert_eq!(4, actual[8].1);
+    }
+
+    #[test]
+    fn root_projects() {
+        // Arrange
+        let solution = parse_str(APR_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.root_projects();
+
+        // Assert
+        let names = actual.iter().map(|p| p.name).collect::<Vec<&str>>();
+        assert_eq!(vec!["ZERO_CHECK"], names);
+    }
+
+    #[test]
+    fn leaf_projects() {
+        // Arrange
+        let solution = parse_str(APR_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.leaf_projects();
+
+        // Assert
+        // ALL_BUILD isn't a leaf because INSTALL depends on it
+        let names = actual.iter().map(|p| p.name).collect::<Vec<&str>>();
+        assert_eq!(vec!["INSTALL"], names);
+    }
+
+    #[rstest]
+    #[case(api::Tag::Build, ast::ProjectConfigTag::Build)]
+    #[case(api::Tag::Deploy, ast::ProjectConfigTag::Deploy)]
+    #[trace]
+    fn tag_round_trip_tests(#[case] tag: api::Tag, #[case] expected: ast::ProjectConfigTag) {
+        // Arrange
+
+        // Act
+        let converted = ast::ProjectConfigTag::from(tag.clone());
+        let back = api::Tag::try_from(converted.clone());
+
+        // Assert
+        assert_eq!(converted, expected);
+        assert_eq!(back, Ok(tag));
+    }
+
+    #[test]
+    fn tag_from_active_cfg_fails() {
+        // Arrange
+
+        // Act
+        let actual = api::Tag::try_from(ast::ProjectConfigTag::ActiveCfg);
+
+        // Assert
+        assert_eq!(actual, Err(ast::ProjectConfigTag::ActiveCfg));
+    }
+
+    #[rstest]
+    #[case(vec![], false, false)]
+    #[case(vec![api::Tag::Build], true, false)]
+    #[case(vec![api::Tag::Deploy], false, true)]
+    #[case(vec![api::Tag::Build, api::Tag::Deploy], true, true)]
+    #[trace]
+    fn project_configuration_tags_tests(
+        #[case] tags: Vec<api::Tag>,
+        #[case] buildable: bool,
+        #[case] deployable: bool,
+    ) {
+        // Arrange
+        let configuration = api::ProjectConfiguration {
+            tags,
+            ..Default::default()
+        };
+
+        // Act
+        let actual = (configuration.is_buildable(), configuration.is_deployable());
+
+        // Assert
+        assert_eq!(actual, (buildable, deployable));
+    }
+
+    #[test]
+    fn project_configuration_tags_apr_solution() {
+        // Arrange
+        let solution = parse_str(APR_SOLUTION).unwrap();
+
+        // Act
+        let install = solution
+            .projects
+            .iter()
+            .find(|p| p.name == "INSTALL")
+            .unwrap();
+
+        // Assert
+        let configurations = install.configurations.as_ref().unwrap();
+        assert!(configurations
+            .iter()
+            .all(|c| !c.is_buildable() && !c.is_deployable()));
+    }
+
+    #[test]
+    fn parse_str_source_control_bindings() {
+        // Arrange
+
+        // Act
+        let solution = parse_str(SOLUTION_WITH_TFS_BINDINGS).unwrap();
+
+        // Assert
+        let bindings = solution.source_control_bindings.unwrap();
+        assert_eq!(Some(3), bindings.number_of_projects);
+        assert_eq!(
+            Some("{4CA58AB2-18FA-4F8D-95D4-32DDF27D184C}"),
+            bindings.provider
+        );
+        assert_eq!(
+            Some("http://tfs:8080/tfs/defaultcollection"),
+            bindings.server
+        );
+        assert_eq!(3, bindings.projects.len());
+        assert_eq!(Some("."), bindings.projects[0].local_path);
+        assert_eq!(None, bindings.projects[0].name);
+        assert_eq!(
+            api::SourceControlProject {
+                index: 2,
+                unique_name: Some(r"b\\b.csproj"),
+                name: Some("b"),
+                local_path: Some("b"),
+            },
+            bindings.projects[2]
+        );
+        assert_eq!(2, solution.projects.len());
+    }
+
+    #[test]
+    fn parse_str_without_source_control_bindings() {
+        // Arrange
+
+        // Act
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+
+        // Assert
+        assert!(solution.source_control_bindings.is_none());
+    }
+
+    #[rstest]
+    #[case("C#", true)]
+    #[case("c#", true)]
+    #[case("F#", false)]
+    #[case("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}", true)]
+    #[case("{fae04ec0-301f-11d3-bf4b-00c04f79efbc}", true)]
+    #[case("{F2A71F9B-5D33-465A-A702-920D77279786}", false)]
+    #[trace]
+    fn contains_project_type_tests(#[case] id_or_name: &str, #[case] expected: bool) {
+        // Arrange
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.contains_project_type(id_or_name);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case("C#", 6)]
+    #[case("{fae04ec0-301f-11d3-bf4b-00c04f79efbc}", 6)]
+    #[case("F#", 0)]
+    #[trace]
+    fn iterate_projects_of_type_tests(#[case] id_or_name: &str, #[case] expected: usize) {
+        // Arrange
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.iterate_projects_of_type(id_or_name).count();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn raw_section_solution_properties() {
+        // Arrange
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.raw_section("SolutionProperties");
+
+        // Assert
+        assert_eq!(
+            Some("GlobalSection(SolutionProperties) = preSolution\n\t\tHideSolutionNode = FALSE\n\tEndGlobalSection"),
+            actual
+        );
+    }
+
+    #[test]
+    fn raw_section_not_exist() {
+        // Arrange
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.raw_section("NestedProjects");
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[rstest]
+    #[case("{405827CB-84E1-46F3-82C9-D889892645AC}", true)]
+    #[case("{405827cb-84e1-46f3-82c9-d889892645ac}", true)]
+    #[case("405827CB-84E1-46F3-82C9-D889892645AC", true)]
+    #[case("{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}", true)]
+    #[case("{1C0ED62B-D506-4E72-BBC2-A50D3926466E}", false)]
+    #[trace]
+    fn depends_on_tests(#[case] other_id: &str, #[case] expected: bool) {
+        // Arrange
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+        let install = solution
+            .projects
+            .iter()
+            .find(|p| p.name == "logviewer.install")
+            .unwrap();
+
+        // Act
+        let actual = install.depends_on(other_id);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[rstest]
+    #[case("/base", "x", "/base/x")]
+    #[case("/base", r"x\y", "/base/x/y")]
+    #[case("/base", "x/y", "/base/x/y")]
+    #[case("/base", r"x\y/z", "/base/x/y/z")]
+    #[case("/base", r"x/y\z.vcxproj", "/base/x/y/z.vcxproj")]
+    #[case("/base", r"x\\y//z", "/base/x/y/z")]
+    #[case("/base", "/abs/x/a.csproj", "/abs/x/a.csproj")]
+    #[case("/base", r"\abs\x\a.csproj", "/abs/x/a.csproj")]
+    #[case("/base", r"\\server\share\a.csproj", "//server/share/a.csproj")]
+    #[trace]
+    fn local_path_tests(#[case] base: &str, #[case] path: &str, #[case] expected: &str) {
+        // Arrange
+        let d = Path::new(base);
+
+        // Act
+        let actual = api::local_path(d, path);
+
+        // Assert
+        assert_eq!(actual.to_str().unwrap(), expected);
+    }
+
+    #[cfg(target_os = "windows")]
+    #[rstest]
+    #[case(r"C:\base", r"C:\x\a.csproj", r"C:\x\a.csproj")]
+    #[case(r"C:\base", "D:/x/a.csproj", r"D:\x\a.csproj")]
+    #[case(r"C:\base", r"C:x\a.csproj", r"C:x\a.csproj")]
+    #[case(r"C:\base", r"\x\a.csproj", r"\x\a.csproj")]
+    #[case(r"C:\base", r"\\server\share\a.csproj", r"\\server\share\a.csproj")]
+    #[trace]
+    fn local_path_rooted_tests(#[case] base: &str, #[case] path: &str, #[case] expected: &str) {
+        // Arrange
+        let d = Path::new(base);
+
+        // Act
+        let actual = api::local_path(d, path);
+
+        // Assert
+        assert_eq!(actual.to_str().unwrap(), expected);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn project_paths_normalized() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_WEB_SITE).unwrap();
+
+        // Act
+        let actual = solution.project_paths();
+
+        // Assert
+        assert_eq!(
+            vec!["src/a/a.csproj", "b.csproj", "http://localhost:8080/site/"],
+            actual
+        );
+    }
+
+    #[test]
+    fn resolve_existing_file() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_LOCAL_AND_URI_PROJECTS).unwrap();
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        // Act
+        let actual = solution.projects[1].resolve_existing(dir);
+
+        // Assert
+        assert_eq!(
+            api::PathResolution::Exists(dir.join("src").join("lib.rs")),
+            actual
+        );
+    }
+
+    #[test]
+    fn resolve_existing_missing_file() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_LOCAL_AND_URI_PROJECTS).unwrap();
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        // Act
+        let actual = solution.projects[2].resolve_existing(dir);
+
+        // Assert
+        assert_eq!(
+            api::PathResolution::Missing(dir.join("missing").join("b.csproj")),
+            actual
+        );
+    }
+
+    #[test]
+    fn resolve_local_path_escaped_space() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("My Project")).unwrap();
+        std::fs::write(dir.path().join("My Project").join("x.csproj"), "").unwrap();
+
+        // Act
+        let actual = api::resolve_local_path(dir.path(), r"My%20Project\x.csproj");
+
+        // Assert
+        assert_eq!(
+            api::PathResolution::Exists(dir.path().join("My Project").join("x.csproj")),
+            actual
+        );
+    }
+
+    #[test]
+    fn resolve_local_path_genuine_percent_kept() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("My%20Project")).unwrap();
+        std::fs::write(dir.path().join("My%20Project").join("x.csproj"), "").unwrap();
+        std::fs::create_dir(dir.path().join("My Project")).unwrap();
+        std::fs::write(dir.path().join("My Project").join("x.csproj"), "").unwrap();
+
+        // Act
+        let actual = api::resolve_local_path(dir.path(), r"My%20Project\x.csproj");
+
+        // Assert
+        assert_eq!(
+            api::PathResolution::Exists(dir.path().join("My%20Project").join("x.csproj")),
+            actual
+        );
+    }
+
+    #[rstest]
+    #[case("/base", "x", Some(PathBuf::from("/base/x")))]
+    #[case("/base", "/abs/x.csproj", Some(PathBuf::from("/abs/x.csproj")))]
+    #[case("/base", "http://localhost/a.csproj", None)]
+    #[trace]
+    fn resolve_local_path_tests(
+        #[case] base: &str,
+        #[case] path: &str,
+        #[case] expected: Option<PathBuf>,
+    ) {
+        // Arrange
+        let d = Path::new(base);
+
+        // Act
+        let actual = api::resolve_local_path(d, path).into_local_path();
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(0, api::PathResolution::Unresolvable)]
+    #[case(3, api::PathResolution::Uri("http://localhost:8080/site/"))]
+    #[trace]
+    fn resolve_existing_without_file_tests(
+        #[case] index: usize,
+        #[case] expected: api::PathResolution,
+    ) {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_LOCAL_AND_URI_PROJECTS).unwrap();
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        // Act
+        let actual = solution.projects[index].resolve_existing(dir);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("Microsoft Visual Studio Solution File, Format Version 12.00", true)]
+    #[case("Microsoft Visual Studio Project File, Version 12.00", false)]
+    #[case("Visual Studio Solution File, Format Version 12.00", false)]
+    #[trace]
+    fn has_valid_header_tests(#[case] header: &str, #[case] expected: bool) {
+        // Arrange
+        let content = format!("{header}\n{HEADERLESS_SOLUTION}");
+
+        // Act
+        let solution = parse_str(&content).unwrap();
+
+        // Assert
+        assert_eq!(header, solution.header);
+        assert_eq!("12.00", solution.format);
+        assert_eq!(expected, solution.has_valid_header());
+    }
+
+    #[test]
+    fn platforms_per_configuration() {
+        // Arrange
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+        let platforms = BTreeSet::from(["Any CPU", "Mixed Platforms", "x86"]);
+
+        // Act
+        let actual = solution.platforms_per_configuration();
+
+        // Assert
+        assert_eq!(
+            BTreeMap::from([("Debug", platforms.clone()), ("Release", platforms)]),
+            actual
+        );
+    }
+
+    #[rstest]
+    #[case(Some("Debug"), None, vec![("Debug", "Any CPU"), ("Debug", "Mixed Platforms"), ("Debug", "x86")])]
+    #[case(None, Some("x86"), vec![("Debug", "x86"), ("Release", "x86")])]
+    #[case(Some("release"), Some("ANY CPU"), vec![("Release", "Any CPU")])]
+    #[case(Some("Debug"), Some("x64"), vec![])]
+    #[case(None, None, vec![("Debug", "Any CPU"), ("Debug", "Mixed Platforms"), ("Debug", "x86"), ("Release", "Any CPU"), ("Release", "Mixed Platforms"), ("Release", "x86")])]
+    #[trace]
+    fn configurations_matching(
+        #[case] configuration: Option<&str>,
+        #[case] platform: Option<&str>,
+        #[case] expected: Vec<(&str, &str)>,
+    ) {
+        // Arrange
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.configurations_matching(configuration, platform);
+
+        // Assert
+        let actual = actual
+            .iter()
+            .map(|c| (c.configuration, c.platform))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn project_configs_for_solution_configuration() {
+        // Arrange
+        let solution = parse_str(REAL_SOLUTION).unwrap();
+        let debug = api::SolutionConfiguration {
+            configuration: "Debug",
+            platform: "Any CPU",
+        };
+
+        // Act
+        let actual = solution
+            .project_configs_for(&debug)
+            .map(|(p, c)| (p.name, c.configuration))
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(
+            vec![
+                ("logviewer.install", "Debug"),
+                ("logviewer.install.bootstrap", "Debug"),
+                ("logviewer.tests", "Debug"),
+                ("logviewer.logic", "Debug"),
+                ("logviewer.engine", "Debug"),
+                ("logviewer.install.mca", "Debug"),
+                ("logviewer.ui", "Debug"),
+                ("logviewer.bench", "Debug"),
+            ],
+            actual
+        );
+        assert!(solution
+            .project_configs_for(&debug)
+            .all(|(_, c)| c.is_buildable()));
+    }
+
+    #[test]
+    fn orphaned_nested_projects() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_ORPHANED_NESTED_PROJECT).unwrap();
+
+        // Act
+        let actual = solution.orphaned_nested_projects();
+
+        // Assert
+        assert_eq!(
+            vec![(
+                "{3AF54C8A-10BF-4332-9147-F68ED9862032}",
+                "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+            )],
+            actual
+        );
+        assert!(parse_str(SOLUTION_WITH_NESTED_PROJECTS)
+            .unwrap()
+            .orphaned_nested_projects()
+            .is_empty());
+    }
+
+    #[test]
+    fn diverging_build_configurations() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_DIVERGING_BUILD_CONFIGURATION).unwrap();
+
+        // Act
+        let actual = solution
+            .projects
+            .iter()
+            .map(api::Project::diverging_build_configurations)
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(
+            vec![vec![("Debug", "Any CPU", "Debug", "Release")], vec![]],
+            actual
+        );
+    }
+
+    #[test]
+    fn unreferenced_solution_items() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_STALE_SOLUTION_ITEMS).unwrap();
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+        // Act
+        let actual = solution.unreferenced_solution_items(dir);
+
+        // Assert
+        assert_eq!(vec![".editorconfig", "build\\appveyor.yml"], actual);
+    }
+
+    #[test]
+    fn folder_items_and_nested_children() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_NESTED_PROJECTS).unwrap();
+
+        // Act
+        let folders = solution.iterate_folders().collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(2, folders.len());
+        let src = folders.iter().find(|f| f.name == "src").unwrap();
+        assert_eq!(Some(vec!["README.md"]), src.items);
+        let children = solution
+            .nested_children(src.id)
+            .map(|p| p.name)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["a", "tests"], children);
+        let tests = folders.iter().find(|f| f.name == "tests").unwrap();
+        let children = solution
+            .nested_children(tests.id)
+            .map(|p| p.name)
+            .collect::<Vec<_>>();
+        assert_eq!(vec!["b"], children);
+        assert!(!solution
+            .projects
+            .iter()
+            .any(|p| !p.is_folder() && p.items.is_some()));
+    }
+
+    #[test]
+    fn project_tree_text() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_NESTED_PROJECTS).unwrap();
+
+        // Act
+        let actual = solution.project_tree_text();
+
+        // Assert
+        assert_eq!("src/\n  a\n  tests/\n    b\n", actual);
+    }
+
+    #[test]
+    fn project_tree_text_without_nesting() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_GLOBAL_FIRST).unwrap();
+
+        // Act
+        let actual = solution.project_tree_text();
+
+        // Assert
+        assert_eq!("a\nb\n", actual);
+    }
+
+    #[test]
+    fn project_tree_text_with_nesting_cycle() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_NESTING_CYCLE).unwrap();
+
+        // Act
+        let actual = solution.project_tree_text();
+
+        // Assert
+        assert_eq!("a\nx/\n  y/\n", actual);
+    }
+
+    #[test]
+    fn semantically_equal_reordered_projects() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_GLOBAL_FIRST).unwrap();
+        let reordered = parse_str(REORDERED_SOLUTION).unwrap();
+
+        // Act
+        let actual = solution.semantically_equal(&reordered);
+
+        // Assert
+        assert!(actual);
+        assert!(solution.semantic_differences(&reordered).is_empty());
+    }
+
+    #[test]
+    fn semantically_equal_project_added() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_GLOBAL_FIRST).unwrap();
+        let extended = parse_str(REORDERED_SOLUTION_WITH_NEW_PROJECT).unwrap();
+
+        // Act
+        let actual = solution.semantically_equal(&extended);
+
+        // Assert
+        assert!(!actual);
+        assert_eq!(
+            vec!["project c {3AF54C8A-10BF-4332-9147-F68ED9862032} added"],
+            solution.semantic_differences(&extended)
+        );
+    }
+
+    #[test]
+    fn semantically_equal_project_platform_changed() {
+        // Arrange
+        let solution = parse_str(SOLUTION_WITH_GLOBAL_FIRST).unwrap();
+        let changed = SOLUTION_WITH_GLOBAL_FIRST
+            .replace(
+                "{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU",
+                "{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|x86",
+            )
+            .replace(
+                "{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU",
+                "{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|x86",
+            );
+        let changed = parse_str(&changed).unwrap();
+
+        // Act
+        let actual = solution.semantically_equal(&changed);
+
+        // Assert
+        assert!(!actual);
+        assert_eq!(
+            vec!["project a {78965571-A6C2-4161-95B1-813B46610EA7} configurations changed"],
+            solution.semantic_differences(&changed)
+        );
+    }
+
+    #[test]
+    fn parse_str_global_section_before_projects() {
+        // Arrange
+
+        // Act
+        let solution = parse_str(SOLUTION_WITH_GLOBAL_FIRST).unwrap();
+
+        // Assert
+        assert_eq!(2, solution.projects.len());
+        assert!(solution.dangling_project_configurations.is_none());
+        for project in &solution.projects {
+            let configurations = project.configurations.as_ref().unwrap();
+            assert_eq!(2, configurations.len());
+            assert!(configurations
+                .iter()
+                .all(|c| c.tags == vec![api::Tag::Build]));
+        }
+    }
+
+    #[rstest]
+    #[case("{78965571-a6c2-4161-95b1-813b46610ea7}")]
+    #[case("{78965571-A6C2-4161-95B1-813B46610EA7}")]
+    #[case("78965571-a6c2-4161-95b1-813b46610ea7")]
+    #[trace]
+    fn guid_normalized(#[case] id: &str) {
+        // Arrange
+        let solution = parse_str(&SOLUTION_WITH_GLOBAL_FIRST.replace(
+            r#""{78965571-A6C2-4161-95B1-813B46610EA7}""#,
+            &format!(r#""{id}""#),
+        ))
+        .unwrap();
+        let project = solution.projects.iter().find(|p| p.name == "a").unwrap();
+
+        // Act
+        let actual = project.guid_normalized();
+
+        // Assert
+        assert_eq!("{78965571-A6C2-4161-95B1-813B46610EA7}", actual);
+        assert!(solution.dangling_project_configurations.is_none());
+    }
+
+    #[derive(Default)]
+    struct BomDetector {
+        bom: Option<bool>,
+    }
+
+    impl Consume for BomDetector {
+        fn ok(&mut self, solution: &Solution) {
+            self.bom = Some(solution.utf8_bom);
+        }
+
+        fn err(&self, _path: &str) {}
+    }
+
+    #[derive(Default)]
+    struct ProjectsCounter {
+        projects: usize,
+        names: Vec<String>,
+        paths: Vec<String>,
+        failed: std::cell::RefCell<Vec<String>>,
+    }
+
+    impl Consume for ProjectsCounter {
+        fn ok(&mut self, solution: &Solution) {
+            self.projects += solution.projects.len();
+            self.names
+                .extend(solution.projects.iter().map(|p| p.name.to_owned()));
+            self.paths.push(solution.path.to_owned());
+        }
+
+        fn err(&self, path: &str) {
+            self.failed.borrow_mut().push(path.to_owned());
+        }
+    }
+
+    const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 15
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{A61CD222-0F3B-47B6-9F7F-25D658368EEC}"
+EndProject
+  Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{B61CD222-0F3B-47B6-9F7F-25D658368EEC}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_FOR_FILTER: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "src", "src", "{1B5D1A2C-2F1A-4E43-9C8B-4D5A0B9E6F11}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "src\a\a.csproj", "{A61CD222-0F3B-47B6-9F7F-25D658368EEC}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "src\b\b.csproj", "{B61CD222-0F3B-47B6-9F7F-25D658368EEC}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "c", "src\c\c.csproj", "{C61CD222-0F3B-47B6-9F7F-25D658368EEC}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{A61CD222-0F3B-47B6-9F7F-25D658368EEC}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{B61CD222-0F3B-47B6-9F7F-25D658368EEC}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{C61CD222-0F3B-47B6-9F7F-25D658368EEC}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_FILTER: &str = r#"{
+  "solution": {
+    "path": "..\\all.sln",
+    "projects": [
+      "src\\a\\a.csproj",
+      "src/C/c.csproj"
+    ]
+  }
+}"#;
+
+    const CYRILLIC_SOLUTION_FILTER: &str = r#"{
+  "solution": {
+    "path": "all.sln",
+    "projects": [
+      "Проект\\Проект.csproj"
+    ]
+  }
+}"#;
+
+    const REAL_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 15
+VisualStudioVersion = 15.0.26403.0
+MinimumVisualStudioVersion = 10.0.40219.1
+Project("{930C7802-8A8C-48F9-8165-68863BCCD9DD}") = "logviewer.install", "logviewer.install\logviewer.install.wixproj", "{27060CA7-FB29-42BC-BA66-7FC80D498354}"
+	ProjectSection(ProjectDependencies) = postProject
+		{405827CB-84E1-46F3-82C9-D889892645AC} = {405827CB-84E1-46F3-82C9-D889892645AC}
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D} = {CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}
+	EndProjectSection
+EndProject
+Project("{930C7802-8A8C-48F9-8165-68863BCCD9DD}") = "logviewer.install.bootstrap", "logviewer.install.bootstrap\logviewer.install.bootstrap.wixproj", "{1C0ED62B-D506-4E72-BBC2-A50D3926466E}"
+	ProjectSection(ProjectDependencies) = postProject
+		{27060CA7-FB29-42BC-BA66-7FC80D498354} = {27060CA7-FB29-42BC-BA66-7FC80D498354}
+	EndProjectSection
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "solution items", "solution items", "{3B960F8F-AD5D-45E7-92C0-05B65E200AC4}"
+	ProjectSection(SolutionItems) = preProject
+		.editorconfig = .editorconfig
+		appveyor.yml = appveyor.yml
+		logviewer.xml = logviewer.xml
+		WiX.msbuild = WiX.msbuild
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.tests", "logviewer.tests\logviewer.tests.csproj", "{939DD379-CDC8-47EF-8D37-0E5E71D99D30}"
+	ProjectSection(ProjectDependencies) = postProject
+		{383C08FC-9CAC-42E5-9B02-471561479A74} = {383C08FC-9CAC-42E5-9B02-471561479A74}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.logic", "logviewer.logic\logviewer.logic.csproj", "{383C08FC-9CAC-42E5-9B02-471561479A74}"
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = ".nuget", ".nuget", "{B720ED85-58CF-4840-B1AE-55B0049212CC}"
+	ProjectSection(SolutionItems) = preProject
+		.nuget\NuGet.Config = .nuget\NuGet.Config
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.engine", "logviewer.engine\logviewer.engine.csproj", "{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.install.mca", "logviewer.install.mca\logviewer.install.mca.csproj", "{405827CB-84E1-46F3-82C9-D889892645AC}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.ui", "logviewer.ui\logviewer.ui.csproj", "{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.bench", "logviewer.bench\logviewer.bench.csproj", "{75E0C034-44C8-461B-A677-9A19566FE393}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Debug|Mixed Platforms = Debug|Mixed Platforms
+		Debug|x86 = Debug|x86
+		Release|Any CPU = Release|Any CPU
+		Release|Mixed Platforms = Release|Mixed Platforms
+		Release|x86 = Release|x86
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Any CPU.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Mixed Platforms.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Mixed Platforms.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|x86.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|x86.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Any CPU.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Any CPU.Build.0 = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Mixed Platforms.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Mixed Platforms.Build.0 = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|x86.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|x86.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Any CPU.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Mixed Platforms.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Mixed Platforms.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|x86.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|x86.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Any CPU.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Any CPU.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Mixed Platforms.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Mixed Platforms.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|x86.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|x86.Build.0 = Release|x86
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Any CPU.Build.0 = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|x86.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Any CPU.Build.0 = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|x86.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Any CPU.Build.0 = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|x86.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Any CPU.Build.0 = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|x86.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Any CPU.Build.0 = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|x86.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|x86.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Any CPU.Build.0 = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|x86.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|x86.Build.0 = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(SolutionProperties) = preSolution
+		HideSolutionNode = FALSE
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const APR_SOLUTION: &str = r#"Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 2013
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "ALL_BUILD", "ALL_BUILD.vcxproj", "{BBF8893C-A160-3C70-B90B-535F5E3312C9}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+		{68964C8B-1971-3532-88C5-533804C9BFDB} = {68964C8B-1971-3532-88C5-533804C9BFDB}
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4} = {A359F328-78FA-3DD7-ADC4-FA4319B010F4}
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722} = {1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0} = {BBD76E2D-0604-3335-B756-A1D4A90FF9E0}
+		{64126389-3479-392A-8F9A-16A536FB7502} = {64126389-3479-392A-8F9A-16A536FB7502}
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC} = {E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "INSTALL", "INSTALL.vcxproj", "{E8CF42A2-27E7-378D-A954-E757587CCCB5}"
+	ProjectSection(ProjectDependencies) = postProject
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9} = {BBF8893C-A160-3C70-B90B-535F5E3312C9}
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "ZERO_CHECK", "ZERO_CHECK.vcxproj", "{B26E4563-5F01-3488-9242-EAB29C8F9513}"
+	ProjectSection(ProjectDependencies) = postProject
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "apr-1", "apr-1.vcxproj", "{68964C8B-1971-3532-88C5-533804C9BFDB}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC} = {E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "aprapp-1", "aprapp-1.vcxproj", "{A359F328-78FA-3DD7-ADC4-FA4319B010F4}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gen_test_char", "gen_test_char.vcxproj", "{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "libapr-1", "libapr-1.vcxproj", "{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC} = {E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "libaprapp-1", "libaprapp-1.vcxproj", "{64126389-3479-392A-8F9A-16A536FB7502}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "test_char_header", "test_char_header.vcxproj", "{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722} = {1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Win32 = Debug|Win32
+		Release|Win32 = Release|Win32
+		MinSizeRel|Win32 = MinSizeRel|Win32
+		RelWithDebInfo|Win32 = RelWithDebInfo|Win32
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.Debug|Win32.ActiveCfg = Debug|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.Debug|Win32.Build.0 = Debug|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.Release|Win32.ActiveCfg = Release|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.Release|Win32.Build.0 = Release|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{E8CF42A2-27E7-378D-A954-E757587CCCB5}.Debug|Win32.ActiveCfg = Debug|Win32
+		{E8CF42A2-27E7-378D-A954-E757587CCCB5}.Release|Win32.ActiveCfg = Release|Win32
+		{E8CF42A2-27E7-378D-A954-E757587CCCB5}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{E8CF42A2-27E7-378D-A954-E757587CCCB5}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.Debug|Win32.ActiveCfg = Debug|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.Debug|Win32.Build.0 = Debug|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.Release|Win32.ActiveCfg = Release|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.Release|Win32.Build.0 = Release|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.Debug|Win32.ActiveCfg = Debug|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.Debug|Win32.Build.0 = Debug|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.Release|Win32.ActiveCfg = Release|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.Release|Win32.Build.0 = Release|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.Debug|Win32.ActiveCfg = Debug|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.Debug|Win32.Build.0 = Debug|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.Release|Win32.ActiveCfg = Release|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.Release|Win32.Build.0 = Release|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.Debug|Win32.ActiveCfg = Debug|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.Debug|Win32.Build.0 = Debug|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.Release|Win32.ActiveCfg = Release|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.Release|Win32.Build.0 = Release|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.Debug|Win32.ActiveCfg = Debug|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.Debug|Win32.Build.0 = Debug|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.Release|Win32.ActiveCfg = Release|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.Release|Win32.Build.0 = Release|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.Debug|Win32.ActiveCfg = Debug|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.Debug|Win32.Build.0 = Debug|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.Release|Win32.ActiveCfg = Release|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.Release|Win32.Build.0 = Release|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.Debug|Win32.ActiveCfg = Debug|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.Debug|Win32.Build.0 = Debug|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.Release|Win32.ActiveCfg = Release|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.Release|Win32.Build.0 = Release|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+	EndGlobalSection
+	GlobalSection(ExtensibilityGlobals) = postSolution
+		SolutionGuid = {A13EFA7E-93E5-3AA8-85BA-838151D3EF23}
+	EndGlobalSection
+	GlobalSection(ExtensibilityAddIns) = postSolution
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_TFS_BINDINGS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 2013
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(TeamFoundationVersionControl) = preSolution
+		SccNumberOfProjects = 3
+		SccEnterpriseProvider = {4CA58AB2-18FA-4F8D-95D4-32DDF27D184C}
+		SccTeamFoundationServer = http://tfs:8080/tfs/defaultcollection
+		SccLocalPath0 = .
+		SccProjectUniqueName1 = a\\a.csproj
+		SccProjectName1 = a
+		SccLocalPath1 = a
+		SccProjectUniqueName2 = b\\b.csproj
+		SccProjectName2 = b
+		SccLocalPath2 = b
+	EndGlobalSection
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_GLOBAL_FIRST: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.Build.0 = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+"#;
+
+    const SOLUTION_WITH_WEB_SITE: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "folder", "folder", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "src\a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Project("{E24C65DC-7377-472B-9ABA-BC803B73C61A}") = "site", "http://localhost:8080/site/", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const REORDERED_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{d9523f4d-6cb7-4431-85f6-8122f55eb144}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Release|Any CPU = Release|Any CPU
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
+		{d9523f4d-6cb7-4431-85f6-8122f55eb144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{d9523f4d-6cb7-4431-85f6-8122f55eb144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{d9523f4d-6cb7-4431-85f6-8122f55eb144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{d9523f4d-6cb7-4431-85f6-8122f55eb144}.Release|Any CPU.Build.0 = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const REORDERED_SOLUTION_WITH_NEW_PROJECT: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "c", "c\c.csproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.Build.0 = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_LOCAL_AND_URI_PROJECTS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "folder", "folder", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "src\lib.rs", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "missing\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Project("{E24C65DC-7377-472B-9ABA-BC803B73C61A}") = "site", "http://localhost:8080/site/", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_NESTED_PROJECTS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "src", "src", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+	ProjectSection(SolutionItems) = preProject
+		README.md = README.md
+	EndProjectSection
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "tests", "tests", "{1C0ED62B-D506-4E72-BBC2-A50D3926466E}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "src\a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "tests\b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+	ProjectSection(ProjectDependencies) = postProject
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {78965571-A6C2-4161-95B1-813B46610EA7}
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(NestedProjects) = preSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E} = {f619a230-72a6-48b8-b4dc-4d0d3f9b4d3e}
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144} = {1C0ED62B-D506-4E72-BBC2-A50D3926466E}
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_NESTING_CYCLE: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "x", "x", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "y", "y", "{1C0ED62B-D506-4E72-BBC2-A50D3926466E}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(NestedProjects) = preSolution
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E} = {F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}
+		{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E} = {1c0ed62b-d506-4e72-bbc2-a50d3926466e}
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const HEADERLESS_SOLUTION: &str = r#"# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_DIVERGING_BUILD_CONFIGURATION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Debug|x64 = Debug|x64
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|x64.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.Build.0 = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_STALE_SOLUTION_ITEMS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "Solution Items", "Solution Items", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+	ProjectSection(SolutionItems) = preProject
+		.editorconfig = .editorconfig
+		Cargo.toml = Cargo.toml
+		src\lib.rs = src\lib.rs
+		build\appveyor.yml = build\appveyor.yml
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "missing\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const CYRILLIC_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "Проект", "Проект\Проект.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
 }
@@ -1,6 +1,7 @@
 use color_eyre::{eyre::Context, Result};
 use std::{fs::File, io::Read, path::Path};
 
+use itertools::Itertools;
 use serde::Deserialize;
 
 /// Shows whether id specified is ID of
@@ -17,6 +18,13 @@ pub fn is_web_site_project(id: &str) -> bool {
     id == ID_WEB_SITE_PROJECT
 }
 
+/// Shows whether id specified is ID of
+/// Test type project
+#[must_use]
+pub fn is_test_project(id: &str) -> bool {
+    id.eq_ignore_ascii_case(ID_TEST_PROJECT)
+}
+
 /// Describes project by id.
 /// Returns human readable description
 /// or id itself if it's not match any
@@ -43,6 +51,25 @@ pub struct Project {
     /// MSBuild project imports
     #[serde(rename = "Import")]
     pub imports: Option<Vec<Import>>,
+
+    /// MSBuild project property groups
+    #[serde(rename = "PropertyGroup", default)]
+    pub property_group: Option<Vec<PropertyGroup>>,
+}
+
+/// Represents a group of properties within an `MSBuild` project.
+///
+/// Only properties used by analysis are deserialized, all others are ignored.
+#[derive(Debug, Deserialize)]
+pub struct PropertyGroup {
+    #[serde(rename = "TargetFramework", default)]
+    pub target_framework: Option<String>,
+    #[serde(rename = "TargetFrameworks", default)]
+    pub target_frameworks: Option<String>,
+    #[serde(rename = "OutputPath", default)]
+    pub output_path: Option<String>,
+    #[serde(rename = "Condition", default)]
+    pub condition: Option<String>,
 }
 
 /// Represents a group of items within an `MSBuild` project.
@@ -55,10 +82,24 @@ pub struct ItemGroup {
     pub project_reference: Option<Vec<ProjectReference>>,
     #[serde(rename = "PackageReference", default)]
     pub package_reference: Option<Vec<PackageReference>>,
+    /// Files that are neither compiled nor deployed but shown in the project like configs
+    #[serde(rename = "None", default)]
+    pub none: Option<Vec<Item>>,
+    /// Files deployed with the project output like static files
+    #[serde(rename = "Content", default)]
+    pub content: Option<Vec<Item>>,
     #[serde(rename = "Condition", default)]
     pub condition: Option<String>,
 }
 
+/// Generic item that includes a file or a files pattern into the project.
+/// Items that only update metadata of already included files have empty `Include`
+#[derive(Debug, Deserialize)]
+pub struct Item {
+    #[serde(rename = "Include", default)]
+    pub include: String,
+}
+
 /// Represents a group of imported files.
 ///
 /// This field contains a list of `Import` objects, which represent individual
@@ -133,6 +174,15 @@ pub struct Import {
 
 const ID_SOLUTION_FOLDER: &str = "{2150E333-8FDC-42A3-9474-1A3956D46DE8}";
 const ID_WEB_SITE_PROJECT: &str = "{E24C65DC-7377-472B-9ABA-BC803B73C61A}";
+const ID_TEST_PROJECT: &str = "{3AC096D0-A1C2-E12C-1390-A8335801FDAB}";
+
+/// Packages whose reference makes SDK project a test project
+const TEST_PACKAGES: [&str; 4] = [
+    "Microsoft.NET.Test.Sdk",
+    "xunit",
+    "NUnit",
+    "MSTest.TestFramework",
+];
 
 static PROJECT_TYPES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "{CC5FD16D-436D-48AD-A40C-5A424C6E3E79}" => "Azure Project",
@@ -202,6 +252,9 @@ static PROJECT_TYPES: phf::Map<&'static str, &'static str> = phf::phf_map! {
     "{8BB0C5E8-0616-4F60-8E55-A43933E57E9C}" => "LightSwitch",
     "{82B43B9B-A64C-4715-B499-D71E9CA2BD60}" => "Extensibility",
     "{9092AA53-FB77-4645-B42D-1CCCA6BD08BD}" => "Node.js",
+    "{54A90642-561A-4BB1-A94E-469ADEE60C69}" => "JavaScript",
+    "{262852C6-CD72-467D-83FE-5EEB1973A190}" => "JScript",
+    "{888888A0-9F3D-457C-B088-3A5042F75D52}" => "Python",
     "{E53339B2-1760-4266-BCC7-CA923CBCF16C}" => "Docker Application",
     "{00D1A9C2-B5F0-4AF3-8072-F6C62B433612}" => "SQL Server Database",
 };
@@ -228,6 +281,109 @@ impl Project {
                 .iter()
                 .any(|i| i.iter().any(|elt| elt.sdk.is_some()))
     }
+
+    /// Returns target framework(s) defined in the project if any.
+    /// Unconditional property groups win over conditional ones
+    #[must_use]
+    pub fn target_framework(&self) -> Option<&str> {
+        self.property_group
+            .iter()
+            .flatten()
+            .sorted_by_key(|pg| pg.condition.is_some())
+            .find_map(|pg| {
+                pg.target_framework
+                    .as_deref()
+                    .or(pg.target_frameworks.as_deref())
+            })
+    }
+
+    /// Returns `OutputPath` property of the configuration and platform specified
+    /// with `$(Configuration)` and `$(Platform)` substituted. Property groups which condition
+    /// selects the configuration win over unconditional ones. Groups with conditions
+    /// that cannot be evaluated without `MSBuild` are ignored
+    #[must_use]
+    pub fn output_path(&self, configuration: &str, platform: &str) -> Option<String> {
+        self.property_group
+            .iter()
+            .flatten()
+            .filter(|pg| {
+                pg.condition
+                    .as_deref()
+                    .is_none_or(|c| condition_matches(c, configuration, platform))
+            })
+            .sorted_by_key(|pg| pg.condition.is_none())
+            .find_map(|pg| pg.output_path.as_deref())
+            .map(|path| {
+                path.replace("$(Configuration)", configuration)
+                    .replace("$(Platform)", platform)
+            })
+    }
+
+    /// Returns target framework monikers of SDK project split from `TargetFramework`
+    /// or `TargetFrameworks` property. Non SDK projects have no monikers
+    #[must_use]
+    pub fn target_frameworks(&self) -> Vec<String> {
+        if !self.is_sdk_project() {
+            return vec![];
+        }
+        self.target_framework()
+            .into_iter()
+            .flat_map(|tf| tf.split(';'))
+            .map(str::trim)
+            .filter(|tf| !tf.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Returns the number of `None` and `Content` items that include files into the project.
+    /// Items that only update metadata of already included files aren't counted
+    #[must_use]
+    pub fn assets(&self) -> (usize, usize) {
+        let count = |items: fn(&ItemGroup) -> &Option<Vec<Item>>| {
+            self.item_group
+                .iter()
+                .flatten()
+                .filter_map(|ig| items(ig).as_ref())
+                .flatten()
+                .filter(|i| !i.include.is_empty())
+                .count()
+        };
+        (count(|ig| &ig.none), count(|ig| &ig.content))
+    }
+
+    /// Shows whether project is SDK project that references
+    /// test SDK or one of xunit/nunit/mstest frameworks
+    #[must_use]
+    pub fn is_test_project(&self) -> bool {
+        self.is_sdk_project()
+            && self
+                .item_group
+                .iter()
+                .flatten()
+                .filter_map(|ig| ig.package_reference.as_ref())
+                .flatten()
+                .any(|p| {
+                    TEST_PACKAGES
+                        .iter()
+                        .any(|t| t.eq_ignore_ascii_case(&p.name))
+                })
+    }
+}
+
+/// Evaluates the most common property group conditions like
+/// `'$(Configuration)|$(Platform)' == 'Debug|AnyCPU'` or `'$(Configuration)' == 'Debug'`.
+/// Any other condition doesn't match
+fn condition_matches(condition: &str, configuration: &str, platform: &str) -> bool {
+    let condition = condition.replace([' ', '\'', '"'], "");
+    let Some((property, value)) = condition.split_once("==") else {
+        return false;
+    };
+    let expected = match property {
+        "$(Configuration)|$(Platform)" => format!("{configuration}|{platform}"),
+        "$(Configuration)" => configuration.to_owned(),
+        _ => return false,
+    };
+    value.eq_ignore_ascii_case(&expected.replace(' ', ""))
 }
 
 impl PackagesConfig {
@@ -249,7 +405,10 @@ impl PackagesConfig {
 mod tests {
     use std::io::Cursor;
 
+    use rstest::rstest;
+
     use super::*;
+    use crate::fixtures::PROJECT_WITH_ASSETS;
 
     #[test]
     fn read_packages_config_from_reader_test() {
@@ -331,6 +490,30 @@ mod tests {
         assert_eq!(3, p.imports.as_ref().unwrap().len());
     }
 
+    #[test]
+    fn target_frameworks_multiple() {
+        // Arrange
+        let p = Project::from_reader(Cursor::new(SDK_PROJECT_WITH_TARGET_FRAMEWORKS)).unwrap();
+
+        // Act
+        let actual = p.target_frameworks();
+
+        // Assert
+        assert_eq!(vec!["net6.0", "net8.0"], actual);
+    }
+
+    #[test]
+    fn target_frameworks_non_sdk_project() {
+        // Arrange
+        let p = Project::from_reader(Cursor::new(VCXPROJ)).unwrap();
+
+        // Act
+        let actual = p.target_frameworks();
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
     #[test]
     fn sdk_project_default_project() {
         // Arrange
@@ -339,6 +522,7 @@ mod tests {
             item_group: None,
             imports: None,
             import_group: None,
+            property_group: None,
         };
 
         // Act
@@ -356,6 +540,7 @@ mod tests {
             item_group: None,
             imports: None,
             import_group: None,
+            property_group: None,
         };
 
         // Act
@@ -378,6 +563,7 @@ mod tests {
                 label: None,
             }]),
             import_group: None,
+            property_group: None,
         };
 
         // Act
@@ -400,6 +586,7 @@ mod tests {
                 label: None,
             }]),
             import_group: None,
+            property_group: None,
         };
 
         // Act
@@ -409,6 +596,161 @@ mod tests {
         assert!(!actual);
     }
 
+    #[test]
+    fn test_project_real_sdk_project() {
+        // Arrange
+        let rdr = Cursor::new(REAL_SDK_PROJECT);
+        let p = Project::from_reader(rdr).unwrap();
+
+        // Act
+        let actual = p.is_test_project();
+
+        // Assert
+        assert!(actual);
+    }
+
+    #[test]
+    fn test_project_vcxproj() {
+        // Arrange
+        let rdr = Cursor::new(VCXPROJ);
+        let p = Project::from_reader(rdr).unwrap();
+
+        // Act
+        let actual = p.is_test_project();
+
+        // Assert
+        assert!(!actual);
+    }
+
+    #[rstest]
+    #[case("{3AC096D0-A1C2-E12C-1390-A8335801FDAB}", true)]
+    #[case("{3ac096d0-a1c2-e12c-1390-a8335801fdab}", true)]
+    #[case("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}", false)]
+    #[trace]
+    fn is_test_project_tests(#[case] id: &str, #[case] expected: bool) {
+        // Arrange
+
+        // Act
+        let actual = is_test_project(id);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case("{54A90642-561A-4BB1-A94E-469ADEE60C69}", "JavaScript")]
+    #[case("{888888A0-9F3D-457C-B088-3A5042F75D52}", "Python")]
+    #[case("{9092AA53-FB77-4645-B42D-1CCCA6BD08BD}", "Node.js")]
+    #[case("{UNKNOWN}", "{UNKNOWN}")]
+    #[trace]
+    fn describe_project_tests(#[case] id: &str, #[case] expected: &str) {
+        // Arrange
+
+        // Act
+        let actual = describe_project(id);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn target_framework_real_sdk_project() {
+        // Arrange
+        let rdr = Cursor::new(REAL_SDK_PROJECT);
+        let p = Project::from_reader(rdr).unwrap();
+
+        // Act
+        let actual = p.target_framework();
+
+        // Assert
+        assert_eq!(Some("net6.0"), actual);
+    }
+
+    #[test]
+    fn target_framework_vcxproj() {
+        // Arrange
+        let rdr = Cursor::new(VCXPROJ);
+        let p = Project::from_reader(rdr).unwrap();
+
+        // Act
+        let actual = p.target_framework();
+
+        // Assert
+        assert_eq!(None, actual);
+    }
+
+    #[rstest]
+    #[case("Debug", "AnyCPU", Some("bin\\Debug\\"))]
+    #[case("Release", "AnyCPU", Some("..\\out\\Release\\"))]
+    #[case("Release", "x64", Some("bin\\Release\\"))]
+    #[trace]
+    fn output_path_tests(
+        #[case] configuration: &str,
+        #[case] platform: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        // Arrange
+        let rdr = Cursor::new(PROJECT_WITH_OUTPUT_PATHS);
+        let p = Project::from_reader(rdr).unwrap();
+
+        // Act
+        let actual = p.output_path(configuration, platform);
+
+        // Assert
+        assert_eq!(expected.map(str::to_owned), actual);
+    }
+
+    #[test]
+    fn assets_counted() {
+        // Arrange
+        let rdr = Cursor::new(PROJECT_WITH_ASSETS);
+        let p = Project::from_reader(rdr).unwrap();
+
+        // Act
+        let actual = p.assets();
+
+        // Assert
+        assert_eq!((2, 3), actual);
+    }
+
+    #[test]
+    fn assets_not_found() {
+        // Arrange
+        let rdr = Cursor::new(REAL_SDK_PROJECT);
+        let p = Project::from_reader(rdr).unwrap();
+
+        // Act
+        let actual = p.assets();
+
+        // Assert
+        assert_eq!((0, 0), actual);
+    }
+
+    #[test]
+    fn output_path_not_set() {
+        // Arrange
+        let rdr = Cursor::new(SDK_PROJECT_WITH_TARGET_FRAMEWORKS);
+        let p = Project::from_reader(rdr).unwrap();
+
+        // Act
+        let actual = p.output_path("Debug", "AnyCPU");
+
+        // Assert
+        assert_eq!(None, actual);
+    }
+
+    const PROJECT_WITH_OUTPUT_PATHS: &str = r#"<Project ToolsVersion="15.0">
+    <PropertyGroup>
+      <OutputPath>bin\$(Configuration)\</OutputPath>
+    </PropertyGroup>
+    <PropertyGroup Condition=" '$(Configuration)|$(Platform)' == 'Release|AnyCPU' ">
+      <OutputPath>..\out\$(Configuration)\</OutputPath>
+    </PropertyGroup>
+    <PropertyGroup Condition=" '$(TargetFramework)' == 'net48' ">
+      <OutputPath>legacy\</OutputPath>
+    </PropertyGroup>
+  </Project>"#;
+
     const REAL_SDK_PROJECT: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
     <PropertyGroup>
       <TargetFramework>net6.0</TargetFramework>
@@ -447,6 +789,13 @@ mod tests {
     </ItemGroup>
   </Project>"#;
 
+    const SDK_PROJECT_WITH_TARGET_FRAMEWORKS: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
+  <PropertyGroup>
+    <TargetFrameworks> net6.0; net8.0;</TargetFrameworks>
+  </PropertyGroup>
+</Project>
+"#;
+
     const PACKAGES_CONFIG: &str = r#"<?xml version="1.0" encoding="utf-8"?>
     <packages>
       <package id="YaccLexTools" version="0.2.2" targetFramework="net45" />
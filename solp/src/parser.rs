@@ -1,5 +1,6 @@
 use crate::ast::Node;
 use crate::ast::{Conf, Prj, PrjConfAggregate, Sol, Ver};
+use crate::ParseOptions;
 use color_eyre::eyre::{self, Result};
 use itertools::Itertools;
 use std::collections::HashSet;
@@ -49,6 +50,12 @@ trait Visitor<'a> {
 /// string is malformed in a way that violates the assumptions of the parser
 /// or lexer.
 pub fn parse_str(contents: &str) -> Result<Sol> {
+    parse_str_with_options(contents, &ParseOptions::default())
+}
+
+/// Parses a given string as a solution file visiting only sections enabled by options.
+/// See [`parse_str`] for details
+pub fn parse_str_with_options<'a>(contents: &'a str, options: &ParseOptions) -> Result<Sol<'a>> {
     if contents.len() < UTF8_BOM.len() {
         return Err(eyre::eyre!("Content is too short or empty"));
     }
@@ -64,17 +71,28 @@ pub fn parse_str(contents: &str) -> Result<Sol> {
     let lexer = crate::lex::Lexer::new(input);
     match parser.parse(input, lexer) {
         Ok(parsed) => {
-            let solution = Sol::default();
-            let visitor = SolutionVisitor::new();
+            let solution = Sol {
+                space_indented: options.indentation && is_space_indented(input),
+                ..Default::default()
+            };
+            let visitor = SolutionVisitor::new(options);
             Ok(visitor.visit(solution, &parsed))
         }
         Err(e) => Err(eyre::eyre!("{e:?}")),
     }
 }
 
+/// Visual Studio indents sections content using tabs so re-saving
+/// space indented solution rewrites almost every line
+fn is_space_indented(input: &str) -> bool {
+    input
+        .lines()
+        .any(|line| line.starts_with(' ') && !line.trim().is_empty())
+}
+
 macro_rules! section_content {
     ($s:ident, $n:literal) => {{
-        if let Node::Section(begin, content) = $s {
+        if let Node::Section(begin, content, _) = $s {
             begin.is_section($n).then_some(content)
         } else {
             None
@@ -96,26 +114,30 @@ macro_rules! select_section_content {
 }
 
 #[derive(Debug)]
-struct SolutionVisitor {}
+struct SolutionVisitor<'o> {
+    options: &'o ParseOptions,
+}
 
-impl SolutionVisitor {
-    pub fn new() -> Self {
-        Self {}
+impl<'o> SolutionVisitor<'o> {
+    pub fn new(options: &'o ParseOptions) -> Self {
+        Self { options }
     }
 }
 
-impl<'a> Visitor<'a> for SolutionVisitor {
+impl<'a> Visitor<'a> for SolutionVisitor<'_> {
     fn visit(&self, solution: Sol<'a>, node: &Node<'a>) -> Sol<'a> {
         let mut s = solution;
         if let Node::Solution(first_line, lines) = node {
-            if let Node::FirstLine(ver) = first_line.as_ref() {
+            if let Node::FirstLine(header, ver) = first_line.as_ref() {
+                s.header = header;
                 s.format = ver;
             }
 
             s = lines.iter().fold(s, |mut s, line| {
-                s = ProjectVisitor::new().visit(s, line);
+                s = ProjectVisitor::new(self.options.dependencies, self.options.items)
+                    .visit(s, line);
                 s = VersionVisitor::new().visit(s, line);
-                s = GlobalVisitor::new().visit(s, line);
+                s = GlobalVisitor::new(self.options.configurations).visit(s, line);
                 s = CommentVisitor::new().visit(s, line);
                 s
             });
@@ -125,23 +147,33 @@ impl<'a> Visitor<'a> for SolutionVisitor {
 }
 
 #[derive(Debug)]
-struct ProjectVisitor {}
+struct ProjectVisitor {
+    dependencies: bool,
+    items: bool,
+}
 
 impl ProjectVisitor {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(dependencies: bool, items: bool) -> Self {
+        Self {
+            dependencies,
+            items,
+        }
     }
 }
 
 impl<'a> Visitor<'a> for ProjectVisitor {
     fn visit(&self, mut solution: Sol<'a>, node: &Node<'a>) -> Sol<'a> {
-        if let Node::Project(head, sections) = node {
+        if let Node::Project(head, sections, start) = node {
             if let Some(mut p) = Prj::from_begin(head) {
-                let dependencies = select_section_content!(sections, "ProjectDependencies");
-                let items = select_section_content!(sections, "SolutionItems");
-
-                p.items.extend(items);
-                p.depends_from.extend(dependencies);
+                p.start = *start;
+                if self.items {
+                    let items = select_section_content!(sections, "SolutionItems");
+                    p.items.extend(items);
+                }
+                if self.dependencies {
+                    let dependencies = select_section_content!(sections, "ProjectDependencies");
+                    p.depends_from.extend(dependencies);
+                }
                 solution.projects.push(p);
             }
         }
@@ -170,75 +202,105 @@ impl<'a> Visitor<'a> for VersionVisitor {
 
 /// Global section node visitor
 #[derive(Debug)]
-struct GlobalVisitor {}
+struct GlobalVisitor {
+    configurations: bool,
+}
 
 impl GlobalVisitor {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(configurations: bool) -> Self {
+        Self { configurations }
     }
-}
 
-impl<'a> Visitor<'a> for GlobalVisitor {
-    fn visit(&self, mut solution: Sol<'a>, node: &Node<'a>) -> Sol<'a> {
-        if let Node::Global(sections) = node {
-            let configs_and_platforms = sections
-                .iter()
-                .filter_map(|sect| section_content!(sect, "SolutionConfigurationPlatforms"))
-                .flatten()
-                .filter_map(Conf::from_node);
+    fn visit_configurations<'a>(mut solution: Sol<'a>, sections: &[Node<'a>]) -> Sol<'a> {
+        let configs_and_platforms = sections
+            .iter()
+            .filter_map(|sect| section_content!(sect, "SolutionConfigurationPlatforms"))
+            .flatten()
+            .filter_map(Conf::from_node);
 
-            solution.solution_configs.extend(configs_and_platforms);
+        solution.solution_configs.extend(configs_and_platforms);
 
-            let project_config_platform_grp = sections
-                .iter()
-                .filter_map(|sect| section_content!(sect, "ProjectConfigurationPlatforms"))
-                .flatten()
-                .filter_map(PrjConfAggregate::handle_project_config_platform)
-                .chunk_by(|x| x.project_id);
-
-            let project_configs_platforms =
-                project_config_platform_grp
-                    .into_iter()
-                    .map(|(pid, project_configs)| {
-                        let c = project_configs.flat_map(|c| c.configs).collect();
-                        PrjConfAggregate::from_id_and_configs(pid, c)
-                    });
-            solution.project_configs.extend(project_configs_platforms);
-
-            let project_configs = sections
-                .iter()
-                .filter_map(|sect| section_content!(sect, "ProjectConfiguration"))
-                .flatten()
-                .filter_map(PrjConfAggregate::handle_project_config)
-                .chunk_by(|x| x.project_id)
+        let project_config_platform_grp = sections
+            .iter()
+            .filter_map(|sect| section_content!(sect, "ProjectConfigurationPlatforms"))
+            .flatten()
+            .filter_map(PrjConfAggregate::handle_project_config_platform)
+            .chunk_by(|x| x.project_id);
+
+        let project_configs_platforms =
+            project_config_platform_grp
                 .into_iter()
                 .map(|(pid, project_configs)| {
                     let c = project_configs.flat_map(|c| c.configs).collect();
                     PrjConfAggregate::from_id_and_configs(pid, c)
-                })
-                .collect_vec();
+                });
+        solution.project_configs.extend(project_configs_platforms);
 
-            let solution_configurations = sections
+        let project_configs = sections
+            .iter()
+            .filter_map(|sect| section_content!(sect, "ProjectConfiguration"))
+            .flatten()
+            .filter_map(PrjConfAggregate::handle_project_config)
+            .chunk_by(|x| x.project_id)
+            .into_iter()
+            .map(|(pid, project_configs)| {
+                let c = project_configs.flat_map(|c| c.configs).collect();
+                PrjConfAggregate::from_id_and_configs(pid, c)
+            })
+            .collect_vec();
+
+        let solution_configurations = sections
+            .iter()
+            .filter_map(|sect| section_content!(sect, "SolutionConfiguration"))
+            .flatten()
+            .filter_map(|expr| match expr {
+                Node::SectionContent(_, right) => Some(*right),
+                _ => None,
+            })
+            .collect::<HashSet<&str>>();
+
+        let from_project_configurations = project_configs
+            .iter()
+            .flat_map(|pc| pc.configs.iter())
+            .filter(|c| solution_configurations.contains(c.solution_config))
+            .map(|c| Conf::new(c.solution_config, c.platform));
+
+        solution
+            .solution_configs
+            .extend(from_project_configurations);
+
+        solution.project_configs.extend(project_configs);
+        solution
+    }
+}
+
+impl<'a> Visitor<'a> for GlobalVisitor {
+    fn visit(&self, mut solution: Sol<'a>, node: &Node<'a>) -> Sol<'a> {
+        if let Node::Global(sections) = node {
+            if self.configurations {
+                solution = GlobalVisitor::visit_configurations(solution, sections);
+            }
+
+            let source_control = sections
                 .iter()
-                .filter_map(|sect| section_content!(sect, "SolutionConfiguration"))
+                .filter_map(|sect| section_content!(sect, "TeamFoundationVersionControl"))
                 .flatten()
                 .filter_map(|expr| match expr {
-                    Node::SectionContent(_, right) => Some(*right),
+                    Node::SectionContent(left, right) => Some((*left, *right)),
                     _ => None,
-                })
-                .collect::<HashSet<&str>>();
+                });
 
-            let from_project_configurations = project_configs
-                .iter()
-                .flat_map(|pc| pc.configs.iter())
-                .filter(|c| solution_configurations.contains(c.solution_config))
-                .map(|c| Conf::new(c.solution_config, c.platform));
+            solution.source_control.extend(source_control);
 
-            solution
-                .solution_configs
-                .extend(from_project_configurations);
+            let global_sections = sections.iter().filter_map(|sect| match sect {
+                Node::Section(begin, _, raw) => match begin.as_ref() {
+                    Node::SectionBegin(names, _) => Some((names.clone(), *raw)),
+                    _ => None,
+                },
+                _ => None,
+            });
 
-            solution.project_configs.extend(project_configs);
+            solution.global_sections.extend(global_sections);
         }
         solution
     }
@@ -308,6 +370,26 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case(
+        "Global\n\tGlobalSection(a) = preSolution\n\tEndGlobalSection\nEndGlobal\n",
+        false
+    )]
+    #[case(
+        "Global\n    GlobalSection(a) = preSolution\n    EndGlobalSection\nEndGlobal\n",
+        true
+    )]
+    #[case("Global\n\t  GlobalSection(a) = preSolution\nEndGlobal\n", false)]
+    #[case("Global\n   \nEndGlobal\n", false)]
+    #[trace]
+    fn is_space_indented_tests(#[case] content: &str, #[case] expected: bool) {
+        // Act
+        let actual = is_space_indented(content);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
     #[test]
     fn parse_str_real_solution() {
         // Act
@@ -337,6 +419,45 @@ mod tests {
         assert_eq!(solution.product, "Visual Studio 15");
     }
 
+    #[test]
+    fn parse_str_projects_only() {
+        // Arrange
+        let options = ParseOptions {
+            configurations: false,
+            dependencies: false,
+            items: false,
+            ..Default::default()
+        };
+
+        // Act
+        let result = parse_str_with_options(REAL_SOLUTION, &options);
+
+        // Assert
+        let solution = result.unwrap();
+        assert_eq!(solution.projects.len(), 10);
+        assert!(solution.project_configs.is_empty());
+        assert!(solution.solution_configs.is_empty());
+        assert!(solution.projects.iter().all(|p| p.items.is_empty()));
+        assert!(solution.projects.iter().all(|p| p.depends_from.is_empty()));
+        assert!(!solution.global_sections.is_empty());
+    }
+
+    #[test]
+    fn parse_str_default_options_parse_everything() {
+        // Arrange
+        let options = ParseOptions::default();
+
+        // Act
+        let result = parse_str_with_options(REAL_SOLUTION, &options);
+
+        // Assert
+        let solution = result.unwrap();
+        assert!(!solution.project_configs.is_empty());
+        assert!(!solution.solution_configs.is_empty());
+        assert!(solution.projects.iter().any(|p| !p.items.is_empty()));
+        assert!(solution.projects.iter().any(|p| !p.depends_from.is_empty()));
+    }
+
     #[test]
     fn parse_str_no_line_break() {
         // Arrange
@@ -414,6 +535,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_str_blank_lines_and_trailing_whitespaces_inside_project() {
+        // Arrange
+
+        // Act
+        let sln = parse_str(SOLUTION_WITH_BLANK_LINES_AND_TRAILING_WHITESPACES);
+
+        // Assert
+        assert!(sln.is_ok());
+        let solution = sln.unwrap();
+        assert_eq!(2, solution.projects.len());
+        assert_eq!(
+            vec!["{D9523F4D-6CB7-4431-85F6-8122F55EB144}"],
+            solution.projects[0].depends_from
+        );
+        assert_eq!(2, solution.solution_configs.len());
+        assert_eq!("Any CPU", solution.solution_configs[0].platform);
+    }
+
+    #[test]
+    fn parse_str_duplicate_dependencies_preserved() {
+        // Arrange
+
+        // Act
+        let solution = parse_str(SOLUTION_WITH_DUPLICATE_DEPENDENCY).unwrap();
+
+        // Assert
+        assert_eq!(
+            vec![
+                "{78965571-A6C2-4161-95B1-813B46610EA7}",
+                "{78965571-A6C2-4161-95B1-813B46610EA7}"
+            ],
+            solution.projects[1].depends_from
+        );
+    }
+
+    const SOLUTION_WITH_DUPLICATE_DEPENDENCY: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+	ProjectSection(ProjectDependencies) = postProject
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {78965571-A6C2-4161-95B1-813B46610EA7}
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {78965571-A6C2-4161-95B1-813B46610EA7}
+	EndProjectSection
+EndProject
+Global
+EndGlobal
+"#;
+
     const REAL_SOLUTION: &str = r#"
 Microsoft Visual Studio Solution File, Format Version 12.00
 # Visual Studio 15
@@ -749,4 +920,27 @@ Global
 	EndGlobalSection
 EndGlobal
 "#;
+
+    const SOLUTION_WITH_BLANK_LINES_AND_TRAILING_WHITESPACES: &str = "
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project(\"{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}\") = \"a\", \"a\\a.csproj\", \"{78965571-A6C2-4161-95B1-813B46610EA7}\"  
+
+\tProjectSection(ProjectDependencies) = postProject  
+
+\t\t{D9523F4D-6CB7-4431-85F6-8122F55EB144} = {D9523F4D-6CB7-4431-85F6-8122F55EB144}  \t
+
+\tEndProjectSection  
+  
+EndProject  
+Project(\"{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}\") = \"b\", \"b\\b.csproj\", \"{D9523F4D-6CB7-4431-85F6-8122F55EB144}\"
+
+EndProject
+Global
+\tGlobalSection(SolutionConfigurationPlatforms) = preSolution
+\t\tDebug|Any CPU = Debug|Any CPU  
+\t\tRelease|Any CPU = Release|Any CPU\t
+\tEndGlobalSection
+EndGlobal
+";
 }
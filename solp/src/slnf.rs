@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::api::Solution;
+use crate::msbuild;
+
+/// Solution filter i.e. `.slnf` file content
+#[derive(Deserialize, Debug)]
+pub struct SolutionFilter {
+    pub solution: FilteredSolution,
+}
+
+/// Base solution and paths of projects that are loaded from it
+#[derive(Deserialize, Debug)]
+pub struct FilteredSolution {
+    /// Path to base solution relative to filter file directory
+    pub path: String,
+    /// Paths of projects relative to base solution directory
+    #[serde(default)]
+    pub projects: Vec<String>,
+}
+
+impl SolutionFilter {
+    /// Path to base solution resolved against directory of the filter file specified
+    pub fn solution_path(&self, filter_path: &str) -> String {
+        let relative = self
+            .solution
+            .path
+            .replace('\\', std::path::MAIN_SEPARATOR_STR);
+        Path::new(filter_path)
+            .parent()
+            .map_or_else(|| relative.clone().into(), |dir| dir.join(&relative))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Removes all but filtered projects from solution. Solution folders are kept
+    pub fn apply(&self, solution: &mut Solution) {
+        let filtered = self
+            .solution
+            .projects
+            .iter()
+            .map(|p| comparable_path(p))
+            .collect::<HashSet<String>>();
+        solution.projects.retain(|p| {
+            msbuild::is_solution_folder(p.type_id)
+                || filtered.contains(&comparable_path(p.path_or_uri))
+        });
+    }
+}
+
+fn comparable_path(path: &str) -> String {
+    path.replace('/', "\\").to_lowercase()
+}
@@ -0,0 +1,267 @@
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+
+use color_eyre::Report;
+use itertools::Itertools;
+use solp::api::Solution;
+use solp::Consume;
+
+use crate::info::Info;
+use crate::nuget::Nuget;
+use crate::tee::Tee;
+use crate::validate::Validate;
+use crate::StatsJson;
+
+/// Runs info, validation and nuget analysis over every solution in a single pass
+pub struct Analyze {
+    info: Info,
+    validate: Validate,
+    nuget: Nuget,
+    base: Option<PathBuf>,
+}
+
+impl Analyze {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            info: Info::new(),
+            validate: Validate::new(false).with_baseline_recording(true),
+            nuget: Nuget::new(false),
+            base: None,
+        }
+    }
+
+    /// Prints paths relative to the base directory specified
+    #[must_use]
+    pub fn with_base(mut self, base: Option<PathBuf>) -> Self {
+        self.info = self.info.with_base(base.clone());
+        self.validate = self.validate.with_base(base.clone());
+        self.nuget = self.nuget.with_base(base.clone());
+        self.base = base;
+        self
+    }
+
+    /// Whether any solution scanned has nuget packages version mismatches
+    #[must_use]
+    pub fn mismatches_found(&self) -> bool {
+        self.nuget.mismatches_found
+    }
+
+    /// Makes self-contained Markdown report with totals,
+    /// problems found in every solution and nuget packages mismatches
+    #[must_use]
+    pub fn markdown(&self) -> String {
+        let mut md = String::from("# Solutions analysis report\n\n");
+
+        let totals = self.info.summary();
+        md.push_str("## Totals\n\n");
+        md.push_str(&markdown_table(
+            &["Metric", "Value"],
+            [
+                ("solutions", totals.solutions.to_string()),
+                ("projects", totals.projects.to_string()),
+                ("not parsed", totals.not_parsed.to_string()),
+            ]
+            .into_iter()
+            .map(|(metric, value)| vec![metric.to_owned(), value]),
+        ));
+        if !totals.projects_by_type.is_empty() {
+            md.push('\n');
+            md.push_str(&markdown_table(
+                &["Project type", "Count"],
+                totals
+                    .projects_by_type
+                    .iter()
+                    .map(|(t, count)| vec![t.clone(), count.to_string()]),
+            ));
+        }
+
+        md.push_str("\n## Problems\n\n");
+        match self.validate.recorded_baseline() {
+            Some(recorded) if !recorded.is_empty() => md.push_str(&markdown_table(
+                &["Solution", "Rule", "Problem"],
+                recorded.iter().map(|e| {
+                    vec![
+                        crate::relative_to(&e.solution, self.base.as_deref()),
+                        e.rule.clone(),
+                        e.identifier.clone(),
+                    ]
+                }),
+            )),
+            _ => md.push_str("No problems found.\n"),
+        }
+
+        md.push_str("\n## Nuget mismatches\n\n");
+        let mismatched = self.nuget.mismatched_packages();
+        if mismatched.is_empty() {
+            md.push_str("No nuget packages mismatches found.\n");
+        } else {
+            md.push_str(&markdown_table(
+                &["Solution", "Package", "Versions"],
+                mismatched.iter().flat_map(|(path, packages)| {
+                    packages.iter().map(move |(package, versions)| {
+                        vec![path.clone(), package.clone(), versions.iter().join(", ")]
+                    })
+                }),
+            ));
+        }
+        md
+    }
+}
+
+impl Default for Analyze {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Consume for Analyze {
+    fn ok(&mut self, solution: &Solution) {
+        let mut checks = Tee::new(&mut self.validate, &mut self.nuget);
+        Tee::new(&mut self.info, &mut checks).ok(solution);
+    }
+
+    fn err(&self, path: &str) {
+        self.info.err(path);
+        self.validate.err(path);
+        self.nuget.err(path);
+    }
+
+    fn err_with(&self, path: &str, error: &Report) {
+        self.info.err_with(path, error);
+        self.validate.err_with(path, error);
+        self.nuget.err_with(path, error);
+    }
+
+    fn should_stop(&self) -> bool {
+        self.validate.should_stop()
+    }
+}
+
+impl StatsJson for Analyze {
+    fn stats_json(&self) -> String {
+        let section =
+            |json: String| serde_json::from_str::<serde_json::Value>(&json).unwrap_or_default();
+        serde_json::json!({
+            "info": section(self.info.stats_json()),
+            "validate": section(self.validate.stats_json()),
+            "nuget": section(self.nuget.stats_json()),
+        })
+        .to_string()
+    }
+}
+
+impl Display for Analyze {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.info)?;
+        write!(f, "{}", self.validate)?;
+        write!(f, "{}", self.nuget)
+    }
+}
+
+/// Makes GitHub flavored Markdown table escaping pipes inside cells
+fn markdown_table(header: &[&str], rows: impl Iterator<Item = Vec<String>>) -> String {
+    let line = |cells: &[String]| {
+        let cells = cells.iter().map(|c| c.replace('|', "\\|")).join(" | ");
+        format!("| {cells} |\n")
+    };
+    let mut table = line(&header.iter().map(|h| (*h).to_owned()).collect_vec());
+    table.push_str(&format!("|{}\n", " --- |".repeat(header.len())));
+    for row in rows {
+        table.push_str(&line(&row));
+    }
+    table
+}
+
+#[cfg(test)]
+#[cfg(not(target_os = "windows"))]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn all_categories_reported() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::create_dir(dir.path().join("b")).unwrap();
+        fs::write(dir.path().join("a").join("a.csproj"), project("1.0.0")).unwrap();
+        fs::write(dir.path().join("b").join("b.csproj"), project("2.0.0")).unwrap();
+        let path = dir.path().join("analyze.sln");
+        fs::write(&path, SOLUTION).unwrap();
+        let mut analyze = Analyze::new();
+
+        // Act
+        solp::parse_file(path.to_str().unwrap(), &mut analyze).unwrap();
+
+        // Assert
+        let stats = serde_json::from_str::<serde_json::Value>(&analyze.stats_json()).unwrap();
+        assert_eq!(1, stats["info"]["solutions"]);
+        assert_eq!(3, stats["info"]["projects"]);
+        assert_eq!(1, stats["validate"]["not_found"]);
+        assert!(stats["nuget"]["mismatches_found"].as_bool().unwrap());
+        assert!(analyze.mismatches_found());
+    }
+
+    #[test]
+    fn markdown_report_contains_all_sections() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::create_dir(dir.path().join("b")).unwrap();
+        fs::write(dir.path().join("a").join("a.csproj"), project("1.0.0")).unwrap();
+        fs::write(dir.path().join("b").join("b.csproj"), project("2.0.0")).unwrap();
+        let path = dir.path().join("analyze.sln");
+        fs::write(&path, SOLUTION).unwrap();
+        let report = dir.path().join("report.md");
+        let mut analyze = Analyze::new().with_base(Some(dir.path().to_path_buf()));
+
+        // Act
+        solp::parse_file(path.to_str().unwrap(), &mut analyze).unwrap();
+        fs::write(&report, analyze.markdown()).unwrap();
+
+        // Assert
+        let markdown = fs::read_to_string(&report).unwrap();
+        assert!(markdown.contains("## Totals"));
+        assert!(markdown.contains("## Problems"));
+        assert!(markdown.contains("## Nuget mismatches"));
+        assert!(markdown.contains("| solutions | 1 |"));
+        assert!(markdown.contains("| analyze.sln | not-found |"));
+        assert!(markdown.contains("| xunit | 1.0.0, 2.0.0 |"));
+    }
+
+    fn project(version: &str) -> String {
+        format!(
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <ItemGroup>
+    <PackageReference Include="xunit" Version="{version}" />
+  </ItemGroup>
+</Project>
+"#
+        )
+    }
+
+    const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "c", "c\c.csproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug|Any CPU.Build.0 = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+}
@@ -0,0 +1,321 @@
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+use std::fs;
+
+use color_eyre::eyre::Context;
+use color_eyre::Result;
+use comfy_table::{Attribute, Cell};
+use serde::{Deserialize, Serialize};
+
+use crate::ux;
+
+/// Single known-acceptable problem
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Entry {
+    /// Path to the solution that contains the problem. Path separators are always forward slashes
+    pub solution: String,
+    /// Validation rule that reported the problem
+    pub rule: String,
+    /// Rule specific problem identifier
+    pub identifier: String,
+}
+
+impl Entry {
+    /// Deterministic problem identifier made of solution path, rule and rule specific identifier.
+    /// It's the same across runs and platforms for the same problem so tools can track problems with it
+    /// as long as solutions are scanned relative to the same base directory
+    #[must_use]
+    pub fn problem_id(&self) -> String {
+        let solution = normalize_solution_path(&self.solution);
+        let hash = [&solution, &self.rule, &self.identifier]
+            .iter()
+            .flat_map(|part| part.bytes().chain(std::iter::once(0)))
+            .fold(FNV_OFFSET_BASIS, |hash, b| {
+                (hash ^ u64::from(b)).wrapping_mul(FNV_PRIME)
+            });
+        format!("{hash:016x}")
+    }
+}
+
+/// Makes solution path spelled the same on all platforms
+fn normalize_solution_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.trim_start_matches("./").to_owned()
+}
+
+// 64-bit FNV-1a parameters. Std hashers aren't used because their algorithm may change between releases
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Set of known-acceptable problems that must not be reported
+#[derive(Serialize, Deserialize, Debug, Default)]
+#[serde(transparent)]
+pub struct Baseline {
+    entries: BTreeSet<Entry>,
+}
+
+impl Baseline {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads baseline from json file specified
+    pub fn load(path: &str) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read baseline: {path}"))?;
+        serde_json::from_str(&contents)
+            .wrap_err_with(|| format!("Failed to parse baseline: {path}"))
+    }
+
+    /// Saves baseline into json file specified
+    pub fn save(&self, path: &str) -> Result<()> {
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(path, contents).wrap_err_with(|| format!("Failed to write baseline: {path}"))
+    }
+
+    pub fn add(&mut self, solution: &str, rule: &str, identifier: &str) {
+        self.entries.insert(Entry {
+            solution: normalize_solution_path(solution),
+            rule: rule.to_owned(),
+            identifier: identifier.to_owned(),
+        });
+    }
+
+    #[must_use]
+    pub fn contains(&self, solution: &str, rule: &str, identifier: &str) -> bool {
+        self.entries.contains(&Entry {
+            solution: normalize_solution_path(solution),
+            rule: rule.to_owned(),
+            identifier: identifier.to_owned(),
+        })
+    }
+
+    /// Iterates all entries ordered by solution, rule and identifier
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Compares problems found with the baseline. Problems that aren't in the baseline
+    /// are new ones and baseline's problems that weren't found anymore are resolved ones
+    #[must_use]
+    pub fn compare(&self, found: &Baseline) -> Comparison {
+        Comparison {
+            added: found.entries.difference(&self.entries).cloned().collect(),
+            resolved: self.entries.difference(&found.entries).cloned().collect(),
+        }
+    }
+}
+
+/// Result of comparing problems found with a baseline
+#[derive(Debug, Default)]
+pub struct Comparison {
+    /// Problems that are not in the baseline i.e. regressions
+    pub added: Vec<Entry>,
+    /// Baseline's problems that weren't found anymore
+    pub resolved: Vec<Entry>,
+}
+
+impl Comparison {
+    /// Whether there are new problems that aren't in the baseline
+    #[must_use]
+    pub fn has_new_problems(&self) -> bool {
+        !self.added.is_empty()
+    }
+}
+
+fn entries_table(head: &str, color: comfy_table::Color, entries: &[Entry]) -> String {
+    let mut table = ux::new_table();
+    table.set_header([
+        Cell::new(head).add_attribute(Attribute::Bold).fg(color),
+        Cell::new("Id").add_attribute(Attribute::Bold),
+        Cell::new("Rule").add_attribute(Attribute::Bold),
+        Cell::new("Problem").add_attribute(Attribute::Bold),
+    ]);
+    for e in entries {
+        table.add_row([&e.solution, &e.problem_id(), &e.rule, &e.identifier]);
+    }
+    table.to_string()
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.added.is_empty() {
+            writeln!(
+                f,
+                "{}",
+                entries_table("New problems", comfy_table::Color::DarkRed, &self.added)
+            )?;
+        }
+        if !self.resolved.is_empty() {
+            writeln!(
+                f,
+                "{}",
+                entries_table(
+                    "Resolved problems",
+                    comfy_table::Color::DarkGreen,
+                    &self.resolved
+                )
+            )?;
+        }
+        writeln!(
+            f,
+            " {} new, {} resolved problems compared to baseline",
+            self.added.len(),
+            self.resolved.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains_added_entry() {
+        // Arrange
+        let mut baseline = Baseline::new();
+
+        // Act
+        baseline.add("a.sln", "cycles", "{A}");
+
+        // Assert
+        assert!(baseline.contains("a.sln", "cycles", "{A}"));
+        assert!(!baseline.contains("a.sln", "danglings", "{A}"));
+        assert!(!baseline.contains("b.sln", "cycles", "{A}"));
+        assert_eq!(1, baseline.len());
+    }
+
+    #[test]
+    fn problem_id_is_fixed() {
+        // Arrange
+        let mut baseline = Baseline::new();
+        baseline.add("a.sln", "cycles", "{A}");
+
+        // Act
+        let actual = baseline.iter().next().unwrap().problem_id();
+
+        // Assert
+        assert_eq!("907ceb8b072c7309", actual);
+    }
+
+    #[test]
+    fn problem_id_same_across_platforms() {
+        // Arrange
+        let windows = Entry {
+            solution: "src\\a.sln".to_owned(),
+            rule: "cycles".to_owned(),
+            identifier: "{A}".to_owned(),
+        };
+        let unix = Entry {
+            solution: "./src/a.sln".to_owned(),
+            ..windows.clone()
+        };
+
+        // Act
+        let actual = windows.problem_id();
+
+        // Assert
+        assert_eq!(unix.problem_id(), actual);
+    }
+
+    #[test]
+    fn contains_path_spelled_differently() {
+        // Arrange
+        let mut baseline = Baseline::new();
+
+        // Act
+        baseline.add("src\\a.sln", "cycles", "{A}");
+
+        // Assert
+        assert!(baseline.contains("src/a.sln", "cycles", "{A}"));
+        assert_eq!("src/a.sln", baseline.iter().next().unwrap().solution);
+    }
+
+    #[test]
+    fn problem_id_distinguishes_entries() {
+        // Arrange
+        let mut baseline = Baseline::new();
+        baseline.add("a.sln", "cycles", "{A}");
+        baseline.add("a.sln", "danglings", "{A}");
+        baseline.add("b.sln", "cycles", "{A}");
+        baseline.add("a.sln", "cyclesa.sln", "");
+
+        // Act
+        let ids = baseline
+            .iter()
+            .map(Entry::problem_id)
+            .collect::<BTreeSet<_>>();
+
+        // Assert
+        assert_eq!(4, ids.len());
+        assert!(ids.iter().all(|id| id.len() == 16));
+    }
+
+    #[test]
+    fn compare_added_problems() {
+        // Arrange
+        let mut baseline = Baseline::new();
+        baseline.add("a.sln", "cycles", "{A}");
+        let mut found = Baseline::new();
+        found.add("a.sln", "cycles", "{A}");
+        found.add("a.sln", "danglings", "{B}");
+
+        // Act
+        let actual = baseline.compare(&found);
+
+        // Assert
+        assert!(actual.has_new_problems());
+        assert_eq!(1, actual.added.len());
+        assert_eq!("danglings", actual.added[0].rule);
+        assert!(actual.resolved.is_empty());
+    }
+
+    #[test]
+    fn compare_resolved_problems() {
+        // Arrange
+        let mut baseline = Baseline::new();
+        baseline.add("a.sln", "cycles", "{A}");
+        baseline.add("a.sln", "danglings", "{B}");
+        let mut found = Baseline::new();
+        found.add("a.sln", "cycles", "{A}");
+
+        // Act
+        let actual = baseline.compare(&found);
+
+        // Assert
+        assert!(!actual.has_new_problems());
+        assert_eq!(1, actual.resolved.len());
+        assert_eq!("{B}", actual.resolved[0].identifier);
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        // Arrange
+        let mut baseline = Baseline::new();
+        baseline.add("a.sln", "missings", "{A}|Debug|x86");
+        baseline.add("a.sln", "missings", "{A}|Debug|x86");
+
+        // Act
+        let json = serde_json::to_string(&baseline).unwrap();
+        let actual: Baseline = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert_eq!(
+            r#"[{"solution":"a.sln","rule":"missings","identifier":"{A}|Debug|x86"}]"#,
+            json
+        );
+        assert_eq!(1, actual.len());
+        assert!(actual.contains("a.sln", "missings", "{A}|Debug|x86"));
+    }
+}
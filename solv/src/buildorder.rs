@@ -0,0 +1,425 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+
+use crossterm::style::Stylize;
+use itertools::Itertools;
+use solp::api::{Project, Solution};
+use solp::{msbuild, Consume};
+
+use crate::error::Collector;
+use crate::{graph, loader, StatsJson};
+
+/// Prints project paths in the order they should be built i.e. dependencies first
+pub struct BuildOrder {
+    pub cycles_found: bool,
+    pub unknown_root: bool,
+    errors: RefCell<Collector>,
+    base: Option<PathBuf>,
+    root: Option<String>,
+    depth: Option<usize>,
+}
+
+impl BuildOrder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cycles_found: false,
+            unknown_root: false,
+            errors: RefCell::new(Collector::new()),
+            base: None,
+            root: None,
+            depth: None,
+        }
+    }
+
+    /// Prints only the root project specified by id and projects it depends on
+    #[must_use]
+    pub fn with_root(mut self, root: Option<String>) -> Self {
+        self.root = root;
+        self
+    }
+
+    /// Limits the number of dependency levels printed from the root project
+    #[must_use]
+    pub fn with_depth(mut self, depth: Option<usize>) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Prints paths relative to the base directory specified
+    #[must_use]
+    pub fn with_base(mut self, base: Option<PathBuf>) -> Self {
+        self.base = base;
+        self
+    }
+}
+
+impl Default for BuildOrder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reasons why build order cannot be made
+#[derive(Debug, PartialEq, Eq)]
+pub enum BuildOrderError {
+    /// Names of the projects that form a cycle
+    Cycle(Vec<String>),
+    /// Root project id that solution has no project with
+    UnknownRoot(String),
+}
+
+/// Returns paths of the solution's projects (except solution folders) in build order.
+/// Returns names of the projects that form a cycle as error
+pub fn build_order(solution: &Solution) -> Result<Vec<String>, BuildOrderError> {
+    build_order_from(solution, None, None)
+}
+
+/// Same as [`build_order`] but if the root project id is specified returns only the root
+/// and projects it depends on not deeper than the depth specified.
+/// Returns error if solution has no project with root id
+pub fn build_order_from(
+    solution: &Solution,
+    root: Option<&str>,
+    depth: Option<usize>,
+) -> Result<Vec<String>, BuildOrderError> {
+    let projects: HashMap<&str, &Project> =
+        solution.iterate_projects().map(|p| (p.id, p)).collect();
+    let dir = crate::parent_of(solution.path);
+    let scope = match root {
+        Some(root) => {
+            let Some(root_id) = projects.keys().find(|id| id.eq_ignore_ascii_case(root)) else {
+                return Err(BuildOrderError::UnknownRoot(root.to_owned()));
+            };
+            let mut scope: HashSet<&str> = graph::dependency_closure(solution, root, depth)
+                .into_iter()
+                .collect();
+            scope.insert(*root_id);
+            Some(scope)
+        }
+        None => None,
+    };
+
+    match graph::topological_order(solution) {
+        Ok(order) => Ok(order
+            .into_iter()
+            .filter(|id| scope.as_ref().is_none_or(|scope| scope.contains(id)))
+            .filter_map(|id| projects.get(id))
+            .map(|p| {
+                loader::project_path(dir, p).map_or_else(
+                    || p.path_or_uri.to_owned(),
+                    |path| path.to_string_lossy().into_owned(),
+                )
+            })
+            .collect()),
+        Err(cycle) => Err(BuildOrderError::Cycle(
+            cycle
+                .into_iter()
+                .map(|id| {
+                    solution
+                        .projects
+                        .iter()
+                        .find(|p| p.id == id && !msbuild::is_solution_folder(p.type_id))
+                        .map_or_else(|| id.to_owned(), |p| p.name.to_owned())
+                })
+                .collect(),
+        )),
+    }
+}
+
+impl Consume for BuildOrder {
+    fn ok(&mut self, solution: &Solution) {
+        match build_order_from(solution, self.root.as_deref(), self.depth) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("{}", crate::relative_to(&path, self.base.as_deref()));
+                }
+            }
+            Err(BuildOrderError::Cycle(cycle)) => {
+                self.cycles_found = true;
+                eprintln!(
+                    "{} {}: {}",
+                    crate::relative_to(solution.path, self.base.as_deref()),
+                    "contains project dependencies cycle".dark_red().bold(),
+                    cycle.iter().join(" -> ")
+                );
+            }
+            Err(BuildOrderError::UnknownRoot(root)) => {
+                self.unknown_root = true;
+                eprintln!(
+                    "{} {}: {root}",
+                    crate::relative_to(solution.path, self.base.as_deref()),
+                    "has no root project".dark_red().bold(),
+                );
+            }
+        }
+    }
+
+    fn err(&self, path: &str) {
+        self.errors.borrow_mut().add_path(path);
+    }
+}
+
+impl StatsJson for BuildOrder {
+    fn stats_json(&self) -> String {
+        serde_json::json!({
+            "cycles_found": self.cycles_found,
+            "unknown_root": self.unknown_root,
+            "not_parsed": self.errors.borrow().count(),
+        })
+        .to_string()
+    }
+}
+
+impl Display for BuildOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.errors.borrow().count() > 0 {
+            write!(f, "{}", self.errors.borrow())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_order_dependencies_first() {
+        // Arrange
+        let solution = solp::parse_str(APR_SOLUTION).unwrap();
+
+        // Act
+        let actual = build_order(&solution).unwrap();
+
+        // Assert
+        assert_eq!(9, actual.len());
+        let position = |name: &str| actual.iter().position(|p| p == name).unwrap();
+        let zero_check = position("ZERO_CHECK.vcxproj");
+        assert!(zero_check < position("ALL_BUILD.vcxproj"));
+        assert!(zero_check < position("INSTALL.vcxproj"));
+        assert!(zero_check < position("apr-1.vcxproj"));
+        assert!(position("ALL_BUILD.vcxproj") < position("INSTALL.vcxproj"));
+    }
+
+    #[test]
+    fn build_order_cycle() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_CYCLE).unwrap();
+
+        // Act
+        let actual = build_order(&solution);
+
+        // Assert
+        let Err(BuildOrderError::Cycle(mut cycle)) = actual else {
+            panic!("cycle expected");
+        };
+        cycle.sort();
+        assert_eq!(vec!["a", "b"], cycle);
+    }
+
+    #[test]
+    fn build_order_from_unknown_root() {
+        // Arrange
+        let solution = solp::parse_str(APR_SOLUTION).unwrap();
+
+        // Act
+        let actual = build_order_from(
+            &solution,
+            Some("{00000000-0000-0000-0000-000000000000}"),
+            None,
+        );
+
+        // Assert
+        assert_eq!(
+            Err(BuildOrderError::UnknownRoot(
+                "{00000000-0000-0000-0000-000000000000}".to_owned()
+            )),
+            actual
+        );
+    }
+
+    #[test]
+    fn build_order_from_root_with_depth() {
+        // Arrange
+        let solution = solp::parse_str(APR_SOLUTION).unwrap();
+
+        // Act
+        let actual = build_order_from(
+            &solution,
+            Some("{68964C8B-1971-3532-88C5-533804C9BFDB}"),
+            Some(1),
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(3, actual.len());
+        assert_eq!("apr-1.vcxproj", actual[2]);
+        assert!(actual.contains(&"ZERO_CHECK.vcxproj".to_owned()));
+        assert!(actual.contains(&"test_char_header.vcxproj".to_owned()));
+    }
+
+    const SOLUTION_WITH_CYCLE: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+	ProjectSection(ProjectDependencies) = postProject
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144} = {D9523F4D-6CB7-4431-85F6-8122F55EB144}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+	ProjectSection(ProjectDependencies) = postProject
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {78965571-A6C2-4161-95B1-813B46610EA7}
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const APR_SOLUTION: &str = r#"Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 2013
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "ALL_BUILD", "ALL_BUILD.vcxproj", "{BBF8893C-A160-3C70-B90B-535F5E3312C9}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+		{68964C8B-1971-3532-88C5-533804C9BFDB} = {68964C8B-1971-3532-88C5-533804C9BFDB}
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4} = {A359F328-78FA-3DD7-ADC4-FA4319B010F4}
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722} = {1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0} = {BBD76E2D-0604-3335-B756-A1D4A90FF9E0}
+		{64126389-3479-392A-8F9A-16A536FB7502} = {64126389-3479-392A-8F9A-16A536FB7502}
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC} = {E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "INSTALL", "INSTALL.vcxproj", "{E8CF42A2-27E7-378D-A954-E757587CCCB5}"
+	ProjectSection(ProjectDependencies) = postProject
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9} = {BBF8893C-A160-3C70-B90B-535F5E3312C9}
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "ZERO_CHECK", "ZERO_CHECK.vcxproj", "{B26E4563-5F01-3488-9242-EAB29C8F9513}"
+	ProjectSection(ProjectDependencies) = postProject
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "apr-1", "apr-1.vcxproj", "{68964C8B-1971-3532-88C5-533804C9BFDB}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC} = {E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "aprapp-1", "aprapp-1.vcxproj", "{A359F328-78FA-3DD7-ADC4-FA4319B010F4}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gen_test_char", "gen_test_char.vcxproj", "{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "libapr-1", "libapr-1.vcxproj", "{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC} = {E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "libaprapp-1", "libaprapp-1.vcxproj", "{64126389-3479-392A-8F9A-16A536FB7502}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "test_char_header", "test_char_header.vcxproj", "{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}"
+	ProjectSection(ProjectDependencies) = postProject
+		{B26E4563-5F01-3488-9242-EAB29C8F9513} = {B26E4563-5F01-3488-9242-EAB29C8F9513}
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722} = {1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Win32 = Debug|Win32
+		Release|Win32 = Release|Win32
+		MinSizeRel|Win32 = MinSizeRel|Win32
+		RelWithDebInfo|Win32 = RelWithDebInfo|Win32
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.Debug|Win32.ActiveCfg = Debug|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.Debug|Win32.Build.0 = Debug|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.Release|Win32.ActiveCfg = Release|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.Release|Win32.Build.0 = Release|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{BBF8893C-A160-3C70-B90B-535F5E3312C9}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{E8CF42A2-27E7-378D-A954-E757587CCCB5}.Debug|Win32.ActiveCfg = Debug|Win32
+		{E8CF42A2-27E7-378D-A954-E757587CCCB5}.Release|Win32.ActiveCfg = Release|Win32
+		{E8CF42A2-27E7-378D-A954-E757587CCCB5}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{E8CF42A2-27E7-378D-A954-E757587CCCB5}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.Debug|Win32.ActiveCfg = Debug|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.Debug|Win32.Build.0 = Debug|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.Release|Win32.ActiveCfg = Release|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.Release|Win32.Build.0 = Release|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{B26E4563-5F01-3488-9242-EAB29C8F9513}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.Debug|Win32.ActiveCfg = Debug|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.Debug|Win32.Build.0 = Debug|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.Release|Win32.ActiveCfg = Release|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.Release|Win32.Build.0 = Release|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{68964C8B-1971-3532-88C5-533804C9BFDB}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.Debug|Win32.ActiveCfg = Debug|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.Debug|Win32.Build.0 = Debug|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.Release|Win32.ActiveCfg = Release|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.Release|Win32.Build.0 = Release|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{A359F328-78FA-3DD7-ADC4-FA4319B010F4}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.Debug|Win32.ActiveCfg = Debug|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.Debug|Win32.Build.0 = Debug|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.Release|Win32.ActiveCfg = Release|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.Release|Win32.Build.0 = Release|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{1276D7BA-8FF1-38C1-A6B9-6068D5E5B722}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.Debug|Win32.ActiveCfg = Debug|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.Debug|Win32.Build.0 = Debug|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.Release|Win32.ActiveCfg = Release|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.Release|Win32.Build.0 = Release|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{BBD76E2D-0604-3335-B756-A1D4A90FF9E0}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.Debug|Win32.ActiveCfg = Debug|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.Debug|Win32.Build.0 = Debug|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.Release|Win32.ActiveCfg = Release|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.Release|Win32.Build.0 = Release|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{64126389-3479-392A-8F9A-16A536FB7502}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.Debug|Win32.ActiveCfg = Debug|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.Debug|Win32.Build.0 = Debug|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.Release|Win32.ActiveCfg = Release|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.Release|Win32.Build.0 = Release|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.MinSizeRel|Win32.ActiveCfg = MinSizeRel|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.MinSizeRel|Win32.Build.0 = MinSizeRel|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.RelWithDebInfo|Win32.ActiveCfg = RelWithDebInfo|Win32
+		{E66A19F4-86EC-35C1-B2CF-985D6AC8E7DC}.RelWithDebInfo|Win32.Build.0 = RelWithDebInfo|Win32
+	EndGlobalSection
+	GlobalSection(ExtensibilityGlobals) = postSolution
+		SolutionGuid = {A13EFA7E-93E5-3AA8-85BA-838151D3EF23}
+	EndGlobalSection
+	GlobalSection(ExtensibilityAddIns) = postSolution
+	EndGlobalSection
+EndGlobal
+"#;
+}
@@ -0,0 +1,227 @@
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+
+use comfy_table::{Attribute, Cell};
+use crossterm::style::Stylize;
+use itertools::Itertools;
+use solp::api::Solution;
+use solp::Consume;
+
+use crate::error::Collector;
+use crate::validate::MIXED_PLATFORMS;
+use crate::{ux, StatsJson};
+
+/// Platforms that Visual Studio and `CMake` create. All others are considered unusual
+const USUAL_PLATFORMS: [&str; 7] = [
+    "Any CPU",
+    "x86",
+    "x64",
+    "Win32",
+    "ARM",
+    "ARM64",
+    MIXED_PLATFORMS,
+];
+
+/// Configuration sprawl of a single solution
+#[derive(Debug, PartialEq, Eq)]
+struct GridRow {
+    solution: String,
+    configurations: usize,
+    platforms: usize,
+    mixed_platforms: bool,
+    unusual_platforms: BTreeSet<String>,
+}
+
+impl GridRow {
+    fn new(solution: &Solution, path: String) -> Self {
+        let platforms = solution
+            .configurations
+            .iter()
+            .map(|c| c.platform)
+            .collect::<BTreeSet<&str>>();
+        Self {
+            solution: path,
+            configurations: solution.configurations.len(),
+            platforms: platforms.len(),
+            mixed_platforms: platforms
+                .iter()
+                .any(|p| p.eq_ignore_ascii_case(MIXED_PLATFORMS)),
+            unusual_platforms: platforms
+                .iter()
+                .filter(|p| !USUAL_PLATFORMS.iter().any(|u| u.eq_ignore_ascii_case(p)))
+                .map(|p| (*p).to_owned())
+                .collect(),
+        }
+    }
+}
+
+/// Prints solutions configurations grid i.e. a row per solution with the number
+/// of its configurations and platforms and flags of Mixed Platforms and unusual platforms presence
+pub struct Configs {
+    rows: Vec<GridRow>,
+    errors: RefCell<Collector>,
+    base: Option<PathBuf>,
+}
+
+impl Configs {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            rows: vec![],
+            errors: RefCell::new(Collector::new()),
+            base: None,
+        }
+    }
+
+    /// Prints paths relative to the base directory specified
+    #[must_use]
+    pub fn with_base(mut self, base: Option<PathBuf>) -> Self {
+        self.base = base;
+        self
+    }
+}
+
+impl Default for Configs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Consume for Configs {
+    fn ok(&mut self, solution: &Solution) {
+        let path = crate::relative_to(solution.path, self.base.as_deref());
+        self.rows.push(GridRow::new(solution, path));
+    }
+
+    fn err(&self, path: &str) {
+        self.errors.borrow_mut().add_path(path);
+    }
+}
+
+impl StatsJson for Configs {
+    fn stats_json(&self) -> String {
+        serde_json::json!({
+            "solutions": self.rows.len(),
+            "with_mixed_platforms": self.rows.iter().filter(|r| r.mixed_platforms).count(),
+            "with_unusual_platforms": self.rows.iter().filter(|r| !r.unusual_platforms.is_empty()).count(),
+            "not_parsed": self.errors.borrow().count(),
+        })
+        .to_string()
+    }
+}
+
+impl Display for Configs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.rows.is_empty() {
+            writeln!(f, " {}", "No solutions found.".dark_yellow().bold())?;
+            return write!(f, "{}", self.errors.borrow());
+        }
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Solution").add_attribute(Attribute::Bold),
+            Cell::new("Configurations").add_attribute(Attribute::Bold),
+            Cell::new("Platforms").add_attribute(Attribute::Bold),
+            Cell::new("Mixed Platforms").add_attribute(Attribute::Bold),
+            Cell::new("Unusual platforms").add_attribute(Attribute::Bold),
+        ]);
+        for row in self.rows.iter().sorted_by(|a, b| {
+            b.configurations
+                .cmp(&a.configurations)
+                .then_with(|| a.solution.cmp(&b.solution))
+        }) {
+            table.add_row([
+                Cell::new(&row.solution),
+                Cell::new(row.configurations),
+                Cell::new(row.platforms),
+                Cell::new(if row.mixed_platforms { "yes" } else { "" }),
+                Cell::new(row.unusual_platforms.iter().join(", ")),
+            ]);
+        }
+        writeln!(f, "{table}")?;
+        write!(f, "{}", self.errors.borrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_rows_per_solution() {
+        // Arrange
+        let mut small = solp::parse_str(SOLUTION).unwrap();
+        small.path = "/repo/a/a.sln";
+        let mut sprawling = solp::parse_str(SPRAWLING_SOLUTION).unwrap();
+        sprawling.path = "/repo/b/b.sln";
+        let mut configs = Configs::new();
+
+        // Act
+        configs.ok(&small);
+        configs.ok(&sprawling);
+
+        // Assert
+        assert_eq!(
+            vec![
+                GridRow {
+                    solution: "/repo/a/a.sln".to_owned(),
+                    configurations: 2,
+                    platforms: 1,
+                    mixed_platforms: false,
+                    unusual_platforms: BTreeSet::new(),
+                },
+                GridRow {
+                    solution: "/repo/b/b.sln".to_owned(),
+                    configurations: 6,
+                    platforms: 3,
+                    mixed_platforms: true,
+                    unusual_platforms: BTreeSet::from(["Xbox".to_owned()]),
+                },
+            ],
+            configs.rows
+        );
+        let stats = serde_json::from_str::<serde_json::Value>(&configs.stats_json()).unwrap();
+        assert_eq!(2, stats["solutions"]);
+        assert_eq!(1, stats["with_mixed_platforms"]);
+        assert_eq!(1, stats["with_unusual_platforms"]);
+    }
+
+    const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SPRAWLING_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "b", "b\b.vcxproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Mixed Platforms = Debug|Mixed Platforms
+		Debug|Win32 = Debug|Win32
+		Debug|Xbox = Debug|Xbox
+		Release|Mixed Platforms = Release|Mixed Platforms
+		Release|Win32 = Release|Win32
+		Release|Xbox = Release|Xbox
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Win32.ActiveCfg = Debug|Win32
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Win32.ActiveCfg = Release|Win32
+	EndGlobalSection
+EndGlobal
+"#;
+}
@@ -0,0 +1,245 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::PathBuf;
+
+use comfy_table::{Attribute, Cell};
+use crossterm::style::Stylize;
+use itertools::Itertools;
+use solp::api::Solution;
+use solp::Consume;
+
+use crate::error::Collector;
+use crate::{ux, StatsJson};
+
+const EXTENSIBILITY_GLOBALS: &str = "ExtensibilityGlobals";
+const SOLUTION_GUID: &str = "SolutionGuid";
+
+/// Finds solutions that are copies of each other i.e. have the same `SolutionGuid`
+/// or the same structure (projects and configurations) if there is no `SolutionGuid`
+pub struct Duplicates {
+    solutions: u64,
+    groups: BTreeMap<String, Vec<String>>,
+    errors: RefCell<Collector>,
+    base: Option<PathBuf>,
+}
+
+impl Duplicates {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            solutions: 0,
+            groups: BTreeMap::new(),
+            errors: RefCell::new(Collector::new()),
+            base: None,
+        }
+    }
+
+    /// Prints paths relative to the base directory specified
+    #[must_use]
+    pub fn with_base(mut self, base: Option<PathBuf>) -> Self {
+        self.base = base;
+        self
+    }
+
+    fn duplicates(&self) -> impl Iterator<Item = (&String, &Vec<String>)> {
+        self.groups.iter().filter(|(_, paths)| paths.len() > 1)
+    }
+}
+
+impl Default for Duplicates {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `SolutionGuid` from `ExtensibilityGlobals` section if any
+fn solution_guid<'a>(solution: &Solution<'a>) -> Option<&'a str> {
+    solution
+        .raw_section(EXTENSIBILITY_GLOBALS)?
+        .lines()
+        .find_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            (key.trim() == SOLUTION_GUID).then(|| value.trim())
+        })
+}
+
+/// Hashes solution structure ignoring its path and projects order
+fn structural_hash(solution: &Solution) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    solution.format.hash(&mut hasher);
+    for project in solution.projects.iter().sorted_by_key(|p| p.id) {
+        project.type_id.hash(&mut hasher);
+        project.id.hash(&mut hasher);
+        project.name.hash(&mut hasher);
+        project.path_or_uri.hash(&mut hasher);
+        project.depends_from.hash(&mut hasher);
+        for c in project.configurations.iter().flatten() {
+            c.configuration.hash(&mut hasher);
+            c.solution_configuration.hash(&mut hasher);
+            c.platform.hash(&mut hasher);
+            c.tags.hash(&mut hasher);
+        }
+    }
+    for c in &solution.configurations {
+        c.configuration.hash(&mut hasher);
+        c.platform.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn identity(solution: &Solution) -> String {
+    solution_guid(solution).map_or_else(
+        || format!("hash {:016x}", structural_hash(solution)),
+        |guid| format!("guid {}", guid.to_uppercase()),
+    )
+}
+
+impl Consume for Duplicates {
+    fn ok(&mut self, solution: &Solution) {
+        self.solutions += 1;
+        self.groups
+            .entry(identity(solution))
+            .or_default()
+            .push(crate::relative_to(solution.path, self.base.as_deref()));
+    }
+
+    fn err(&self, path: &str) {
+        self.errors.borrow_mut().add_path(path);
+    }
+}
+
+impl StatsJson for Duplicates {
+    fn stats_json(&self) -> String {
+        serde_json::json!({
+            "solutions": self.solutions,
+            "duplicate_groups": self.duplicates().count(),
+            "not_parsed": self.errors.borrow().count(),
+        })
+        .to_string()
+    }
+}
+
+impl Display for Duplicates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut found = false;
+        for (identity, paths) in self.duplicates() {
+            found = true;
+            let mut table = ux::new_table();
+            table.set_header([Cell::new(identity).add_attribute(Attribute::Bold)]);
+            for path in paths {
+                table.add_row([Cell::new(path)]);
+            }
+            writeln!(f, "{table}")?;
+        }
+        if !found {
+            writeln!(
+                f,
+                " {}",
+                "No duplicate solutions found.".dark_green().bold()
+            )?;
+        }
+        write!(f, "{}", self.errors.borrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SINGLE_PROJECT_SOLUTION;
+
+    #[test]
+    fn identical_solutions_in_different_directories() {
+        // Arrange
+        let mut first = solp::parse_str(SOLUTION).unwrap();
+        first.path = "/repo/a/s.sln";
+        let mut copy = solp::parse_str(SOLUTION).unwrap();
+        copy.path = "/repo/b/s.sln";
+        let mut other = solp::parse_str(SINGLE_PROJECT_SOLUTION).unwrap();
+        other.path = "/repo/c/s.sln";
+        let mut duplicates = Duplicates::new();
+
+        // Act
+        duplicates.ok(&first);
+        duplicates.ok(&copy);
+        duplicates.ok(&other);
+
+        // Assert
+        let groups = duplicates.duplicates().collect::<Vec<_>>();
+        assert_eq!(1, groups.len());
+        assert_eq!(&vec!["/repo/a/s.sln", "/repo/b/s.sln"], groups[0].1);
+        let stats = serde_json::from_str::<serde_json::Value>(&duplicates.stats_json()).unwrap();
+        assert_eq!(3, stats["solutions"]);
+        assert_eq!(1, stats["duplicate_groups"]);
+    }
+
+    #[test]
+    fn same_solution_guid_wins_over_structure() {
+        // Arrange
+        let mut first = solp::parse_str(SOLUTION_WITH_GUID).unwrap();
+        first.path = "/repo/a/s.sln";
+        let mut modified = solp::parse_str(MODIFIED_SOLUTION_WITH_GUID).unwrap();
+        modified.path = "/repo/b/s.sln";
+        let mut duplicates = Duplicates::new();
+
+        // Act
+        duplicates.ok(&first);
+        duplicates.ok(&modified);
+
+        // Assert
+        let groups = duplicates.duplicates().collect::<Vec<_>>();
+        assert_eq!(1, groups.len());
+        assert_eq!("guid {0A3B1F5C-9E51-4F6D-8C11-3C7E2D5A9B01}", groups[0].0);
+    }
+
+    const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_GUID: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ExtensibilityGlobals) = postSolution
+		SolutionGuid = {0A3B1F5C-9E51-4F6D-8C11-3C7E2D5A9B01}
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const MODIFIED_SOLUTION_WITH_GUID: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ExtensibilityGlobals) = postSolution
+		SolutionGuid = {0a3b1f5c-9e51-4f6d-8c11-3c7e2d5a9b01}
+	EndGlobalSection
+EndGlobal
+"#;
+}
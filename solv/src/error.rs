@@ -1,11 +1,20 @@
+use std::cell::RefCell;
 use std::fmt::Display;
+use std::io::Write;
+use std::time::Duration;
 
+use color_eyre::Report;
+use comfy_table::{Attribute, Cell};
 use crossterm::style::Stylize;
+use serde::Serialize;
+use solp::api::Solution;
+use solp::Consume;
 
 use crate::ux;
 
 pub struct Collector {
-    paths: Vec<String>,
+    /// Paths that failed along with the reason if it's known
+    paths: Vec<(String, Option<String>)>,
 }
 
 impl Collector {
@@ -15,7 +24,20 @@ impl Collector {
     }
 
     pub fn add_path(&mut self, path: &str) {
-        self.paths.push(path.to_owned());
+        self.paths.push((path.to_owned(), None));
+    }
+
+    /// Adds path along with the error that describes why it failed
+    pub fn add_path_with(&mut self, path: &str, error: &Report) {
+        self.paths
+            .push((path.to_owned(), Some(format!("{error:#}"))));
+    }
+
+    /// Reasons of the failures that have them
+    pub fn reasons(&self) -> impl Iterator<Item = &str> {
+        self.paths
+            .iter()
+            .filter_map(|(_, reason)| reason.as_deref())
     }
 
     #[must_use]
@@ -39,12 +61,132 @@ impl Display for Collector {
                 "These solutions cannot be parsed:".dark_red().bold()
             )?;
 
-            ux::print_one_column_table(
-                "Path",
-                None,
-                self.paths.iter().map(std::string::String::as_str),
-            );
+            if self.reasons().next().is_none() {
+                ux::print_one_column_table(
+                    "Path",
+                    None,
+                    self.paths.iter().map(|(path, _)| path.as_str()),
+                );
+            } else {
+                let mut table = ux::new_table();
+                table.set_header([
+                    Cell::new("Path").add_attribute(Attribute::Bold),
+                    Cell::new("Reason").add_attribute(Attribute::Bold),
+                ]);
+                for (path, reason) in &self.paths {
+                    table.add_row([path.as_str(), reason.as_deref().unwrap_or_default()]);
+                }
+                println!("{table}");
+            }
         }
         Ok(())
     }
 }
+
+/// Writes every parse failure as a JSON line `{path, message}` into the writer specified
+/// and passes all results further to the consumer wrapped
+pub struct JsonlErrors<'a, W: Write> {
+    consumer: &'a mut dyn Consume,
+    out: RefCell<W>,
+}
+
+#[derive(Serialize)]
+struct ErrorLine<'a> {
+    path: &'a str,
+    message: String,
+}
+
+impl<'a, W: Write> JsonlErrors<'a, W> {
+    #[must_use]
+    pub fn new(consumer: &'a mut dyn Consume, out: W) -> Self {
+        Self {
+            consumer,
+            out: RefCell::new(out),
+        }
+    }
+}
+
+impl<W: Write> Consume for JsonlErrors<'_, W> {
+    fn ok(&mut self, solution: &Solution) {
+        self.consumer.ok(solution);
+    }
+
+    fn err(&self, path: &str) {
+        self.consumer.err(path);
+    }
+
+    fn err_with(&self, path: &str, error: &Report) {
+        let line = ErrorLine {
+            path,
+            message: format!("{error:#}"),
+        };
+        if let Ok(json) = serde_json::to_string(&line) {
+            let _ = writeln!(self.out.borrow_mut(), "{json}");
+        }
+        self.consumer.err_with(path, error);
+    }
+
+    fn should_stop(&self) -> bool {
+        self.consumer.should_stop()
+    }
+
+    fn timing(&self, path: &str, elapsed: Duration) {
+        self.consumer.timing(path, elapsed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SINGLE_PROJECT_SOLUTION;
+    use crate::stats::Stats;
+    use crate::StatsJson;
+    use std::fs;
+
+    #[test]
+    fn jsonl_errors_malformed_solution() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("malformed.sln");
+        fs::write(&path, MALFORMED_SOLUTION).unwrap();
+        let path = path.to_str().unwrap();
+        let mut stats = Stats::new();
+        let mut out = vec![];
+
+        // Act
+        let result = solp::parse_file(path, &mut JsonlErrors::new(&mut stats, &mut out));
+
+        // Assert
+        assert!(result.is_err());
+        let written = String::from_utf8(out).unwrap();
+        let lines = written.lines().collect::<Vec<&str>>();
+        assert_eq!(1, lines.len());
+        let line = serde_json::from_str::<serde_json::Value>(lines[0]).unwrap();
+        assert_eq!(path, line["path"]);
+        assert!(line["message"]
+            .as_str()
+            .unwrap()
+            .starts_with("Failed to parse solution from path"));
+        let stats = serde_json::from_str::<serde_json::Value>(&stats.stats_json()).unwrap();
+        assert_eq!(1, stats["not_parsed"]);
+    }
+
+    #[test]
+    fn jsonl_errors_nothing_written_on_success() {
+        // Arrange
+        let mut stats = Stats::new();
+        let mut out = vec![];
+        let solution = solp::parse_str(SINGLE_PROJECT_SOLUTION).unwrap();
+
+        // Act
+        JsonlErrors::new(&mut stats, &mut out).ok(&solution);
+
+        // Assert
+        assert!(out.is_empty());
+    }
+
+    const MALFORMED_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj"
+"#;
+}
@@ -0,0 +1,282 @@
+use std::cell::RefCell;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{Context, Result};
+use crossterm::style::Stylize;
+use rusqlite::{params, Connection};
+use solp::api::Solution;
+use solp::Consume;
+
+use crate::error::Collector;
+use crate::StatsJson;
+
+const SCHEMA: &str = r"
+CREATE TABLE IF NOT EXISTS solutions (
+    id INTEGER PRIMARY KEY,
+    path TEXT NOT NULL UNIQUE,
+    format TEXT NOT NULL,
+    product TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS projects (
+    id INTEGER PRIMARY KEY,
+    solution_id INTEGER NOT NULL REFERENCES solutions(id),
+    guid TEXT NOT NULL,
+    name TEXT NOT NULL,
+    type_id TEXT NOT NULL,
+    type_description TEXT NOT NULL,
+    path TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS configurations (
+    project_id INTEGER NOT NULL REFERENCES projects(id),
+    solution_configuration TEXT NOT NULL,
+    platform TEXT NOT NULL,
+    configuration TEXT NOT NULL,
+    buildable INTEGER NOT NULL,
+    deployable INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS dependencies (
+    project_id INTEGER NOT NULL REFERENCES projects(id),
+    depends_on TEXT NOT NULL
+);
+";
+
+/// Writes solutions, projects, project configurations and dependencies
+/// into normalized `SQLite` tables so that they can be queried using SQL
+pub struct Sqlite {
+    connection: Connection,
+    solutions: u64,
+    projects: u64,
+    errors: RefCell<Collector>,
+    base: Option<PathBuf>,
+}
+
+impl Sqlite {
+    /// Creates database tables using the connection specified if they don't exist yet
+    pub fn new(connection: Connection) -> Result<Self> {
+        connection
+            .execute_batch(SCHEMA)
+            .wrap_err("Failed to create database schema")?;
+        Ok(Self {
+            connection,
+            solutions: 0,
+            projects: 0,
+            errors: RefCell::new(Collector::new()),
+            base: None,
+        })
+    }
+
+    /// Opens or creates database file at the path specified
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let connection = Connection::open(path)
+            .wrap_err_with(|| format!("Failed to open database: {}", path.display()))?;
+        Self::new(connection)
+    }
+
+    /// Writes paths relative to the base directory specified
+    #[must_use]
+    pub fn with_base(mut self, base: Option<PathBuf>) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Connection to the database solutions written into
+    #[must_use]
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    fn insert(&mut self, solution: &Solution) -> rusqlite::Result<u64> {
+        let path = crate::relative_to(solution.path, self.base.as_deref());
+        let tx = self.connection.transaction()?;
+        // Solution exported before is replaced so that exporting the same tree again doesn't duplicate rows
+        tx.execute(
+            "DELETE FROM configurations WHERE project_id IN (SELECT p.id FROM projects p JOIN solutions s ON p.solution_id = s.id WHERE s.path = ?1)",
+            params![path],
+        )?;
+        tx.execute(
+            "DELETE FROM dependencies WHERE project_id IN (SELECT p.id FROM projects p JOIN solutions s ON p.solution_id = s.id WHERE s.path = ?1)",
+            params![path],
+        )?;
+        tx.execute(
+            "DELETE FROM projects WHERE solution_id IN (SELECT id FROM solutions WHERE path = ?1)",
+            params![path],
+        )?;
+        tx.execute("DELETE FROM solutions WHERE path = ?1", params![path])?;
+        tx.execute(
+            "INSERT INTO solutions (path, format, product) VALUES (?1, ?2, ?3)",
+            params![path, solution.format, solution.product],
+        )?;
+        let solution_id = tx.last_insert_rowid();
+        for project in &solution.projects {
+            tx.execute(
+                "INSERT INTO projects (solution_id, guid, name, type_id, type_description, path) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    solution_id,
+                    project.id,
+                    project.name,
+                    project.type_id,
+                    project.type_description,
+                    project.path_or_uri
+                ],
+            )?;
+            let project_id = tx.last_insert_rowid();
+            for c in project.configurations.iter().flatten() {
+                tx.execute(
+                    "INSERT INTO configurations (project_id, solution_configuration, platform, configuration, buildable, deployable) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                    params![
+                        project_id,
+                        c.solution_configuration,
+                        c.platform,
+                        c.configuration,
+                        c.is_buildable(),
+                        c.is_deployable()
+                    ],
+                )?;
+            }
+            for dependency in project.depends_from.iter().flatten() {
+                tx.execute(
+                    "INSERT INTO dependencies (project_id, depends_on) VALUES (?1, ?2)",
+                    params![project_id, dependency],
+                )?;
+            }
+        }
+        tx.commit()?;
+        Ok(solution.projects.len() as u64)
+    }
+}
+
+impl Consume for Sqlite {
+    fn ok(&mut self, solution: &Solution) {
+        match self.insert(solution) {
+            Ok(projects) => {
+                self.solutions += 1;
+                self.projects += projects;
+            }
+            Err(_) => self.errors.borrow_mut().add_path(solution.path),
+        }
+    }
+
+    fn err(&self, path: &str) {
+        self.errors.borrow_mut().add_path(path);
+    }
+}
+
+impl StatsJson for Sqlite {
+    fn stats_json(&self) -> String {
+        serde_json::json!({
+            "solutions": self.solutions,
+            "projects": self.projects,
+            "not_parsed": self.errors.borrow().count(),
+        })
+        .to_string()
+    }
+}
+
+impl Display for Sqlite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            " {}",
+            format!(
+                "{} solutions with {} projects exported",
+                self.solutions, self.projects
+            )
+            .dark_green()
+            .bold()
+        )?;
+        write!(f, "{}", self.errors.borrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_written() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION).unwrap();
+        let mut sqlite = Sqlite::new(Connection::open_in_memory().unwrap()).unwrap();
+
+        // Act
+        sqlite.ok(&solution);
+
+        // Assert
+        let count = |table: &str| {
+            sqlite
+                .connection()
+                .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |r| {
+                    r.get::<_, i64>(0)
+                })
+                .unwrap()
+        };
+        assert_eq!(1, count("solutions"));
+        assert_eq!(2, count("projects"));
+        assert_eq!(4, count("configurations"));
+        assert_eq!(1, count("dependencies"));
+        let buildable = sqlite
+            .connection()
+            .query_row(
+                "SELECT COUNT(*) FROM configurations WHERE buildable = 1",
+                [],
+                |r| r.get::<_, i64>(0),
+            )
+            .unwrap();
+        assert_eq!(3, buildable);
+    }
+
+    #[test]
+    fn rows_replaced_on_repeated_export() {
+        // Arrange
+        let mut solution = solp::parse_str(SOLUTION).unwrap();
+        solution.path = "/repo/a.sln";
+        let mut sqlite = Sqlite::new(Connection::open_in_memory().unwrap()).unwrap();
+        sqlite.ok(&solution);
+
+        // Act
+        sqlite.ok(&solution);
+
+        // Assert
+        let count = |table: &str| {
+            sqlite
+                .connection()
+                .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |r| {
+                    r.get::<_, i64>(0)
+                })
+                .unwrap()
+        };
+        assert_eq!(1, count("solutions"));
+        assert_eq!(2, count("projects"));
+        assert_eq!(4, count("configurations"));
+        assert_eq!(1, count("dependencies"));
+    }
+
+    const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+	ProjectSection(ProjectDependencies) = postProject
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {78965571-A6C2-4161-95B1-813B46610EA7}
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+}
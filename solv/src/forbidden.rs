@@ -0,0 +1,250 @@
+use std::cmp::Ordering;
+use std::fs;
+
+use color_eyre::eyre::{bail, eyre, Context};
+use color_eyre::Result;
+
+/// List of nuget packages that must not be used.
+/// Every non empty line that doesn't start with `#` is a package name
+/// optionally followed by a version or a version range using nuget interval notation
+/// i.e. `[1.0,2.0)`, `(,1.0]` or `[1.0]`. A bare version like `1.0` forbids exactly this version
+/// so that fixed releases aren't forbidden. Use `[1.0,)` to forbid a version and all later ones
+#[derive(Debug, Default)]
+pub struct Forbidden {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug)]
+struct Rule {
+    name: String,
+    range: Option<Range>,
+}
+
+#[derive(Debug)]
+struct Range {
+    min: Option<(Version, bool)>,
+    max: Option<(Version, bool)>,
+}
+
+#[derive(Debug, Clone)]
+struct Version {
+    numbers: Vec<u64>,
+    prerelease: Option<String>,
+}
+
+impl Forbidden {
+    /// Loads forbidden packages list from the file specified
+    pub fn load(path: &str) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read forbidden packages: {path}"))?;
+        Self::parse(&contents)
+            .wrap_err_with(|| format!("Failed to parse forbidden packages: {path}"))
+    }
+
+    /// Parses forbidden packages list from the string specified
+    pub fn parse(contents: &str) -> Result<Self> {
+        let rules = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (name, range) = match line.split_once(char::is_whitespace) {
+                    Some((name, range)) => (name, Some(Range::parse(range.trim())?)),
+                    None => (line, None),
+                };
+                Ok(Rule {
+                    name: name.to_owned(),
+                    range,
+                })
+            })
+            .collect::<Result<Vec<Rule>>>()?;
+        Ok(Self { rules })
+    }
+
+    /// Whether package's version specified is forbidden. Package names are compared case-insensitive.
+    /// Versions that cannot be parsed like `$(Version)` match only rules without version
+    #[must_use]
+    pub fn is_forbidden(&self, name: &str, version: &str) -> bool {
+        let version = Version::parse(version);
+        self.rules
+            .iter()
+            .filter(|r| r.name.eq_ignore_ascii_case(name))
+            .any(|r| match (&r.range, &version) {
+                (None, _) => true,
+                (Some(range), Some(version)) => range.contains(version),
+                (Some(_), None) => false,
+            })
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+impl Range {
+    fn parse(s: &str) -> Result<Self> {
+        let version =
+            |v: &str| Version::parse(v).ok_or_else(|| eyre!("Invalid version range: {s}"));
+        let (Some(first), Some(last)) = (s.chars().next(), s.chars().last()) else {
+            bail!("Empty version range");
+        };
+        if !matches!(first, '[' | '(') {
+            return Ok(Self::exact(version(s)?));
+        }
+        if !matches!(last, ']' | ')') {
+            bail!("Invalid version range: {s}");
+        }
+        let inner = &s[1..s.len() - 1];
+        let bound = |v: &str, inclusive: bool| -> Result<Option<(Version, bool)>> {
+            let v = v.trim();
+            if v.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some((version(v)?, inclusive)))
+            }
+        };
+        match inner.split_once(',') {
+            Some((min, max)) => Ok(Self {
+                min: bound(min, first == '[')?,
+                max: bound(max, last == ']')?,
+            }),
+            None if first == '[' && last == ']' => Ok(Self::exact(version(inner.trim())?)),
+            None => bail!("Invalid version range: {s}"),
+        }
+    }
+
+    fn exact(version: Version) -> Self {
+        Self {
+            min: Some((version.clone(), true)),
+            max: Some((version, true)),
+        }
+    }
+
+    fn contains(&self, version: &Version) -> bool {
+        let above = self
+            .min
+            .as_ref()
+            .is_none_or(|(min, inclusive)| match version.cmp(min) {
+                Ordering::Greater => true,
+                Ordering::Equal => *inclusive,
+                Ordering::Less => false,
+            });
+        let below = self
+            .max
+            .as_ref()
+            .is_none_or(|(max, inclusive)| match version.cmp(max) {
+                Ordering::Less => true,
+                Ordering::Equal => *inclusive,
+                Ordering::Greater => false,
+            });
+        above && below
+    }
+}
+
+impl Version {
+    fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let s = s.split_once('+').map_or(s, |(v, _)| v);
+        let (numbers, prerelease) = match s.split_once('-') {
+            Some((numbers, prerelease)) => (numbers, Some(prerelease.to_owned())),
+            None => (s, None),
+        };
+        let numbers = numbers
+            .split('.')
+            .map(|n| n.parse::<u64>().ok())
+            .collect::<Option<Vec<u64>>>()?;
+        Some(Self {
+            numbers,
+            prerelease,
+        })
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Version {}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.numbers.len().max(other.numbers.len());
+        let number = |v: &Version, i: usize| v.numbers.get(i).copied().unwrap_or_default();
+        (0..len)
+            .map(|i| number(self, i).cmp(&number(other, i)))
+            .find(|o| o.is_ne())
+            .unwrap_or_else(|| match (&self.prerelease, &other.prerelease) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.to_lowercase().cmp(&b.to_lowercase()),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Newtonsoft.Json", "newtonsoft.json", "13.0.1", true)]
+    #[case("Newtonsoft.Json", "xunit", "13.0.1", false)]
+    #[case("log4net 1.2.10", "log4net", "1.2.10", true)]
+    #[case("log4net 1.2.10", "log4net", "1.2.10.0", true)]
+    #[case("log4net 1.2.10", "log4net", "1.2.11", false)]
+    #[case("log4net 1.2.10", "log4net", "2.0.15", false)]
+    #[case("log4net 1.2.10", "log4net", "1.2.9", false)]
+    #[case("log4net 1.2.10", "log4net", "1.2.10-beta", false)]
+    #[case("log4net [1.2.10]", "log4net", "1.2.10", true)]
+    #[case("log4net [1.0,2.0)", "log4net", "1.9.9", true)]
+    #[case("log4net [1.0,2.0)", "log4net", "2.0.0", false)]
+    #[case("log4net [1.0,2.0)", "log4net", "0.9", false)]
+    #[case("log4net (,2.0]", "log4net", "2.0", true)]
+    #[case("log4net (1.0,)", "log4net", "1.0", false)]
+    #[case("log4net (1.0,)", "log4net", "1.0.1-beta", true)]
+    #[case("log4net [1.0,)", "log4net", "1.0.0-beta", false)]
+    #[case("log4net [1.2.10,)", "log4net", "1.2.11", true)]
+    #[case("log4net [1.0,)", "log4net", "$(Log4NetVersion)", false)]
+    #[case("# comment\n\nlog4net", "log4net", "$(Log4NetVersion)", true)]
+    #[trace]
+    fn is_forbidden_tests(
+        #[case] list: &str,
+        #[case] name: &str,
+        #[case] version: &str,
+        #[case] expected: bool,
+    ) {
+        // Arrange
+        let forbidden = Forbidden::parse(list).unwrap();
+
+        // Act
+        let actual = forbidden.is_forbidden(name, version);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[rstest]
+    #[case("log4net [1.0")]
+    #[case("log4net [a,b]")]
+    #[case("log4net (1.0)")]
+    #[case("log4net latest")]
+    #[trace]
+    fn invalid_ranges(#[case] list: &str) {
+        // Act
+        let actual = Forbidden::parse(list);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+}
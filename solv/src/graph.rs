@@ -0,0 +1,173 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use petgraph::prelude::DiGraphMap;
+use petgraph::Direction;
+use solp::api::Solution;
+
+/// Builds solution's projects dependency graph.
+/// Edges are directed from dependency to the project that depends from it.
+/// Ids are compared case-insensitive i.e. uppercased and every node is
+/// the first spelling of the id met in the solution, project's own one if any
+#[must_use]
+pub fn dependency_graph<'a>(solution: &'a Solution<'a>) -> DiGraphMap<&'a str, ()> {
+    let mut spellings: HashMap<String, &'a str> = solution
+        .projects
+        .iter()
+        .rev()
+        .map(|p| (p.id.to_uppercase(), p.id))
+        .collect();
+    let mut graph = DiGraphMap::<&'a str, ()>::new();
+    for p in &solution.projects {
+        let id = spellings[&p.id.to_uppercase()];
+        graph.add_node(id);
+        for d in p.depends_from.iter().flatten() {
+            let d = *spellings.entry(d.to_uppercase()).or_insert(*d);
+            graph.add_edge(d, id, ());
+        }
+    }
+    graph
+}
+
+/// Returns ids of the projects the root project depends on directly or transitively
+/// in breadth-first order. Depth limits the number of dependency levels traversed
+/// i.e. 1 means direct dependencies only. Root id is compared case-insensitive
+#[must_use]
+pub fn dependency_closure<'a>(
+    solution: &'a Solution<'a>,
+    root: &str,
+    depth: Option<usize>,
+) -> Vec<&'a str> {
+    let graph = dependency_graph(solution);
+    let Some(root) = graph.nodes().find(|n| n.eq_ignore_ascii_case(root)) else {
+        return vec![];
+    };
+    let mut visited = HashSet::from([root]);
+    let mut queue = VecDeque::from([(root, 0)]);
+    let mut closure = vec![];
+    while let Some((node, level)) = queue.pop_front() {
+        if depth.is_some_and(|depth| level >= depth) {
+            continue;
+        }
+        for dependency in graph.neighbors_directed(node, Direction::Incoming) {
+            if visited.insert(dependency) {
+                closure.push(dependency);
+                queue.push_back((dependency, level + 1));
+            }
+        }
+    }
+    closure
+}
+
+/// Orders solution's project ids so that every project goes after all its dependencies.
+/// Returns ids of the projects that form a cycle as error
+pub fn topological_order<'a>(solution: &'a Solution<'a>) -> Result<Vec<&'a str>, Vec<&'a str>> {
+    let graph = dependency_graph(solution);
+    petgraph::algo::toposort(&graph, None).map_err(|cycle| {
+        petgraph::algo::tarjan_scc(&graph)
+            .into_iter()
+            .find(|scc| scc.contains(&cycle.node_id()))
+            .unwrap_or_else(|| vec![cycle.node_id()])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Some(1), vec!["b"])]
+    #[case(Some(2), vec!["b", "c"])]
+    #[case(None, vec!["b", "c", "d"])]
+    #[case(Some(0), vec![])]
+    #[trace]
+    fn dependency_closure_tests(#[case] depth: Option<usize>, #[case] expected: Vec<&str>) {
+        // Arrange
+        let solution = solp::parse_str(CHAIN_SOLUTION).unwrap();
+        let names = solution
+            .projects
+            .iter()
+            .map(|p| (p.id, p.name))
+            .collect::<std::collections::HashMap<_, _>>();
+
+        // Act
+        let actual = dependency_closure(&solution, "{78965571-a6c2-4161-95b1-813b46610ea7}", depth);
+
+        // Assert
+        let actual = actual.iter().map(|id| names[id]).collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn dependency_graph_ids_case_insensitive() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_LOWERCASE_DEPENDENCY).unwrap();
+
+        // Act
+        let actual = dependency_graph(&solution);
+
+        // Assert
+        assert_eq!(2, actual.node_count());
+        assert!(actual.contains_edge(
+            "{D9523F4D-6CB7-4431-85F6-8122F55EB144}",
+            "{78965571-A6C2-4161-95B1-813B46610EA7}"
+        ));
+    }
+
+    #[test]
+    fn dependency_closure_unknown_root() {
+        // Arrange
+        let solution = solp::parse_str(CHAIN_SOLUTION).unwrap();
+
+        // Act
+        let actual = dependency_closure(&solution, "{00000000-0000-0000-0000-000000000000}", None);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    const CHAIN_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+	ProjectSection(ProjectDependencies) = postProject
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144} = {D9523F4D-6CB7-4431-85F6-8122F55EB144}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+	ProjectSection(ProjectDependencies) = postProject
+		{3AF54C8A-10BF-4332-9147-F68ED9862032} = {3AF54C8A-10BF-4332-9147-F68ED9862032}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "c", "c\c.csproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+	ProjectSection(ProjectDependencies) = postProject
+		{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E} = {F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "d", "d\d.csproj", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_LOWERCASE_DEPENDENCY: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+	ProjectSection(ProjectDependencies) = postProject
+		{d9523f4d-6cb7-4431-85f6-8122f55eb144} = {d9523f4d-6cb7-4431-85f6-8122f55eb144}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+}
@@ -1,20 +1,47 @@
 use comfy_table::{Attribute, Cell, CellAlignment, ContentArrangement};
 use crossterm::style::Stylize;
 use num_format::{Locale, ToFormattedString};
-use solp::api::Solution;
+use regex::Regex;
+use serde::Serialize;
+use solp::api::{Project, Solution};
 use solp::{msbuild, Consume};
 use std::cell::RefCell;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::fmt::Display;
+use std::path::PathBuf;
 
 use crate::error::Collector;
-use crate::{calculate_percent, ux};
+use crate::{calculate_percent, loader, ux, StatsJson};
+
+const NONE: &str = "none";
+
+/// Info aggregate over all solutions scanned
+#[derive(Serialize)]
+pub struct Summary<'a> {
+    pub solutions: i32,
+    pub projects: i32,
+    pub not_parsed: u64,
+    /// Number of projects of every project type
+    pub projects_by_type: &'a BTreeMap<String, i32>,
+}
+
 pub struct Info {
     total_projects: BTreeMap<String, i32>,
     projects_in_solutions: BTreeMap<String, i32>,
     solutions: i32,
     errors: RefCell<Collector>,
+    name_filter: Option<Regex>,
+    show_tests: bool,
+    show_build_props: bool,
+    show_config_counts: bool,
+    show_assets: bool,
+    show_folders: bool,
+    show_bom: bool,
+    count_by_type: bool,
+    only: Option<String>,
+    min_projects: Option<usize>,
+    base: Option<PathBuf>,
 }
 
 impl Info {
@@ -25,8 +52,165 @@ impl Info {
             projects_in_solutions: BTreeMap::new(),
             solutions: 0,
             errors: RefCell::new(Collector::new()),
+            name_filter: None,
+            show_tests: false,
+            show_build_props: false,
+            show_config_counts: false,
+            show_assets: false,
+            show_folders: false,
+            show_bom: false,
+            count_by_type: false,
+            only: None,
+            min_projects: None,
+            base: None,
         }
     }
+
+    /// Restricts output to projects which name matches the regular expression specified
+    #[must_use]
+    pub fn with_name_filter(mut self, name_filter: Option<Regex>) -> Self {
+        self.name_filter = name_filter;
+        self
+    }
+
+    /// Enables test projects listing
+    #[must_use]
+    pub fn with_tests(mut self, show_tests: bool) -> Self {
+        self.show_tests = show_tests;
+        self
+    }
+
+    /// Enables reading SDK version from `global.json` and target framework from `Directory.Build.props`
+    #[must_use]
+    pub fn with_build_props(mut self, show_build_props: bool) -> Self {
+        self.show_build_props = show_build_props;
+        self
+    }
+
+    /// Enables table with configuration entries count of projects
+    /// which configurations count differs from the solution's one
+    #[must_use]
+    pub fn with_config_counts(mut self, show_config_counts: bool) -> Self {
+        self.show_config_counts = show_config_counts;
+        self
+    }
+
+    /// Enables table with `None` and `Content` items count per project.
+    /// Project files are loaded to count them
+    #[must_use]
+    pub fn with_assets(mut self, show_assets: bool) -> Self {
+        self.show_assets = show_assets;
+        self
+    }
+
+    /// Shows whether solution file starts with UTF-8 signature i.e. BOM
+    #[must_use]
+    pub fn with_bom(mut self, show_bom: bool) -> Self {
+        self.show_bom = show_bom;
+        self
+    }
+
+    /// Counts solution folders as projects of `Solution Folder` type.
+    /// Folders are neither shown nor counted anywhere, including totals, unless enabled
+    #[must_use]
+    pub fn with_folders(mut self, show_folders: bool) -> Self {
+        self.show_folders = show_folders;
+        self
+    }
+
+    /// Prints only total projects count by type across all solutions
+    /// without any per solution output
+    #[must_use]
+    pub fn with_count_by_type(mut self, count_by_type: bool) -> Self {
+        self.count_by_type = count_by_type;
+        self
+    }
+
+    /// Shows only the project with the id specified, its configurations and dependencies
+    /// in every solution that contains it. Ids are compared case-insensitive
+    #[must_use]
+    pub fn with_only(mut self, only: Option<String>) -> Self {
+        self.only = only;
+        self
+    }
+
+    /// Skips solutions with less than the number of projects specified. Solution folders aren't counted
+    #[must_use]
+    pub fn with_min_projects(mut self, min_projects: Option<usize>) -> Self {
+        self.min_projects = min_projects;
+        self
+    }
+
+    /// Prints paths relative to the base directory specified
+    #[must_use]
+    pub fn with_base(mut self, base: Option<PathBuf>) -> Self {
+        self.base = base;
+        self
+    }
+
+    fn name_matches(&self, name: &str) -> bool {
+        self.name_filter.as_ref().is_none_or(|re| re.is_match(name))
+    }
+
+    /// Whether project should be shown and counted i.e. it's not a solution folder
+    /// (unless folders are enabled), its name matches the filter and it's the only project requested if any
+    fn counts(&self, project: &Project) -> bool {
+        let folder = msbuild::is_solution_folder(project.type_id);
+        let only = self
+            .only
+            .as_ref()
+            .is_none_or(|id| project.id.eq_ignore_ascii_case(id));
+        (self.show_folders || !folder) && only && self.name_matches(project.name)
+    }
+}
+
+/// Makes table with project's details, its configurations and dependencies
+fn project_table(solution: &Solution, project: &Project) -> comfy_table::Table {
+    let mut table = ux::new_table();
+    table.add_row([
+        Cell::new("Project"),
+        Cell::new(project.name).add_attribute(Attribute::Bold),
+    ]);
+    table.add_row([Cell::new("Id"), Cell::new(project.id)]);
+    table.add_row([Cell::new("Type"), Cell::new(project.type_description)]);
+    table.add_row([Cell::new("Path"), Cell::new(project.path_or_uri)]);
+
+    let mut configurations = ux::new_table();
+    configurations.set_header([
+        Cell::new("Solution configuration").add_attribute(Attribute::Bold),
+        Cell::new("Project configuration").add_attribute(Attribute::Bold),
+        Cell::new("Build").add_attribute(Attribute::Bold),
+        Cell::new("Deploy").add_attribute(Attribute::Bold),
+    ]);
+    for c in project.configurations.iter().flatten() {
+        configurations.add_row([
+            Cell::new(format!("{}|{}", c.solution_configuration, c.platform)),
+            Cell::new(c.configuration).add_attribute(Attribute::Italic),
+            Cell::new(c.is_buildable()),
+            Cell::new(c.is_deployable()),
+        ]);
+    }
+    table.add_row([Cell::new("Configurations"), Cell::new(configurations)]);
+
+    let dependencies = project
+        .depends_from
+        .iter()
+        .flatten()
+        .map(|id| {
+            solution
+                .projects
+                .iter()
+                .find(|p| p.id.eq_ignore_ascii_case(id))
+                .map_or(*id, |p| p.name)
+        })
+        .collect::<BTreeSet<&str>>();
+    let dependencies = if dependencies.is_empty() {
+        NONE.to_owned()
+    } else {
+        dependencies.into_iter().collect::<Vec<_>>().join("\n")
+    };
+    table.add_row([Cell::new("Depends on"), Cell::new(dependencies)]);
+    table
 }
 
 impl Default for Info {
@@ -37,18 +221,43 @@ impl Default for Info {
 
 impl Consume for Info {
     fn ok(&mut self, solution: &Solution) {
-        self.solutions += 1;
+        if !crate::has_min_projects(solution, self.min_projects) {
+            return;
+        }
         let mut projects_by_type: BTreeMap<&str, i32> = BTreeMap::new();
-        for prj in &solution.projects {
-            if msbuild::is_solution_folder(prj.type_id) {
-                continue;
-            }
+        for prj in solution.projects.iter().filter(|p| self.counts(p)) {
             *projects_by_type.entry(prj.type_description).or_insert(0) += 1;
         }
 
-        let mut solution_table = ux::create_solution_table(solution.path);
+        // Solutions without projects matching the filter are not interesting at all
+        if (self.name_filter.is_some() || self.only.is_some()) && projects_by_type.is_empty() {
+            return;
+        }
+        self.solutions += 1;
+
+        for (key, value) in &projects_by_type {
+            *self.total_projects.entry(String::from(*key)).or_insert(0) += *value;
+            *self
+                .projects_in_solutions
+                .entry(String::from(*key))
+                .or_insert(0) += 1;
+        }
+
+        if self.count_by_type {
+            return;
+        }
+
+        let mut solution_table = ux::create_solution_table(solution.path, self.base.as_deref());
         solution_table.set_content_arrangement(ContentArrangement::Disabled);
 
+        if self.only.is_some() {
+            for project in solution.projects.iter().filter(|p| self.counts(p)) {
+                solution_table.add_row([Cell::new(project_table(solution, project))]);
+            }
+            println!("{solution_table}");
+            return;
+        }
+
         let mut table = ux::new_table();
 
         table.add_row([
@@ -62,12 +271,33 @@ impl Consume for Info {
             ]);
         }
 
+        if self.show_bom {
+            table.add_row([
+                Cell::new("UTF-8 BOM"),
+                Cell::new(if solution.utf8_bom { "yes" } else { "no" })
+                    .add_attribute(Attribute::Bold),
+            ]);
+        }
+
         for version in &solution.versions {
             table.add_row([
                 Cell::new(version.name),
                 Cell::new(version.version).add_attribute(Attribute::Bold),
             ]);
         }
+        if self.show_build_props {
+            let props = loader::load_build_props(crate::parent_of(solution.path));
+            table.add_row([
+                Cell::new("SDK version"),
+                Cell::new(props.sdk_version.as_deref().unwrap_or(NONE))
+                    .add_attribute(Attribute::Bold),
+            ]);
+            table.add_row([
+                Cell::new("Central TargetFramework"),
+                Cell::new(props.target_framework.as_deref().unwrap_or(NONE))
+                    .add_attribute(Attribute::Bold),
+            ]);
+        }
         solution_table.add_row([Cell::new(table)]);
 
         let mut table = ux::new_table();
@@ -77,11 +307,6 @@ impl Consume for Info {
         ]);
 
         for (key, value) in &projects_by_type {
-            *self.total_projects.entry(String::from(*key)).or_insert(0) += *value;
-            *self
-                .projects_in_solutions
-                .entry(String::from(*key))
-                .or_insert(0) += 1;
             table.add_row([
                 Cell::new(*key),
                 Cell::new(*value).add_attribute(Attribute::Italic),
@@ -110,6 +335,37 @@ impl Consume for Info {
         if let Some(t) = ux::create_one_column_table("Platform", None, platforms.into_iter()) {
             solution_table.add_row([Cell::new(t)]);
         }
+        if self.show_config_counts {
+            let mut table = ux::new_table();
+            table.set_header([
+                Cell::new("Project").add_attribute(Attribute::Bold),
+                Cell::new("Configuration entries").add_attribute(Attribute::Bold),
+            ]);
+            for (project, count) in config_count_outliers(solution)
+                .into_iter()
+                .filter(|(p, _)| self.counts(p))
+            {
+                table.add_row([
+                    Cell::new(project.name),
+                    Cell::new(count).add_attribute(Attribute::Italic),
+                ]);
+            }
+            solution_table.add_row([Cell::new(table)]);
+        }
+        if self.show_assets {
+            solution_table.add_row([Cell::new(self.assets_table(solution))]);
+        }
+        if self.show_tests {
+            let dir = crate::parent_of(solution.path);
+            let tests = solution
+                .iterate_projects()
+                .filter(|p| self.name_matches(p.name) && loader::is_test_project(dir, p))
+                .map(|p| p.name)
+                .collect::<BTreeSet<&str>>();
+            if let Some(t) = ux::create_one_column_table("Test project", None, tests.into_iter()) {
+                solution_table.add_row([Cell::new(t)]);
+            }
+        }
         println!("{solution_table}");
     }
 
@@ -118,8 +374,89 @@ impl Consume for Info {
     }
 }
 
+impl Info {
+    fn assets_table(&self, solution: &Solution) -> comfy_table::Table {
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project").add_attribute(Attribute::Bold),
+            Cell::new("None items").add_attribute(Attribute::Bold),
+            Cell::new("Content items").add_attribute(Attribute::Bold),
+        ]);
+        for (project, (none, content)) in project_assets(solution)
+            .into_iter()
+            .filter(|(p, _)| self.counts(p))
+        {
+            table.add_row([
+                Cell::new(project.name),
+                Cell::new(none).add_attribute(Attribute::Italic),
+                Cell::new(content).add_attribute(Attribute::Italic),
+            ]);
+        }
+        table
+    }
+
+    /// Totals over all solutions scanned so far
+    #[must_use]
+    pub fn summary(&self) -> Summary<'_> {
+        Summary {
+            solutions: self.solutions,
+            projects: self.total_projects.values().sum(),
+            not_parsed: self.errors.borrow().count(),
+            projects_by_type: &self.total_projects,
+        }
+    }
+}
+
+/// Returns configuration entries count of projects which configurations count differs
+/// from the solution's one i.e. ones with stale or unmapped configurations. Sorted by count descending
+fn config_count_outliers<'a>(solution: &'a Solution<'a>) -> Vec<(&'a Project<'a>, usize)> {
+    let expected = solution.configurations.len();
+    solution
+        .configuration_count_per_project()
+        .into_iter()
+        .filter(|(p, _)| p.configurations.as_ref().map_or(0, Vec::len) != expected)
+        .collect()
+}
+
+/// Counts `None` and `Content` items of every solution's project which file can be loaded
+fn project_assets<'a>(solution: &'a Solution<'a>) -> Vec<(&'a Project<'a>, (usize, usize))> {
+    let dir = crate::parent_of(solution.path);
+    solution
+        .iterate_projects()
+        .filter_map(|p| {
+            let project = loader::project_path(dir, p)
+                .filter(|path| path.is_file())
+                .and_then(|path| loader::load_msbuild_project(&path))?;
+            Some((p, project.assets()))
+        })
+        .collect()
+}
+
+impl StatsJson for Info {
+    fn stats_json(&self) -> String {
+        serde_json::to_string(&self.summary()).unwrap_or_default()
+    }
+}
+
 impl Display for Info {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.count_by_type {
+            let mut table = ux::new_table();
+            table.set_header([
+                Cell::new("Project type").add_attribute(Attribute::Bold),
+                Cell::new("Count").add_attribute(Attribute::Bold),
+            ]);
+            for (key, value) in &self.total_projects {
+                table.add_row([
+                    Cell::new(key),
+                    Cell::new(value.to_formatted_string(&Locale::en))
+                        .add_attribute(Attribute::Italic),
+                ]);
+            }
+            writeln!(f, "{table}")?;
+            return write!(f, "{}", self.errors.borrow());
+        }
+
         writeln!(f, " {}", "Statistic:".dark_red().bold())?;
 
         let mut table = ux::new_table();
@@ -164,3 +501,457 @@ impl Display for Info {
         write!(f, "{}", self.errors.borrow())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use solp::fixtures::PROJECT_WITH_ASSETS;
+
+    #[test]
+    fn name_filter_restricts_projects() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let filter = Regex::new(".*tests").unwrap();
+        let mut info = Info::new().with_name_filter(Some(filter));
+
+        // Act
+        info.ok(&solution);
+
+        // Assert
+        assert_eq!(1, info.solutions);
+        assert_eq!(Some(&1), info.total_projects.get("C#"));
+        assert_eq!(1, info.total_projects.len());
+    }
+
+    #[test]
+    fn min_projects_skips_small_solution() {
+        // Arrange
+        let small = solp::parse_str(SINGLE_PROJECT_SOLUTION).unwrap();
+        let big = solp::parse_str(SOLUTION).unwrap();
+        let mut info = Info::new().with_min_projects(Some(2));
+
+        // Act
+        info.ok(&small);
+        info.ok(&big);
+
+        // Assert
+        assert_eq!(1, info.solutions);
+        assert_eq!(Some(&2), info.total_projects.get("C#"));
+    }
+
+    #[test]
+    fn name_filter_without_matches_skips_solution() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let filter = Regex::new("^nothing$").unwrap();
+        let mut info = Info::new().with_name_filter(Some(filter));
+
+        // Act
+        info.ok(&solution);
+
+        // Assert
+        assert_eq!(0, info.solutions);
+        assert!(info.total_projects.is_empty());
+    }
+
+    #[test]
+    fn folders_excluded_by_default() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut info = Info::new();
+
+        // Act
+        info.ok(&solution);
+
+        // Assert
+        assert!(!info.total_projects.contains_key("Solution Folder"));
+    }
+
+    #[test]
+    fn folders_included() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut info = Info::new().with_folders(true);
+
+        // Act
+        info.ok(&solution);
+
+        // Assert
+        assert_eq!(Some(&2), info.total_projects.get("Solution Folder"));
+        let stats: serde_json::Value = serde_json::from_str(&info.stats_json()).unwrap();
+        assert_eq!(10, stats["projects"]);
+    }
+
+    #[rstest]
+    #[case(false, 8, false)]
+    #[case(true, 10, true)]
+    #[trace]
+    fn folders_counted_consistently_tests(
+        #[case] include_folders: bool,
+        #[case] expected_projects: i32,
+        #[case] expected_folders_shown: bool,
+    ) {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut info = Info::new().with_folders(include_folders);
+
+        // Act
+        info.ok(&solution);
+
+        // Assert
+        let stats: serde_json::Value = serde_json::from_str(&info.stats_json()).unwrap();
+        assert_eq!(expected_projects, stats["projects"]);
+        assert_eq!(expected_projects, info.total_projects.values().sum::<i32>());
+        assert_eq!(
+            expected_folders_shown,
+            info.to_string().contains("Solution Folder")
+        );
+    }
+
+    #[test]
+    fn folders_not_matched_by_name_filter_by_default() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let filter = Regex::new("^solution items$").unwrap();
+        let mut info = Info::new().with_name_filter(Some(filter));
+
+        // Act
+        info.ok(&solution);
+
+        // Assert
+        assert_eq!(0, info.solutions);
+        assert!(info.total_projects.is_empty());
+    }
+
+    #[test]
+    fn count_by_type_across_solutions() {
+        // Arrange
+        let first = solp::parse_str(REAL_SOLUTION).unwrap();
+        let second = solp::parse_str(SOLUTION).unwrap();
+        let mut info = Info::new().with_count_by_type(true);
+
+        // Act
+        info.ok(&first);
+        info.ok(&second);
+
+        // Assert
+        assert_eq!(2, info.solutions);
+        assert_eq!(Some(&8), info.total_projects.get("C#"));
+        let output = info.to_string();
+        assert!(output.contains("C#"));
+        assert!(!output.contains("Total solutions"));
+    }
+
+    #[test]
+    fn stats_json_matches_totals() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut info = Info::new();
+        info.ok(&solution);
+        info.err("bad.sln");
+
+        // Act
+        let actual = info.stats_json();
+
+        // Assert
+        let stats: serde_json::Value = serde_json::from_str(&actual).unwrap();
+        assert!(!actual.contains('\n'));
+        assert_eq!(1, stats["solutions"]);
+        assert_eq!(8, stats["projects"]);
+        assert_eq!(1, stats["not_parsed"]);
+        assert_eq!(6, stats["projects_by_type"]["C#"]);
+        assert_eq!(
+            i64::from(*info.total_projects.get("C#").unwrap()),
+            stats["projects_by_type"]["C#"]
+        );
+    }
+
+    #[test]
+    fn only_project_across_solutions() {
+        // Arrange
+        let first = solp::parse_str(SOLUTION).unwrap();
+        let second = solp::parse_str(SOLUTION_SHARING_PROJECT).unwrap();
+        let other = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut info =
+            Info::new().with_only(Some("{d9523f4d-6cb7-4431-85f6-8122f55eb144}".to_owned()));
+
+        // Act
+        info.ok(&first);
+        info.ok(&second);
+        info.ok(&other);
+
+        // Assert
+        assert_eq!(2, info.solutions);
+        assert_eq!(Some(&2), info.total_projects.get("C#"));
+        assert_eq!(1, info.total_projects.len());
+    }
+
+    #[test]
+    fn only_project_details() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_SHARING_PROJECT).unwrap();
+        let project = &solution.projects[1];
+
+        // Act
+        let actual = project_table(&solution, project).to_string();
+
+        // Assert
+        assert!(actual.contains("Release|Any CPU"));
+        assert!(actual.contains("Depends on"));
+        assert!(actual.contains("shared"));
+    }
+
+    #[test]
+    fn assets_per_project() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("a")).unwrap();
+        std::fs::write(dir.path().join("a").join("a.csproj"), PROJECT_WITH_ASSETS).unwrap();
+        let sln = dir.path().join("s.sln");
+        let mut solution = solp::parse_str(SOLUTION).unwrap();
+        solution.path = sln.to_str().unwrap();
+        let info = Info::new().with_assets(true);
+
+        // Act
+        let actual = project_assets(&solution);
+        let table = info.assets_table(&solution).to_string();
+
+        // Assert
+        assert_eq!(1, actual.len());
+        assert_eq!("a", actual[0].0.name);
+        assert_eq!((2, 3), actual[0].1);
+        assert!(table.contains("Content items"));
+    }
+
+    #[test]
+    fn config_count_outliers_only() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_STALE_CONFIGURATION).unwrap();
+
+        // Act
+        let actual = config_count_outliers(&solution);
+
+        // Assert
+        assert_eq!(1, actual.len());
+        assert_eq!("b", actual[0].0.name);
+        assert_eq!(3, actual[0].1);
+    }
+
+    const SOLUTION_WITH_STALE_CONFIGURATION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SINGLE_PROJECT_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "src", "src", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "src\a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_SHARING_PROJECT: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "shared", "shared\shared.csproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "..\b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+	ProjectSection(ProjectDependencies) = postProject
+		{3AF54C8A-10BF-4332-9147-F68ED9862032} = {3AF54C8A-10BF-4332-9147-F68ED9862032}
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.Build.0 = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const REAL_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 15
+VisualStudioVersion = 15.0.26403.0
+MinimumVisualStudioVersion = 10.0.40219.1
+Project("{930C7802-8A8C-48F9-8165-68863BCCD9DD}") = "logviewer.install", "logviewer.install\logviewer.install.wixproj", "{27060CA7-FB29-42BC-BA66-7FC80D498354}"
+	ProjectSection(ProjectDependencies) = postProject
+		{405827CB-84E1-46F3-82C9-D889892645AC} = {405827CB-84E1-46F3-82C9-D889892645AC}
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D} = {CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}
+	EndProjectSection
+EndProject
+Project("{930C7802-8A8C-48F9-8165-68863BCCD9DD}") = "logviewer.install.bootstrap", "logviewer.install.bootstrap\logviewer.install.bootstrap.wixproj", "{1C0ED62B-D506-4E72-BBC2-A50D3926466E}"
+	ProjectSection(ProjectDependencies) = postProject
+		{27060CA7-FB29-42BC-BA66-7FC80D498354} = {27060CA7-FB29-42BC-BA66-7FC80D498354}
+	EndProjectSection
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "solution items", "solution items", "{3B960F8F-AD5D-45E7-92C0-05B65E200AC4}"
+	ProjectSection(SolutionItems) = preProject
+		.editorconfig = .editorconfig
+		appveyor.yml = appveyor.yml
+		logviewer.xml = logviewer.xml
+		WiX.msbuild = WiX.msbuild
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.tests", "logviewer.tests\logviewer.tests.csproj", "{939DD379-CDC8-47EF-8D37-0E5E71D99D30}"
+	ProjectSection(ProjectDependencies) = postProject
+		{383C08FC-9CAC-42E5-9B02-471561479A74} = {383C08FC-9CAC-42E5-9B02-471561479A74}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.logic", "logviewer.logic\logviewer.logic.csproj", "{383C08FC-9CAC-42E5-9B02-471561479A74}"
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = ".nuget", ".nuget", "{B720ED85-58CF-4840-B1AE-55B0049212CC}"
+	ProjectSection(SolutionItems) = preProject
+		.nuget\NuGet.Config = .nuget\NuGet.Config
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.engine", "logviewer.engine\logviewer.engine.csproj", "{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.install.mca", "logviewer.install.mca\logviewer.install.mca.csproj", "{405827CB-84E1-46F3-82C9-D889892645AC}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.ui", "logviewer.ui\logviewer.ui.csproj", "{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.bench", "logviewer.bench\logviewer.bench.csproj", "{75E0C034-44C8-461B-A677-9A19566FE393}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Debug|Mixed Platforms = Debug|Mixed Platforms
+		Debug|x86 = Debug|x86
+		Release|Any CPU = Release|Any CPU
+		Release|Mixed Platforms = Release|Mixed Platforms
+		Release|x86 = Release|x86
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Any CPU.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Mixed Platforms.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Mixed Platforms.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|x86.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|x86.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Any CPU.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Any CPU.Build.0 = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Mixed Platforms.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Mixed Platforms.Build.0 = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|x86.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|x86.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Any CPU.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Mixed Platforms.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Mixed Platforms.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|x86.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|x86.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Any CPU.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Any CPU.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Mixed Platforms.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Mixed Platforms.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|x86.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|x86.Build.0 = Release|x86
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Any CPU.Build.0 = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|x86.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Any CPU.Build.0 = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|x86.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Any CPU.Build.0 = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|x86.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Any CPU.Build.0 = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|x86.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Any CPU.Build.0 = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|x86.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|x86.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Any CPU.Build.0 = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|x86.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|x86.Build.0 = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(SolutionProperties) = preSolution
+		HideSolutionNode = FALSE
+	EndGlobalSection
+EndGlobal
+"#;
+}
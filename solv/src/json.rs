@@ -1,10 +1,33 @@
 use std::fmt::{self, Display};
 
-use solp::Consume;
+use itertools::Itertools;
+use serde::Serialize;
+use serde_json::Value;
+use solp::api::Solution;
+use solp::{msbuild, Consume};
+
+use crate::{loader, StatsJson};
+
+/// Project fields that can be selected and names they're serialized with
+const PROJECT_FIELDS: [(&str, &str); 11] = [
+    ("type", "type_id"),
+    ("type_description", "type_description"),
+    ("id", "id"),
+    ("name", "name"),
+    ("path", "path_or_uri"),
+    ("configurations", "configurations"),
+    ("items", "items"),
+    ("depends_from", "depends_from"),
+    ("line", "line"),
+    ("column", "column"),
+    ("sdk_style", "sdk_style"),
+];
 
 pub struct Json {
     serialized: Vec<String>,
     pretty: bool,
+    resolve_sdk: bool,
+    fields: Option<Vec<&'static str>>,
 }
 
 impl Json {
@@ -13,18 +36,111 @@ impl Json {
         Self {
             serialized: vec![],
             pretty,
+            resolve_sdk: false,
+            fields: None,
+        }
+    }
+
+    /// Emits only project fields specified. Use [`parse_project_fields`] to get them
+    #[must_use]
+    pub fn with_fields(mut self, fields: Option<Vec<&'static str>>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// Enables loading of project files to find out whether they're SDK-style ones
+    #[must_use]
+    pub fn with_sdk_resolving(mut self, resolve_sdk: bool) -> Self {
+        self.resolve_sdk = resolve_sdk;
+        self
+    }
+}
+
+/// Parses comma separated project fields list like `name,id,path,type`.
+/// Returns the fields specified. Selected fields are emitted with these names
+///
+/// # Errors
+/// Returns an error listing all valid fields if some field is unknown
+pub fn parse_project_fields(fields: &str) -> Result<Vec<&'static str>, String> {
+    fields
+        .split(',')
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .map(|field| {
+            PROJECT_FIELDS
+                .iter()
+                .find(|(name, _)| *name == field)
+                .map(|(name, _)| *name)
+                .ok_or_else(|| {
+                    format!(
+                        "Unknown project field '{field}'. Valid fields are: {}",
+                        PROJECT_FIELDS.iter().map(|(name, _)| name).join(", ")
+                    )
+                })
+        })
+        .collect()
+}
+
+/// Keeps only fields specified in every solution's project renaming them
+/// from the names they're serialized with to the names they're selected by
+fn select_project_fields(solution: &mut Value, fields: &[&str]) {
+    if let Some(projects) = solution.get_mut("projects").and_then(Value::as_array_mut) {
+        for project in projects.iter_mut().filter_map(Value::as_object_mut) {
+            let mut selected = serde_json::Map::new();
+            for (name, serialized) in PROJECT_FIELDS.iter().filter(|(n, _)| fields.contains(n)) {
+                if let Some(value) = project.remove(*serialized) {
+                    selected.insert((*name).to_owned(), value);
+                }
+            }
+            *project = selected;
+        }
+    }
+}
+
+fn to_json<T: Serialize>(value: &T, pretty: bool) -> serde_json::Result<String> {
+    if pretty {
+        serde_json::to_string_pretty(value)
+    } else {
+        serde_json::to_string(value)
+    }
+}
+
+/// Adds SDK-style flag to every serialized project which file can be read.
+/// The flag is found out by loading project files so it isn't a part of parsed solution
+fn add_sdk_style(value: &mut Value, solution: &Solution) {
+    let dir = crate::parent_of(solution.path);
+    let Some(projects) = value.get_mut("projects").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for (serialized, project) in projects.iter_mut().zip(&solution.projects) {
+        if msbuild::is_solution_folder(project.type_id) {
+            continue;
+        }
+        let sdk_style = loader::project_path(dir, project)
+            .and_then(|path| loader::load_msbuild_project(&path))
+            .map(|p| p.is_sdk_project());
+        if let (Some(sdk_style), Some(serialized)) = (sdk_style, serialized.as_object_mut()) {
+            serialized.insert("sdk_style".to_owned(), Value::Bool(sdk_style));
         }
     }
 }
 
 impl Consume for Json {
-    fn ok(&mut self, solution: &solp::api::Solution) {
-        let conveter = if self.pretty {
-            serde_json::to_string_pretty
+    fn ok(&mut self, solution: &Solution) {
+        let serialized = if self.resolve_sdk || self.fields.is_some() {
+            serde_json::to_value(solution).and_then(|mut value| {
+                if self.resolve_sdk {
+                    add_sdk_style(&mut value, solution);
+                }
+                if let Some(fields) = &self.fields {
+                    select_project_fields(&mut value, fields);
+                }
+                to_json(&value, self.pretty)
+            })
         } else {
-            serde_json::to_string
+            to_json(solution, self.pretty)
         };
-        if let Ok(s) = conveter(solution) {
+        if let Ok(s) = serialized {
             self.serialized.push(s);
         }
     }
@@ -32,6 +148,12 @@ impl Consume for Json {
     fn err(&self, _path: &str) {}
 }
 
+impl StatsJson for Json {
+    fn stats_json(&self) -> String {
+        serde_json::json!({ "solutions": self.serialized.len() }).to_string()
+    }
+}
+
 impl Display for Json {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let many_solutions = self.serialized.len() > 1;
@@ -55,6 +177,7 @@ impl Display for Json {
 mod tests {
     use super::*;
     use rstest::rstest;
+    use std::fs;
 
     #[rstest]
     #[case(true)]
@@ -90,6 +213,142 @@ mod tests {
         assert_eq!(2, deserialized.unwrap().projects.len())
     }
 
+    #[test]
+    fn sdk_style_resolving() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("sdk.csproj"), SDK_PROJECT).unwrap();
+        fs::write(dir.path().join("legacy.vcxproj"), VCXPROJ).unwrap();
+        let path = dir.path().join("s.sln");
+        let path = path.to_str().unwrap();
+        let mut solution = solp::parse_str(SOLUTION_WITH_SDK_AND_LEGACY_PROJECTS).unwrap();
+        solution.path = path;
+        let mut json = Json::new(false).with_sdk_resolving(true);
+
+        // Act
+        json.ok(&solution);
+
+        // Assert
+        let s = format!("{json}");
+        let value = serde_json::from_str::<Value>(&s).unwrap();
+        let sdk_styles = value["projects"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| (p["name"].as_str().unwrap(), p["sdk_style"].as_bool()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                ("sdk", Some(true)),
+                ("legacy", Some(false)),
+                ("missing", None)
+            ],
+            sdk_styles
+        );
+    }
+
+    #[test]
+    fn sdk_style_not_resolved_by_default() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_SDK_AND_LEGACY_PROJECTS).unwrap();
+        let mut json = Json::new(false);
+
+        // Act
+        json.ok(&solution);
+
+        // Assert
+        let s = format!("{json}");
+        assert!(!s.contains("sdk_style"));
+    }
+
+    #[test]
+    fn only_selected_fields() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_SDK_AND_LEGACY_PROJECTS).unwrap();
+        let fields = parse_project_fields("name,path").unwrap();
+        let mut json = Json::new(false).with_fields(Some(fields));
+
+        // Act
+        json.ok(&solution);
+
+        // Assert
+        let s = format!("{json}");
+        let value = serde_json::from_str::<Value>(&s).unwrap();
+        let projects = value["projects"].as_array().unwrap();
+        assert_eq!(3, projects.len());
+        for project in projects {
+            let keys = project.as_object().unwrap().keys().collect::<Vec<_>>();
+            assert_eq!(vec!["name", "path"], keys);
+        }
+        assert_eq!("sdk.csproj", projects[0]["path"]);
+    }
+
+    #[rstest]
+    #[case("name,path", Ok(vec!["name", "path"]))]
+    #[case(" type , id ", Ok(vec!["type", "id"]))]
+    #[case("", Ok(vec![]))]
+    #[trace]
+    fn parse_project_fields_tests(
+        #[case] fields: &str,
+        #[case] expected: Result<Vec<&'static str>, String>,
+    ) {
+        // Arrange
+
+        // Act
+        let actual = parse_project_fields(fields);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn parse_project_fields_unknown_field() {
+        // Arrange
+
+        // Act
+        let actual = parse_project_fields("name,guid");
+
+        // Assert
+        let error = actual.unwrap_err();
+        assert!(error.contains("'guid'"));
+        assert!(error.contains("name, path, configurations"));
+    }
+
+    const SOLUTION_WITH_SDK_AND_LEGACY_PROJECTS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "sdk", "sdk.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "legacy", "legacy.vcxproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "missing", "missing.csproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SDK_PROJECT: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
+    <PropertyGroup>
+      <TargetFramework>net8.0</TargetFramework>
+    </PropertyGroup>
+</Project>
+"#;
+
+    const VCXPROJ: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<Project DefaultTargets="Build" ToolsVersion="14.0" xmlns="http://schemas.microsoft.com/developer/msbuild/2003">
+  <ItemGroup Label="ProjectConfigurations">
+    <ProjectConfiguration Include="Release|x64">
+      <Configuration>Release</Configuration>
+      <Platform>x64</Platform>
+    </ProjectConfiguration>
+  </ItemGroup>
+  <Import Project="$(VCTargetsPath)\Microsoft.Cpp.Default.props" />
+</Project>
+"#;
+
     const CORRECT_SOLUTION: &str = r#"
 Microsoft Visual Studio Solution File, Format Version 8.00
 Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest", "gtest.vcproj", "{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}"
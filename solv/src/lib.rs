@@ -1,44 +1,72 @@
 #![warn(unused_extern_crates)]
 #![allow(clippy::missing_errors_doc)]
+pub mod analyze;
+pub mod baseline;
+pub mod buildorder;
+pub mod configs;
+pub mod duplicates;
 pub mod error;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(test)]
+mod fixtures;
+pub mod forbidden;
+pub mod graph;
 pub mod info;
 pub mod json;
+pub mod loader;
+pub mod matrix;
 pub mod nuget;
+pub mod stats;
+pub mod tee;
+pub mod timing;
+pub mod toml;
 pub mod ux;
 pub mod validate;
+pub mod watch;
+
+use color_eyre::eyre::{Context, Result};
+use solp::{Consume, ParseOptions};
+use std::io::{BufRead, Read, Seek};
+use std::path::Path;
+use zip::ZipArchive;
 
-use solp::Consume;
-use std::path::{Path, PathBuf};
-use url::Url;
+/// Consumer's final aggregate that can be printed as a single compact json line
+pub trait StatsJson {
+    /// Serializes consumer's aggregate into compact json
+    fn stats_json(&self) -> String;
+}
 
 #[must_use]
 pub fn parent_of(path: &str) -> &Path {
     Path::new(path).parent().unwrap_or_else(|| Path::new(""))
 }
 
+/// Makes path relative to the base directory if it's specified and the path is inside it.
+/// Paths outside the base are returned as absolute ones
 #[must_use]
-pub fn try_make_local_path(dir: &Path, relative: &str) -> Option<PathBuf> {
-    // We dont need Uri so if parsed successfully throw it away
-    if Url::parse(relative).is_ok() {
-        None
-    } else {
-        Some(make_path(dir, relative))
+pub fn relative_to(path: &str, base: Option<&Path>) -> String {
+    let Some(base) = base else {
+        return path.to_owned();
+    };
+    let (Ok(absolute), Ok(base)) = (std::path::absolute(path), std::path::absolute(base)) else {
+        return path.to_owned();
+    };
+    let absolute = loader::normalize_path(&absolute);
+    let base = loader::normalize_path(&base);
+    if absolute.starts_with(&base) {
+        if let Some(relative) = pathdiff::diff_paths(&absolute, &base) {
+            return relative.to_string_lossy().into_owned();
+        }
     }
+    absolute.to_string_lossy().into_owned()
 }
 
+/// Whether solution has at least the number of projects specified if any.
+/// Solution folders aren't counted
 #[must_use]
-#[cfg(not(target_os = "windows"))]
-pub fn make_path(dir: &Path, relative: &str) -> PathBuf {
-    // Converts all possible Windows paths into Unix ones
-    relative
-        .split('\\')
-        .fold(PathBuf::from(&dir), |pb, s| pb.join(s))
-}
-
-#[must_use]
-#[cfg(target_os = "windows")]
-fn make_path(dir: &Path, relative: &str) -> PathBuf {
-    PathBuf::from(&dir).join(relative)
+pub fn has_min_projects(solution: &solp::api::Solution, min_projects: Option<usize>) -> bool {
+    min_projects.is_none_or(|min| solution.iterate_projects().count() >= min)
 }
 
 #[must_use]
@@ -50,42 +78,105 @@ pub fn calculate_percent(value: i32, total: i32) -> f64 {
     }
 }
 
+/// Parses solutions which paths are read line by line from the reader specified.
+/// Empty lines are skipped and paths that cannot be read or parsed are reported using `err`
+/// function of the consumer. Returns the number of successfully parsed solutions
+pub fn parse_paths<R: BufRead>(
+    reader: R,
+    consumer: &mut dyn Consume,
+    options: &ParseOptions,
+) -> usize {
+    let paths = reader
+        .lines()
+        .map_while(std::result::Result::ok)
+        .filter(|line| !line.trim().is_empty());
+
+    let mut parsed = 0;
+    for line in paths {
+        if consumer.should_stop() {
+            break;
+        }
+        if solp::parse_file_with_options(line.trim(), consumer, options).is_ok() {
+            parsed += 1;
+        }
+    }
+    parsed
+}
+
+/// Parses solutions found inside zip archive without extracting it to disk.
+/// Only entries with the extension specified are parsed and entry name is used as solution path.
+/// Entries that cannot be read or parsed are reported using `err_with` function of the consumer.
+/// Returns the number of successfully parsed solutions
+///
+/// # Errors
+/// Returns an error if the reader doesn't contain a valid zip archive
+pub fn parse_zip<R: Read + Seek>(
+    reader: R,
+    extension: &str,
+    consumer: &mut dyn Consume,
+    options: &ParseOptions,
+) -> Result<usize> {
+    let mut archive = ZipArchive::new(reader).wrap_err("Failed to open zip archive")?;
+    let extension = extension.trim_start_matches('.');
+    let mut names = archive
+        .file_names()
+        .filter(|name| {
+            Path::new(name)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+        })
+        .map(str::to_owned)
+        .collect::<Vec<String>>();
+    names.sort();
+
+    let mut parsed = 0;
+    for name in names {
+        if consumer.should_stop() {
+            break;
+        }
+        let result = read_zip_entry(&mut archive, &name)
+            .wrap_err_with(|| format!("Failed to read zip entry: {name}"))
+            .inspect_err(|e| consumer.err_with(&name, e))
+            .and_then(|bytes| {
+                solp::parse_bytes_with_options(&name, &bytes, None, consumer, options)
+            });
+        if result.is_ok() {
+            parsed += 1;
+        }
+    }
+    Ok(parsed)
+}
+
+fn read_zip_entry<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Result<Vec<u8>> {
+    let mut entry = archive.by_name(name)?;
+    let mut bytes = Vec::new();
+    entry.read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
 #[cfg(test)]
 #[cfg(not(target_os = "windows"))]
 pub mod tests {
     use super::*;
+    use crate::fixtures::SINGLE_PROJECT_SOLUTION;
     use rstest::rstest;
+    use solp::api::Solution;
+    use std::cell::RefCell;
+    use std::io::{Cursor, Write};
 
     #[rstest]
-    #[case("/base", "x", "/base/x")]
-    #[case("/base", r"x\y", "/base/x/y")]
-    #[case("/base", "x/y", "/base/x/y")]
-    #[trace]
-    fn make_path_tests(#[case] base: &str, #[case] path: &str, #[case] expected: &str) {
-        // Arrange
-        let d = Path::new(base);
-
-        // Act
-        let actual = make_path(d, path);
-
-        // Assert
-        assert_eq!(actual.to_str().unwrap(), expected);
-    }
-
-    #[rstest]
-    #[case("/base", "x", Some(PathBuf::from("/base/x")))]
-    #[case("/base", "http://localhost/a.csproj", None)]
+    #[case("/base/x/a.sln", Some("/base"), "x/a.sln")]
+    #[case("/base/x/../y/a.sln", Some("/base"), "y/a.sln")]
+    #[case("/other/a.sln", Some("/base"), "/other/a.sln")]
+    #[case("/base/../other/a.sln", Some("/base"), "/other/a.sln")]
+    #[case("/base/x/a.sln", None, "/base/x/a.sln")]
     #[trace]
-    fn try_make_local_path_tests(
-        #[case] base: &str,
-        #[case] path: &str,
-        #[case] expected: Option<PathBuf>,
-    ) {
+    fn relative_to_tests(#[case] path: &str, #[case] base: Option<&str>, #[case] expected: &str) {
         // Arrange
-        let d = Path::new(base);
+        let base = base.map(Path::new);
 
         // Act
-        let actual = try_make_local_path(d, path);
+        let actual = relative_to(path, base);
 
         // Assert
         assert_eq!(actual, expected);
@@ -146,4 +237,64 @@ pub mod tests {
         // Assert
         assert_eq!(actual, expected);
     }
+
+    #[derive(Default)]
+    struct Paths {
+        parsed: Vec<String>,
+        failed: RefCell<Vec<String>>,
+    }
+
+    impl Consume for Paths {
+        fn ok(&mut self, solution: &Solution) {
+            self.parsed.push(solution.path.to_owned());
+        }
+
+        fn err(&self, path: &str) {
+            self.failed.borrow_mut().push(path.to_owned());
+        }
+    }
+
+    #[test]
+    fn parse_paths_from_reader() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let existing = dir.path().join("a.sln");
+        std::fs::write(&existing, SINGLE_PROJECT_SOLUTION).unwrap();
+        let missing = dir.path().join("b.sln");
+        let existing = existing.to_str().unwrap();
+        let missing = missing.to_str().unwrap();
+        let input = format!("{existing}\n\n{missing}\n");
+        let mut consumer = Paths::default();
+
+        // Act
+        let actual = parse_paths(Cursor::new(input), &mut consumer, &ParseOptions::default());
+
+        // Assert
+        assert_eq!(1, actual);
+        assert_eq!(vec![existing.to_owned()], consumer.parsed);
+        assert_eq!(vec![missing.to_owned()], *consumer.failed.borrow());
+    }
+
+    #[test]
+    fn parse_zip_with_solution() {
+        // Arrange
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = zip::write::SimpleFileOptions::default();
+        writer.start_file("src/a.sln", options).unwrap();
+        writer
+            .write_all(SINGLE_PROJECT_SOLUTION.as_bytes())
+            .unwrap();
+        writer.start_file("src/readme.md", options).unwrap();
+        writer.write_all(b"readme").unwrap();
+        let archive = writer.finish().unwrap();
+        let mut consumer = Paths::default();
+
+        // Act
+        let actual = parse_zip(archive, "sln", &mut consumer, &ParseOptions::default()).unwrap();
+
+        // Assert
+        assert_eq!(1, actual);
+        assert_eq!(vec!["src/a.sln".to_owned()], consumer.parsed);
+        assert!(consumer.failed.borrow().is_empty());
+    }
 }
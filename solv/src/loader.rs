@@ -0,0 +1,1034 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{mpsc, Arc, LazyLock, Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use color_eyre::eyre::{eyre, Result};
+use itertools::Itertools;
+use petgraph::graph::{DiGraph, NodeIndex};
+use serde::Deserialize;
+use solp::{api, msbuild};
+
+const GLOBAL_JSON: &str = "global.json";
+const DIRECTORY_BUILD_PROPS: &str = "Directory.Build.props";
+
+/// Minimal `global.json` structure
+#[derive(Debug, Default, Deserialize)]
+pub struct GlobalJson {
+    pub sdk: Option<GlobalJsonSdk>,
+}
+
+/// SDK section of the `global.json`
+#[derive(Debug, Default, Deserialize)]
+pub struct GlobalJsonSdk {
+    pub version: Option<String>,
+}
+
+/// Build settings defined outside of projects i.e. in `global.json` and `Directory.Build.props`
+#[derive(Debug, Default)]
+pub struct BuildProps {
+    /// SDK version pinned by the nearest `global.json`
+    pub sdk_version: Option<String>,
+    /// Target framework defined centrally by the nearest `Directory.Build.props`
+    pub target_framework: Option<String>,
+}
+
+/// Parsed project files shared by all solutions scanned so that project
+/// included into many solutions is read only once.
+/// Entries are keyed by canonical path and invalidated when file modification time changes
+#[derive(Default)]
+pub struct ProjectCache {
+    entries: HashMap<PathBuf, (SystemTime, Arc<msbuild::Project>)>,
+    hits: usize,
+}
+
+impl ProjectCache {
+    /// Returns cached project if file wasn't modified since it was read or reads it otherwise
+    pub fn load(cache: &Mutex<ProjectCache>, path: &Path) -> Option<Arc<msbuild::Project>> {
+        let lock = || cache.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some((canonical, modified)) = cache_key(path) else {
+            return read_msbuild_project(path).map(Arc::new);
+        };
+        if let Some(project) = lock().get(&canonical, modified) {
+            return Some(project);
+        }
+        // Lock isn't held while parsing so that slow or timed out parsing doesn't block other loads
+        let project = Arc::new(read_msbuild_project(&canonical)?);
+        lock().insert(canonical, modified, Arc::clone(&project));
+        Some(project)
+    }
+
+    /// The number of loads served from cache
+    #[must_use]
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    fn get(&mut self, canonical: &Path, modified: SystemTime) -> Option<Arc<msbuild::Project>> {
+        let (cached_modified, project) = self.entries.get(canonical)?;
+        if *cached_modified != modified {
+            return None;
+        }
+        self.hits += 1;
+        Some(Arc::clone(project))
+    }
+
+    fn insert(&mut self, canonical: PathBuf, modified: SystemTime, project: Arc<msbuild::Project>) {
+        self.entries.insert(canonical, (modified, project));
+    }
+}
+
+/// Canonical path and modification time of the file that identify cache entry
+fn cache_key(path: &Path) -> Option<(PathBuf, SystemTime)> {
+    let canonical = fs::canonicalize(path).ok()?;
+    let modified = fs::metadata(&canonical).and_then(|m| m.modified()).ok()?;
+    Some((canonical, modified))
+}
+
+static PROJECT_CACHE: LazyLock<Mutex<ProjectCache>> = LazyLock::new(Mutex::default);
+
+/// Reads `MSBuild` project file from path specified using process wide cache.
+/// Returns None if file cannot be read or parsed
+#[must_use]
+pub fn load_msbuild_project(path: &Path) -> Option<Arc<msbuild::Project>> {
+    ProjectCache::load(&PROJECT_CACHE, path)
+}
+
+/// Reads `MSBuild` project file like [`load_msbuild_project`] but gives up on files
+/// larger than the size specified or if parsing takes longer than the timeout specified.
+/// Parsing that timed out isn't interrupted and finishes in background
+///
+/// # Errors
+/// Returns an error if the file is too large or parsing timed out
+pub fn load_msbuild_project_limited(
+    path: &Path,
+    max_size: Option<u64>,
+    timeout: Option<Duration>,
+) -> Result<Option<Arc<msbuild::Project>>> {
+    if let Some(max_size) = max_size {
+        let size = fs::metadata(path).map(|m| m.len()).unwrap_or_default();
+        if size > max_size {
+            return Err(eyre!(
+                "Project file size {size} bytes exceeds {max_size} bytes limit"
+            ));
+        }
+    }
+    let Some(timeout) = timeout else {
+        return Ok(load_msbuild_project(path));
+    };
+    let (sender, receiver) = mpsc::channel();
+    let owned = path.to_path_buf();
+    thread::spawn(move || {
+        let _ = sender.send(load_msbuild_project(&owned));
+    });
+    receiver.recv_timeout(timeout).map_err(|_| {
+        eyre!(
+            "Project file parsing takes longer than {}",
+            humantime::format_duration(timeout)
+        )
+    })
+}
+
+fn read_msbuild_project(path: &Path) -> Option<msbuild::Project> {
+    match msbuild::Project::from_path(path) {
+        Ok(project) => Some(project),
+        Err(e) => {
+            if cfg!(debug_assertions) {
+                let p = path.to_str().unwrap_or_default();
+                println!("{p}: {e:?}");
+            }
+            None
+        }
+    }
+}
+
+/// Makes full path of solution's project file.
+/// Returns None for projects that have no local file like web sites
+#[must_use]
+pub fn project_path(solution_dir: &Path, project: &api::Project) -> Option<PathBuf> {
+    if msbuild::is_web_site_project(project.type_id) {
+        None
+    } else {
+        project.resolve_existing(solution_dir).into_local_path()
+    }
+}
+
+/// Shows whether project is a test project.
+/// Uses project type id first and then, if project file available,
+/// checks whether it's SDK project that references test packages
+#[must_use]
+pub fn is_test_project(solution_dir: &Path, project: &api::Project) -> bool {
+    project.is_test_project()
+        || project_path(solution_dir, project)
+            .filter(|p| p.is_file())
+            .and_then(|p| load_msbuild_project(&p))
+            .is_some_and(|p| p.is_test_project())
+}
+
+/// Finds the nearest file with the name specified walking up from the directory specified
+#[must_use]
+pub fn find_up(dir: &Path, file_name: &str) -> Option<PathBuf> {
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    std::path::absolute(dir)
+        .ok()?
+        .ancestors()
+        .map(|d| d.join(file_name))
+        .find(|f| f.is_file())
+}
+
+/// Reads SDK version and central target framework starting from the directory specified
+#[must_use]
+pub fn load_build_props(dir: &Path) -> BuildProps {
+    let sdk_version = find_up(dir, GLOBAL_JSON)
+        .and_then(|p| std::fs::read_to_string(p).ok())
+        .and_then(|s| serde_json::from_str::<GlobalJson>(&s).ok())
+        .and_then(|g| g.sdk?.version);
+    let target_framework = find_up(dir, DIRECTORY_BUILD_PROPS)
+        .and_then(|p| load_msbuild_project(&p))
+        .and_then(|p| p.target_framework().map(str::to_owned));
+    BuildProps {
+        sdk_version,
+        target_framework,
+    }
+}
+
+/// Resolves `.` and `..` path components without touching file system
+/// so that it works for paths that don't exist
+#[must_use]
+pub fn normalize_path(path: &Path) -> PathBuf {
+    path.components()
+        .fold(PathBuf::new(), |mut result, component| {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    if !result.pop() {
+                        result.push(component);
+                    }
+                }
+                _ => result.push(component),
+            }
+            result
+        })
+}
+
+fn project_references<'a>(
+    project: &'a msbuild::Project,
+    project_dir: &'a Path,
+) -> impl Iterator<Item = PathBuf> + 'a {
+    project
+        .item_group
+        .iter()
+        .flatten()
+        .filter_map(|ig| ig.project_reference.as_ref())
+        .flatten()
+        .map(move |r| normalize_path(&solp::api::local_path(project_dir, &r.include)))
+}
+
+/// Builds transitive `ProjectReference` graph of project files. Graph traversal starts from
+/// solution's projects and follows references even outside the solution.
+/// Every project file is loaded once so cycles are safe. Missing or unreadable files stay leaf nodes
+#[must_use]
+pub fn project_reference_graph(solution: &api::Solution) -> DiGraph<PathBuf, ()> {
+    let dir = crate::parent_of(solution.path);
+    let mut graph = DiGraph::<PathBuf, ()>::new();
+    let mut nodes: HashMap<PathBuf, NodeIndex> = HashMap::new();
+    let mut pending = solution
+        .iterate_projects()
+        .filter_map(|p| project_path(dir, p))
+        .map(|p| normalize_path(&p))
+        .collect::<Vec<PathBuf>>();
+    let mut visited = HashSet::new();
+
+    while let Some(path) = pending.pop() {
+        if !path.is_file() || !visited.insert(path.clone()) {
+            continue;
+        }
+        let (Some(project), Some(project_dir)) = (load_msbuild_project(&path), path.parent())
+        else {
+            continue;
+        };
+        let from = *nodes
+            .entry(path.clone())
+            .or_insert_with(|| graph.add_node(path.clone()));
+        for reference in project_references(&project, project_dir) {
+            let to = *nodes
+                .entry(reference.clone())
+                .or_insert_with(|| graph.add_node(reference.clone()));
+            graph.add_edge(from, to, ());
+            pending.push(reference);
+        }
+    }
+    graph
+}
+
+/// Finds `ProjectReference` cycles between project files.
+/// Returns every cycle found as sorted project paths list
+#[must_use]
+pub fn project_reference_cycles(solution: &api::Solution) -> Vec<Vec<PathBuf>> {
+    let graph = project_reference_graph(solution);
+
+    // Every strongly connected component with more then one node
+    // or a project that references itself is a cycle
+    petgraph::algo::tarjan_scc(&graph)
+        .into_iter()
+        .filter(|scc| scc.len() > 1 || graph.contains_edge(scc[0], scc[0]))
+        .map(|scc| {
+            scc.into_iter()
+                .map(|ix| graph[ix].clone())
+                .sorted()
+                .collect()
+        })
+        .sorted()
+        .collect()
+}
+
+fn comparable(path: &Path) -> String {
+    path.to_string_lossy().to_lowercase()
+}
+
+/// Finds `ProjectReference` items of solution's projects that point to projects
+/// not included into the solution. References resolved relative to referring project's directory.
+/// Returns map where key is referring project path and value is referenced paths outside solution
+#[must_use]
+pub fn references_outside_solution(
+    solution: &api::Solution,
+) -> BTreeMap<PathBuf, BTreeSet<PathBuf>> {
+    let dir = crate::parent_of(solution.path);
+    let paths = solution
+        .iterate_projects()
+        .filter_map(|p| project_path(dir, p))
+        .map(|p| normalize_path(&p))
+        .collect::<Vec<PathBuf>>();
+    let known = paths
+        .iter()
+        .map(|p| comparable(p))
+        .collect::<HashSet<String>>();
+
+    paths
+        .iter()
+        .filter(|p| p.is_file())
+        .filter_map(|path| {
+            let project = load_msbuild_project(path)?;
+            let project_dir = path.parent()?;
+            let outside = project_references(&project, project_dir)
+                .filter(|r| !known.contains(&comparable(r)))
+                .collect::<BTreeSet<PathBuf>>();
+            if outside.is_empty() {
+                None
+            } else {
+                Some((path.clone(), outside))
+            }
+        })
+        .collect()
+}
+
+/// Finds existing project files reachable from solution's projects through `ProjectReference`
+/// items, directly or transitively, that aren't included into the solution
+#[must_use]
+pub fn transitive_references_outside_solution(solution: &api::Solution) -> BTreeSet<PathBuf> {
+    let dir = crate::parent_of(solution.path);
+    let known = solution
+        .iterate_projects()
+        .filter_map(|p| project_path(dir, p))
+        .map(|p| comparable(&normalize_path(&p)))
+        .collect::<HashSet<String>>();
+    project_reference_graph(solution)
+        .node_weights()
+        .filter(|p| !known.contains(&comparable(p)) && p.is_file())
+        .cloned()
+        .collect()
+}
+
+/// Finds projects which files set the same `OutputPath` for the same solution configuration
+/// so that they overwrite each other's build results. Project configuration and platform
+/// are the ones solution configuration is mapped to. Spaces are removed from platform
+/// because `MSBuild` uses `AnyCPU` where solutions use `Any CPU`.
+/// Returns map where key is solution configuration and shared output directory
+/// and value is names of projects that build into it
+#[must_use]
+pub fn shared_output_paths<'a>(
+    solution: &api::Solution<'a>,
+) -> BTreeMap<(String, PathBuf), BTreeSet<&'a str>> {
+    let dir = crate::parent_of(solution.path);
+    let mut outputs: BTreeMap<(String, PathBuf), BTreeSet<&'a str>> = BTreeMap::new();
+    for project in solution.iterate_projects() {
+        let Some(path) = project_path(dir, project).filter(|p| p.is_file()) else {
+            continue;
+        };
+        let (Some(msbuild_project), Some(project_dir)) =
+            (load_msbuild_project(&path), path.parent())
+        else {
+            continue;
+        };
+        for c in project
+            .configurations
+            .iter()
+            .flatten()
+            .filter(|c| c.is_buildable())
+        {
+            let platform = c.project_platform.replace(' ', "");
+            let Some(output) = msbuild_project.output_path(c.configuration, &platform) else {
+                continue;
+            };
+            let output = normalize_path(&solp::api::local_path(project_dir, &output));
+            outputs
+                .entry((
+                    format!("{}|{}", c.solution_configuration, c.platform),
+                    output,
+                ))
+                .or_default()
+                .insert(project.name);
+        }
+    }
+    outputs.retain(|_, projects| projects.len() > 1);
+    outputs
+}
+
+/// Whether import can be resolved without evaluating `MSBuild` i.e. it's not SDK import,
+/// has no condition and its path has neither properties nor wildcards
+fn is_resolvable_import(import: &msbuild::Import) -> bool {
+    import.sdk.is_none()
+        && import.condition.is_none()
+        && !import.project.is_empty()
+        && !import.project.contains("$(")
+        && !import.project.contains('*')
+}
+
+/// Finds `Import` elements of solution's projects that point to not existing files.
+/// Imports are resolved relative to importing project's directory. SDK imports, conditional
+/// imports and imports using properties or wildcards are skipped because they cannot be resolved
+/// without evaluating `MSBuild`. Returns map where key is importing project path
+/// and value is missing imported paths
+#[must_use]
+pub fn missing_imports(solution: &api::Solution) -> BTreeMap<PathBuf, BTreeSet<PathBuf>> {
+    let dir = crate::parent_of(solution.path);
+    solution
+        .iterate_projects()
+        .filter_map(|p| project_path(dir, p))
+        .map(|p| normalize_path(&p))
+        .filter(|p| p.is_file())
+        .filter_map(|path| {
+            let project = load_msbuild_project(&path)?;
+            let project_dir = path.parent()?;
+            let grouped = project
+                .import_group
+                .iter()
+                .flatten()
+                .filter_map(|g| g.imports.as_ref())
+                .flatten();
+            let missing = project
+                .imports
+                .iter()
+                .flatten()
+                .chain(grouped)
+                .filter(|i| is_resolvable_import(i))
+                .map(|i| normalize_path(&solp::api::local_path(project_dir, &i.project)))
+                .filter(|i| !i.is_file())
+                .collect::<BTreeSet<PathBuf>>();
+            if missing.is_empty() {
+                None
+            } else {
+                Some((path, missing))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::fixtures::{PROJECT_REFERENCING_A, SINGLE_SDK_PROJECT_SOLUTION};
+
+    #[test]
+    fn project_cache_hits_on_repeated_path() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("t.csproj");
+        fs::write(&path, REAL_SDK_PROJECT).unwrap();
+        let cache = Mutex::new(ProjectCache::default());
+
+        // Act
+        let first = ProjectCache::load(&cache, &path).unwrap();
+        let second = ProjectCache::load(&cache, &dir.path().join(".").join("t.csproj")).unwrap();
+
+        // Assert
+        assert_eq!(1, cache.lock().unwrap().hits());
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn project_cache_invalidated_on_modification() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("t.csproj");
+        fs::write(&path, REAL_SDK_PROJECT).unwrap();
+        let cache = Mutex::new(ProjectCache::default());
+        let first = ProjectCache::load(&cache, &path).unwrap();
+        let modified = fs::metadata(&path).unwrap().modified().unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(modified + std::time::Duration::from_secs(10))
+            .unwrap();
+
+        // Act
+        let second = ProjectCache::load(&cache, &path).unwrap();
+
+        // Assert
+        assert_eq!(0, cache.lock().unwrap().hits());
+        assert!(!Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn project_cache_missing_file() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let cache = Mutex::new(ProjectCache::default());
+
+        // Act
+        let actual = ProjectCache::load(&cache, &dir.path().join("missing.csproj"));
+
+        // Assert
+        assert!(actual.is_none());
+        assert_eq!(0, cache.lock().unwrap().hits());
+    }
+
+    #[test]
+    fn load_msbuild_project_limited_too_large() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("t.csproj");
+        fs::write(&path, REAL_SDK_PROJECT).unwrap();
+
+        // Act
+        let actual = load_msbuild_project_limited(&path, Some(10), None);
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn load_msbuild_project_limited_within_limits() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("t.csproj");
+        fs::write(&path, REAL_SDK_PROJECT).unwrap();
+        let max_size = REAL_SDK_PROJECT.len() as u64;
+
+        // Act
+        let actual =
+            load_msbuild_project_limited(&path, Some(max_size), Some(Duration::from_secs(10)));
+
+        // Assert
+        assert!(actual.unwrap().is_some());
+    }
+
+    #[test]
+    fn load_msbuild_project_limited_after_timeout() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let huge = dir.path().join("huge.csproj");
+        let references = (0..100_000)
+            .map(|i| format!(r#"<PackageReference Include="P{i}" Version="1.0.0" />"#))
+            .collect::<String>();
+        fs::write(
+            &huge,
+            format!(
+                r#"<Project Sdk="Microsoft.NET.Sdk"><ItemGroup>{references}</ItemGroup></Project>"#
+            ),
+        )
+        .unwrap();
+        let path = dir.path().join("t.csproj");
+        fs::write(&path, REAL_SDK_PROJECT).unwrap();
+        let timed_out = load_msbuild_project_limited(&huge, None, Some(Duration::ZERO));
+
+        // Act
+        let actual = load_msbuild_project_limited(&path, None, Some(Duration::from_secs(1)));
+
+        // Assert
+        assert!(timed_out.is_err());
+        assert!(actual.unwrap().is_some());
+    }
+
+    #[test]
+    fn is_test_project_by_type_id() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let project = create_project("{3AC096D0-A1C2-E12C-1390-A8335801FDAB}", "t.csproj");
+
+        // Act
+        let actual = is_test_project(dir.path(), &project);
+
+        // Assert
+        assert!(actual);
+    }
+
+    #[test]
+    fn is_test_project_by_package_references() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("t.csproj"), REAL_SDK_PROJECT).unwrap();
+        let project = create_project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}", "t.csproj");
+
+        // Act
+        let actual = is_test_project(dir.path(), &project);
+
+        // Assert
+        assert!(actual);
+    }
+
+    #[test]
+    fn is_test_project_file_not_exist() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let project = create_project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}", "t.csproj");
+
+        // Act
+        let actual = is_test_project(dir.path(), &project);
+
+        // Assert
+        assert!(!actual);
+    }
+
+    #[test]
+    fn references_outside_solution_found() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::write(
+            dir.path().join("a").join("a.csproj"),
+            PROJECT_WITH_REFERENCES,
+        )
+        .unwrap();
+        let sln = dir.path().join("s.sln");
+        let sln = sln.to_str().unwrap();
+        let mut solution = solp::parse_str(SOLUTION_WITHOUT_REFERENCED_PROJECT).unwrap();
+        solution.path = sln;
+
+        // Act
+        let actual = references_outside_solution(&solution);
+
+        // Assert
+        assert_eq!(1, actual.len());
+        let outside = actual.get(&dir.path().join("a").join("a.csproj")).unwrap();
+        assert_eq!(1, outside.len());
+        assert!(outside.contains(&dir.path().join("c").join("c.csproj")));
+    }
+
+    #[test]
+    fn project_reference_cycles_found() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::create_dir(dir.path().join("b")).unwrap();
+        fs::write(dir.path().join("a").join("a.csproj"), PROJECT_REFERENCING_B).unwrap();
+        fs::write(dir.path().join("b").join("b.csproj"), PROJECT_REFERENCING_A).unwrap();
+        let sln = dir.path().join("s.sln");
+        let sln = sln.to_str().unwrap();
+        let mut solution = solp::parse_str(SOLUTION_WITHOUT_REFERENCED_PROJECT).unwrap();
+        solution.path = sln;
+
+        // Act
+        let actual = project_reference_cycles(&solution);
+
+        // Assert
+        assert_eq!(
+            vec![vec![
+                dir.path().join("a").join("a.csproj"),
+                dir.path().join("b").join("b.csproj")
+            ]],
+            actual
+        );
+    }
+
+    #[test]
+    fn project_reference_cycles_not_found() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::create_dir(dir.path().join("b")).unwrap();
+        fs::write(dir.path().join("a").join("a.csproj"), PROJECT_REFERENCING_B).unwrap();
+        fs::write(dir.path().join("b").join("b.csproj"), REAL_SDK_PROJECT).unwrap();
+        let sln = dir.path().join("s.sln");
+        let sln = sln.to_str().unwrap();
+        let mut solution = solp::parse_str(SOLUTION_WITHOUT_REFERENCED_PROJECT).unwrap();
+        solution.path = sln;
+
+        // Act
+        let actual = project_reference_cycles(&solution);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn missing_imports_found() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::create_dir(dir.path().join("build")).unwrap();
+        fs::write(dir.path().join("a").join("a.csproj"), PROJECT_WITH_IMPORTS).unwrap();
+        fs::write(dir.path().join("build").join("common.props"), "<Project />").unwrap();
+        let sln = dir.path().join("s.sln");
+        let sln = sln.to_str().unwrap();
+        let mut solution = solp::parse_str(SINGLE_SDK_PROJECT_SOLUTION).unwrap();
+        solution.path = sln;
+
+        // Act
+        let actual = missing_imports(&solution);
+
+        // Assert
+        assert_eq!(1, actual.len());
+        let missing = actual.get(&dir.path().join("a").join("a.csproj")).unwrap();
+        assert_eq!(
+            &BTreeSet::from([dir.path().join("build").join("missing.props")]),
+            missing
+        );
+    }
+
+    #[test]
+    fn missing_imports_not_found() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::write(dir.path().join("a").join("a.csproj"), REAL_SDK_PROJECT).unwrap();
+        let sln = dir.path().join("s.sln");
+        let sln = sln.to_str().unwrap();
+        let mut solution = solp::parse_str(SINGLE_SDK_PROJECT_SOLUTION).unwrap();
+        solution.path = sln;
+
+        // Act
+        let actual = missing_imports(&solution);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn shared_output_paths_found() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::create_dir(dir.path().join("b")).unwrap();
+        fs::write(
+            dir.path().join("a").join("a.csproj"),
+            PROJECT_WITH_SHARED_DEBUG_OUTPUT,
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("b").join("b.csproj"),
+            PROJECT_WITH_SHARED_DEBUG_OUTPUT,
+        )
+        .unwrap();
+        let sln = dir.path().join("s.sln");
+        let sln = sln.to_str().unwrap();
+        let mut solution = solp::parse_str(SOLUTION_WITH_DEBUG_AND_RELEASE_PROJECTS).unwrap();
+        solution.path = sln;
+
+        // Act
+        let actual = shared_output_paths(&solution);
+
+        // Assert
+        assert_eq!(1, actual.len());
+        let key = (
+            "Debug|Any CPU".to_owned(),
+            dir.path().join("out").join("Debug"),
+        );
+        assert_eq!(Some(&BTreeSet::from(["a", "b"])), actual.get(&key));
+    }
+
+    #[test]
+    fn shared_output_paths_not_found() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::create_dir(dir.path().join("b")).unwrap();
+        fs::write(
+            dir.path().join("a").join("a.csproj"),
+            PROJECT_WITH_OWN_OUTPUT,
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("b").join("b.csproj"),
+            PROJECT_WITH_OWN_OUTPUT,
+        )
+        .unwrap();
+        let sln = dir.path().join("s.sln");
+        let sln = sln.to_str().unwrap();
+        let mut solution = solp::parse_str(SOLUTION_WITH_DEBUG_AND_RELEASE_PROJECTS).unwrap();
+        solution.path = sln;
+
+        // Act
+        let actual = shared_output_paths(&solution);
+
+        // Assert
+        assert!(actual.is_empty());
+    }
+
+    #[test]
+    fn transitive_references_outside_solution_chain() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::create_dir(dir.path().join("b")).unwrap();
+        fs::create_dir(dir.path().join("c")).unwrap();
+        fs::write(dir.path().join("a").join("a.csproj"), PROJECT_REFERENCING_B).unwrap();
+        fs::write(dir.path().join("b").join("b.csproj"), PROJECT_REFERENCING_C).unwrap();
+        fs::write(dir.path().join("c").join("c.csproj"), REAL_SDK_PROJECT).unwrap();
+        let sln = dir.path().join("s.sln");
+        let sln = sln.to_str().unwrap();
+        let mut solution = solp::parse_str(SINGLE_SDK_PROJECT_SOLUTION).unwrap();
+        solution.path = sln;
+
+        // Act
+        let actual = transitive_references_outside_solution(&solution);
+
+        // Assert
+        assert_eq!(
+            BTreeSet::from([
+                dir.path().join("b").join("b.csproj"),
+                dir.path().join("c").join("c.csproj")
+            ]),
+            actual
+        );
+    }
+
+    #[test]
+    fn transitive_references_outside_solution_cycle_and_missing_file() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::create_dir(dir.path().join("b")).unwrap();
+        fs::create_dir(dir.path().join("c")).unwrap();
+        fs::write(dir.path().join("a").join("a.csproj"), PROJECT_REFERENCING_B).unwrap();
+        fs::write(dir.path().join("b").join("b.csproj"), PROJECT_REFERENCING_C).unwrap();
+        fs::write(
+            dir.path().join("c").join("c.csproj"),
+            PROJECT_REFERENCING_A_AND_MISSING,
+        )
+        .unwrap();
+        let sln = dir.path().join("s.sln");
+        let sln = sln.to_str().unwrap();
+        let mut solution = solp::parse_str(SINGLE_SDK_PROJECT_SOLUTION).unwrap();
+        solution.path = sln;
+
+        // Act
+        let actual = transitive_references_outside_solution(&solution);
+
+        // Assert
+        assert_eq!(
+            BTreeSet::from([
+                dir.path().join("b").join("b.csproj"),
+                dir.path().join("c").join("c.csproj")
+            ]),
+            actual
+        );
+    }
+
+    #[test]
+    fn normalize_path_tests() {
+        // Arrange
+        let path = Path::new("/base/a/./../b/b.csproj");
+
+        // Act
+        let actual = normalize_path(path);
+
+        // Assert
+        assert_eq!(PathBuf::from("/base/b/b.csproj"), actual);
+    }
+
+    #[test]
+    fn load_build_props_walking_up() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("src").join("app");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(dir.path().join(GLOBAL_JSON), GLOBAL_JSON_CONTENT).unwrap();
+        fs::write(
+            dir.path().join("src").join(DIRECTORY_BUILD_PROPS),
+            REAL_SDK_PROJECT,
+        )
+        .unwrap();
+
+        // Act
+        let actual = load_build_props(&nested);
+
+        // Assert
+        assert_eq!(Some("8.0.100".to_owned()), actual.sdk_version);
+        assert_eq!(Some("net6.0".to_owned()), actual.target_framework);
+    }
+
+    #[test]
+    fn find_up_absent() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+
+        // Act
+        let actual = find_up(dir.path(), "definitely.not.exist.props");
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    fn create_project<'a>(type_id: &'a str, path: &'a str) -> api::Project<'a> {
+        api::Project {
+            type_id,
+            type_description: "",
+            id: "{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}",
+            name: "t",
+            path_or_uri: path,
+            configurations: None,
+            items: None,
+            depends_from: None,
+            line: None,
+            column: None,
+        }
+    }
+
+    const REAL_SDK_PROJECT: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
+    <PropertyGroup>
+      <TargetFramework>net6.0</TargetFramework>
+      <ProjectTypeGuids>{3AC096D0-A1C2-E12C-1390-A8335801FDAB};{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}</ProjectTypeGuids>
+      <TestProjectType>UnitTest</TestProjectType>
+      <AssemblyTitle>_tst.net</AssemblyTitle>
+      <Company>Egoroff</Company>
+      <Product>_tst.net</Product>
+      <Copyright>Copyright © 2009-2022 Alexander Egorov</Copyright>
+      <OutputPath>bin\$(Configuration)\</OutputPath>
+      <AppendTargetFrameworkToOutputPath>false</AppendTargetFrameworkToOutputPath>
+    </PropertyGroup>
+    <PropertyGroup Condition=" '$(Configuration)|$(Platform)' == 'Debug|AnyCPU' ">
+      <DebugType>portable</DebugType>
+    </PropertyGroup>
+    <PropertyGroup Condition=" '$(Configuration)|$(Platform)' == 'Release|AnyCPU' ">
+      <DebugType>portable</DebugType>
+    </PropertyGroup>
+    <ItemGroup>
+      <PackageReference Include="FluentAssertions" Version="6.7.0" />
+      <PackageReference Include="System.Runtime.CompilerServices.Unsafe" Version="6.0.0" />
+      <PackageReference Include="System.Threading.Tasks.Extensions" Version="4.5.4" />
+      <PackageReference Include="System.ValueTuple" Version="4.5.0" />
+      <PackageReference Include="xunit" Version="2.4.1" />
+      <PackageReference Include="xunit.abstractions" Version="2.0.3" />
+      <PackageReference Include="xunit.analyzers" Version="0.10.0" />
+      <PackageReference Include="xunit.assert" Version="2.4.1" />
+      <PackageReference Include="xunit.core" Version="2.4.1" />
+      <PackageReference Include="xunit.extensibility.core" Version="2.4.1" />
+      <PackageReference Include="xunit.extensibility.execution" Version="2.4.1" />
+      <PackageReference Include="Microsoft.NET.Test.Sdk" Version="17.2.0" />
+      <PackageReference Include="xunit.runner.visualstudio" Version="2.4.5">
+        <PrivateAssets>all</PrivateAssets>
+        <IncludeAssets>runtime; build; native; contentfiles; analyzers; buildtransitive</IncludeAssets>
+      </PackageReference>
+    </ItemGroup>
+  </Project>"#;
+
+    const PROJECT_WITH_REFERENCES: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
+    <PropertyGroup>
+      <TargetFramework>net6.0</TargetFramework>
+    </PropertyGroup>
+    <ItemGroup>
+      <ProjectReference Include="..\b\b.csproj" />
+      <ProjectReference Include="..\c\c.csproj" />
+    </ItemGroup>
+  </Project>"#;
+
+    const SOLUTION_WITHOUT_REFERENCED_PROJECT: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const PROJECT_REFERENCING_C: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
+    <ItemGroup>
+      <ProjectReference Include="..\c\c.csproj" />
+    </ItemGroup>
+  </Project>"#;
+
+    const PROJECT_REFERENCING_A_AND_MISSING: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
+    <ItemGroup>
+      <ProjectReference Include="..\a\a.csproj" />
+      <ProjectReference Include="..\d\d.csproj" />
+    </ItemGroup>
+  </Project>"#;
+
+    const PROJECT_REFERENCING_B: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
+    <ItemGroup>
+      <ProjectReference Include="..\b\b.csproj" />
+    </ItemGroup>
+  </Project>"#;
+
+    const PROJECT_WITH_IMPORTS: &str = r#"<Project ToolsVersion="15.0">
+    <Import Project="..\build\common.props" />
+    <Import Project="..\build\missing.props" />
+    <Import Project="$(MSBuildToolsPath)\Microsoft.CSharp.targets" />
+    <Import Project="Sdk.props" Sdk="Microsoft.NET.Sdk" />
+    <ImportGroup Label="PropertySheets">
+      <Import Project="..\build\optional.props" Condition="exists('..\build\optional.props')" />
+    </ImportGroup>
+  </Project>"#;
+
+    const PROJECT_WITH_SHARED_DEBUG_OUTPUT: &str = r#"<Project ToolsVersion="15.0">
+    <PropertyGroup Condition=" '$(Configuration)|$(Platform)' == 'Debug|AnyCPU' ">
+      <OutputPath>..\out\$(Configuration)\</OutputPath>
+    </PropertyGroup>
+    <PropertyGroup Condition=" '$(Configuration)|$(Platform)' == 'Release|AnyCPU' ">
+      <OutputPath>bin\$(Configuration)\</OutputPath>
+    </PropertyGroup>
+  </Project>"#;
+
+    const PROJECT_WITH_OWN_OUTPUT: &str = r#"<Project ToolsVersion="15.0">
+    <PropertyGroup>
+      <OutputPath>bin\$(Configuration)\</OutputPath>
+    </PropertyGroup>
+  </Project>"#;
+
+    const SOLUTION_WITH_DEBUG_AND_RELEASE_PROJECTS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.Build.0 = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const GLOBAL_JSON_CONTENT: &str = r#"{
+  "sdk": {
+    "version": "8.0.100",
+    "rollForward": "latestFeature"
+  }
+}"#;
+}
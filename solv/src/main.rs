@@ -1,14 +1,32 @@
-use clap::{command, Arg, ArgAction, ArgMatches, Command};
+use clap::{command, Arg, ArgAction, ArgGroup, ArgMatches, Command};
 use clap_complete::{generate, Shell};
-use color_eyre::eyre::{Context, Result};
-use solp::Consume;
+use color_eyre::eyre::{bail, Context, Result};
+use regex::Regex;
+use solp::{Consume, ParseOptions, SolpWalker};
+use solv::analyze::Analyze;
+use solv::baseline::Baseline;
+use solv::buildorder::BuildOrder;
+use solv::configs::Configs;
+use solv::duplicates::Duplicates;
+use solv::error::JsonlErrors;
+#[cfg(feature = "export")]
+use solv::export::Sqlite;
+use solv::forbidden::Forbidden;
 use solv::info::Info;
 use solv::json::Json;
+use solv::matrix::Matrix;
 use solv::nuget::Nuget;
-use solv::validate::Validate;
+use solv::stats::Stats;
+use solv::tee::Tee;
+use solv::timing::TimeEach;
+use solv::toml::Toml;
+use solv::validate::{self, Validate};
+use solv::watch::{self, SolutionIndex};
+use solv::StatsJson;
 use std::fmt::Display;
 use std::fs;
-use std::io::{BufReader, Read};
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
 use std::{
     io,
     time::{Duration, Instant},
@@ -22,8 +40,23 @@ const EXT_DESCR: &str = "Visual Studio solution extension";
 const RECURSIVELY_FLAG: &str = "recursively";
 const RECURSIVELY_DESCR: &str = "Scan directory recursively. False by default";
 const BENCHMARK_DESCR: &str = "Show scanning time in case of directory scanning. False by default";
-const PATH_DESCR: &str = "Sets solution path or directory to analyze";
+const PATH_DESCR: &str = "Sets solution path, directory or zip archive to analyze";
 const DEFAULT_SOLUTION_EXT: &str = "sln";
+const NAME_FILTER_ARG: &str = "name-filter";
+const BASELINE_ARG: &str = "baseline";
+const WRITE_BASELINE_ARG: &str = "write-baseline";
+const COMPARE_BASELINE_ARG: &str = "compare-baseline";
+const MIN_PROJECTS_ARG: &str = "min-projects";
+const STDIN_PATHS_FLAG: &str = "stdin-paths";
+const STATS_JSON_FLAG: &str = "stats-json";
+const BASE_DIR_ARG: &str = "base";
+const JSON_OUT_ARG: &str = "json-out";
+const JSONL_ERRORS_FLAG: &str = "jsonl-errors";
+const TIME_EACH_FLAG: &str = "time-each";
+const ENCODING_ARG: &str = "encoding";
+const HEAD_ARG: &str = "head";
+const FAIL_ON_EMPTY_FLAG: &str = "fail-on-empty";
+const TAIL_ARG: &str = "tail";
 
 fn main() -> Result<()> {
     color_eyre::install()?;
@@ -35,6 +68,15 @@ fn main() -> Result<()> {
         Some(("info", cmd)) => info(cmd),
         Some(("nuget", cmd)) => nuget(cmd),
         Some(("json", cmd)) => json(cmd),
+        Some(("toml", cmd)) => toml(cmd),
+        Some(("buildorder", cmd)) => buildorder(cmd),
+        Some(("stats", cmd)) => stats(cmd),
+        Some(("analyze", cmd)) => analyze(cmd),
+        Some(("duplicates", cmd)) => duplicates(cmd),
+        Some(("matrix", cmd)) => matrix(cmd),
+        Some(("configs", cmd)) => configs(cmd),
+        #[cfg(feature = "export")]
+        Some(("export", cmd)) => export(cmd),
         Some(("completion", cmd)) => {
             print_completions(cmd);
             Ok(())
@@ -44,24 +86,173 @@ fn main() -> Result<()> {
 }
 
 fn validate(cmd: &ArgMatches) -> Result<()> {
+    if let Some(rule) = cmd.get_one::<String>("explain") {
+        let Some(explanation) = validate::explain(rule) else {
+            let rules = validate::explanations()
+                .into_iter()
+                .map(|(r, _)| r)
+                .collect::<Vec<_>>()
+                .join(", ");
+            bail!("Unknown rule: {rule}. Known rules are: {rules}");
+        };
+        println!("{rule}\n\n{explanation}");
+        return Ok(());
+    }
+
+    let write_baseline = cmd.get_one::<String>(WRITE_BASELINE_ARG);
+    let watch = cmd.get_flag("watch-dir");
+    let mut consumer = validate_consumer(cmd)?;
+    scan_path(cmd, &mut consumer, &validate_options(cmd))?;
+
+    if let (Some(path), Some(recorded)) = (write_baseline, consumer.recorded_baseline()) {
+        recorded.save(path)?;
+    }
+    if let (Some(path), Some(recorded)) = (
+        cmd.get_one::<String>(COMPARE_BASELINE_ARG),
+        consumer.recorded_baseline(),
+    ) {
+        let comparison = Baseline::load(path)?.compare(recorded);
+        print!("{comparison}");
+        // Watching keeps going so that new problems can be fixed without restart
+        if comparison.has_new_problems() && !watch {
+            std::process::exit(exitcode::SOFTWARE);
+        }
+    }
+    if watch {
+        return watch_validate(cmd);
+    }
+    if consumer.truncated() {
+        std::process::exit(exitcode::SOFTWARE);
+    }
+    if consumer.parse_errors_found() && cmd.get_flag("fail-on-parse-error") {
+        std::process::exit(exitcode::SOFTWARE);
+    }
+    Ok(())
+}
+
+fn validate_consumer(cmd: &ArgMatches) -> Result<Validate> {
     let only_problems = cmd.get_flag("problems");
 
-    let mut consumer = Validate::new(only_problems);
-    scan_path(cmd, &mut consumer)
+    let baseline_path = cmd
+        .get_one::<String>(BASELINE_ARG)
+        .or(cmd.get_one::<String>(COMPARE_BASELINE_ARG));
+    let baseline = match baseline_path {
+        Some(path) => Some(Baseline::load(path)?),
+        None => None,
+    };
+    let record = cmd.contains_id(WRITE_BASELINE_ARG) || cmd.contains_id(COMPARE_BASELINE_ARG);
+
+    Ok(Validate::new(only_problems)
+        .with_baseline(baseline)
+        .with_baseline_recording(record)
+        .with_legacy_platforms_check(cmd.get_flag("check-legacy-platforms"))
+        .with_format_product_check(cmd.get_flag("check-format-product"))
+        .with_source_control_check(cmd.get_flag("check-source-control"))
+        .with_name_case_check(cmd.get_flag("check-name-case"))
+        .with_indentation_check(cmd.get_flag("check-indentation"))
+        .with_imports_check(cmd.get_flag("check-imports"))
+        .with_strict_guids(cmd.get_flag("strict-guids"))
+        .with_follow_project_references(cmd.get_flag("follow-project-references"))
+        .with_outside_references_check(cmd.get_flag("check-outside-references"))
+        .with_reference_cycles_check(cmd.get_flag("check-reference-cycles"))
+        .with_output_paths_check(cmd.get_flag("check-output-paths"))
+        .with_solution_items_check(cmd.get_flag("check-solution-items"))
+        .with_unknown_platforms_check(cmd.get_flag("check-unknown-platforms"))
+        .with_oneline(cmd.get_flag("oneline"))
+        .with_score(cmd.get_flag("score"))
+        .with_max_problems(cmd.get_one::<usize>("max-problems").copied())
+        .with_min_projects(cmd.get_one::<usize>(MIN_PROJECTS_ARG).copied())
+        .with_ignored_projects(
+            &cmd.get_many::<String>("ignore-project")
+                .unwrap_or_default()
+                .cloned()
+                .collect::<Vec<String>>(),
+        )
+        .with_base(base_dir(cmd)))
+}
+
+/// Parse options that detect only what enabled validators need
+fn validate_options(cmd: &ArgMatches) -> ParseOptions {
+    ParseOptions {
+        indentation: cmd.get_flag("check-indentation"),
+        ..Default::default()
+    }
+}
+
+/// Re-validates solutions which directory subtree changed until interrupted
+fn watch_validate(cmd: &ArgMatches) -> Result<()> {
+    let path = cmd.get_one::<String>(PATH).map_or("", String::as_str);
+    if !fs::metadata(path).is_ok_and(|m| m.is_dir()) {
+        bail!("Only directories can be watched: {path}");
+    }
+    let extension = cmd.get_one::<String>("ext").map_or("", String::as_str);
+    let index = SolutionIndex::scan(path, extension, cmd.get_flag(RECURSIVELY_FLAG));
+    let options = with_encoding(cmd, &validate_options(cmd));
+    println!(" Watching {path} for changes. Press Ctrl+C to stop");
+    watch::watch_dir(path, index, |affected| {
+        let mut consumer = match validate_consumer(cmd) {
+            Ok(consumer) => consumer,
+            Err(e) => {
+                eprintln!("{e:?}");
+                return;
+            }
+        };
+        for solution in affected {
+            let path = solution.to_string_lossy();
+            if let Err(e) = solp::parse_file_with_options(&path, &mut consumer, &options) {
+                eprintln!("{e:?}");
+            }
+        }
+        print!("{consumer}");
+    })
 }
 
 fn info(cmd: &ArgMatches) -> Result<()> {
-    let mut consumer = Info::new();
-    scan_path_or_stdin(cmd, &mut consumer)
+    let name_filter = cmd.get_one::<Regex>(NAME_FILTER_ARG).cloned();
+    let mut consumer = Info::new()
+        .with_name_filter(name_filter)
+        .with_tests(cmd.get_flag("tests"))
+        .with_build_props(cmd.get_flag("build-props"))
+        .with_config_counts(cmd.get_flag("config-counts"))
+        .with_assets(cmd.get_flag("assets"))
+        .with_bom(cmd.get_flag("bom"))
+        .with_folders(cmd.get_flag("include-folders"))
+        .with_count_by_type(cmd.get_flag("count-by-type"))
+        .with_only(cmd.get_one::<String>("only").cloned())
+        .with_min_projects(cmd.get_one::<usize>(MIN_PROJECTS_ARG).copied())
+        .with_base(base_dir(cmd));
+    if cmd.get_flag(STDIN_PATHS_FLAG) {
+        scan_paths_stream(
+            cmd,
+            io::stdin().lock(),
+            &mut consumer,
+            &ParseOptions::default(),
+        )
+    } else {
+        scan_path_or_stdin(cmd, &mut consumer, &ParseOptions::default())
+    }
 }
 
 fn nuget(cmd: &ArgMatches) -> Result<()> {
     let only_mismatched = cmd.get_flag("mismatch");
     let fail_if_mismatched = cmd.get_flag("fail");
+    let forbidden = match cmd.get_one::<String>("forbid") {
+        Some(path) => Some(Forbidden::load(path)?),
+        None => None,
+    };
 
-    let mut consumer = Nuget::new(only_mismatched);
-    let result = scan_path(cmd, &mut consumer);
-    if consumer.mismatches_found && fail_if_mismatched {
+    let mut consumer = Nuget::new(only_mismatched)
+        .with_dedup_packages(cmd.get_flag("dedup-packages"))
+        .with_max_project_size(cmd.get_one::<u64>("max-project-size").copied())
+        .with_project_timeout(
+            cmd.get_one::<u64>("project-timeout")
+                .copied()
+                .map(Duration::from_millis),
+        )
+        .with_forbidden(forbidden)
+        .with_base(base_dir(cmd));
+    let result = scan_path(cmd, &mut consumer, &ParseOptions::default());
+    if (consumer.mismatches_found || consumer.forbidden_found()) && fail_if_mismatched {
         std::process::exit(exitcode::SOFTWARE);
     }
     result
@@ -69,37 +260,102 @@ fn nuget(cmd: &ArgMatches) -> Result<()> {
 
 fn json(cmd: &ArgMatches) -> Result<()> {
     let pretty = cmd.get_flag("pretty");
-    let mut consumer = Json::new(pretty);
-    scan_path_or_stdin(cmd, &mut consumer)
+    let options = ParseOptions {
+        positions: cmd.get_flag("positions"),
+        ..Default::default()
+    };
+    let mut consumer = Json::new(pretty)
+        .with_sdk_resolving(cmd.get_flag("resolve-sdk"))
+        .with_fields(cmd.get_one::<Vec<&'static str>>("fields").cloned());
+    scan_path_or_stdin(cmd, &mut consumer, &options)
+}
+
+fn toml(cmd: &ArgMatches) -> Result<()> {
+    let mut consumer = Toml::new();
+    scan_path_or_stdin(cmd, &mut consumer, &ParseOptions::default())
+}
+
+fn buildorder(cmd: &ArgMatches) -> Result<()> {
+    let mut consumer = BuildOrder::new()
+        .with_root(cmd.get_one::<String>("root").cloned())
+        .with_depth(cmd.get_one::<usize>("depth").copied())
+        .with_base(base_dir(cmd));
+    let result = scan_path_or_stdin(cmd, &mut consumer, &ParseOptions::default());
+    if consumer.cycles_found || consumer.unknown_root {
+        std::process::exit(exitcode::SOFTWARE);
+    }
+    result
+}
+
+fn stats(cmd: &ArgMatches) -> Result<()> {
+    let mut consumer = Stats::new();
+    scan_path(cmd, &mut consumer, &ParseOptions::default())
+}
+
+fn analyze(cmd: &ArgMatches) -> Result<()> {
+    let mut consumer = Analyze::new().with_base(base_dir(cmd));
+    scan_path(cmd, &mut consumer, &ParseOptions::default())?;
+    if let Some(path) = cmd.get_one::<PathBuf>("markdown") {
+        fs::write(path, consumer.markdown())
+            .wrap_err_with(|| format!("Failed to write markdown into: {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn duplicates(cmd: &ArgMatches) -> Result<()> {
+    let mut consumer = Duplicates::new().with_base(base_dir(cmd));
+    scan_path(cmd, &mut consumer, &ParseOptions::default())
+}
+
+fn matrix(cmd: &ArgMatches) -> Result<()> {
+    // Dependencies matrix is the only kind so far and the kind group is required
+    let mut consumer = Matrix::new();
+    scan_path_or_stdin(cmd, &mut consumer, &ParseOptions::default())
 }
 
-fn scan_path_or_stdin<C: Consume + Display>(cmd: &ArgMatches, consumer: &mut C) -> Result<()> {
+/// Prints configurations sprawl grid of the solutions found i.e. a row per solution with
+/// configurations and platforms counts. Grid is the only kind so far and `--grid` is required
+fn configs(cmd: &ArgMatches) -> Result<()> {
+    let mut consumer = Configs::new().with_base(base_dir(cmd));
+    scan_path(cmd, &mut consumer, &ParseOptions::default())
+}
+
+#[cfg(feature = "export")]
+fn export(cmd: &ArgMatches) -> Result<()> {
+    // SQLite is the only target so far and the argument is required
+    let path = cmd.get_one::<PathBuf>("sqlite").unwrap();
+    let mut consumer = Sqlite::open(path)?.with_base(base_dir(cmd));
+    scan_path(cmd, &mut consumer, &ParseOptions::default())
+}
+
+fn base_dir(cmd: &ArgMatches) -> Option<PathBuf> {
+    cmd.get_one::<PathBuf>(BASE_DIR_ARG).cloned()
+}
+
+fn scan_path_or_stdin<C: Consume + Display + StatsJson>(
+    cmd: &ArgMatches,
+    consumer: &mut C,
+    options: &ParseOptions,
+) -> Result<()> {
     if cmd.get_one::<String>(PATH).is_some() {
-        scan_path(cmd, consumer)
+        scan_path(cmd, consumer, options)
     } else {
-        scan_stream(io::stdin(), consumer)
+        scan_stream(cmd, io::stdin(), consumer, options)
     }
 }
 
 #[allow(clippy::cast_possible_truncation)]
-fn scan_path<C: Consume + Display>(cmd: &ArgMatches, consumer: &mut C) -> Result<()> {
+fn scan_path<C: Consume + Display + StatsJson>(
+    cmd: &ArgMatches,
+    consumer: &mut C,
+    options: &ParseOptions,
+) -> Result<()> {
     let now = Instant::now();
+    let options = &with_encoding(cmd, options);
     if let Some(path) = cmd.get_one::<String>(PATH) {
-        let metadata =
-            fs::metadata(path).wrap_err_with(|| format!("Failed to use path: {path}"))?;
-        if metadata.is_dir() {
-            let empty = String::default();
-            let extension = cmd.get_one::<String>("ext").unwrap_or(&empty);
-            let recursively = cmd.get_flag(RECURSIVELY_FLAG);
-            if recursively {
-                solp::parse_dir_tree(path, extension, consumer);
-            } else {
-                solp::parse_dir(path, extension, consumer);
-            }
-        } else {
-            solp::parse_file(path, consumer)?;
-        }
+        let scanned = scan_solutions(cmd, path, consumer, options)?;
         print!("{consumer}");
+        print_stats(cmd, consumer);
 
         if cmd.get_flag("time") {
             let duration = now.elapsed().as_millis();
@@ -110,23 +366,197 @@ fn scan_path<C: Consume + Display>(cmd: &ArgMatches, consumer: &mut C) -> Result
                 humantime::format_duration(duration)
             );
         }
+        let code = scan_exit_code(cmd, scanned);
+        if code != exitcode::OK {
+            eprintln!("No solutions parsed in: {path}");
+            std::process::exit(code);
+        }
     }
     Ok(())
 }
 
-fn scan_stream<C: Consume + Display, R: Read>(read: R, consumer: &mut C) -> Result<()> {
-    let mut contents = String::new();
+/// Exit code of the scan that parsed the number of solutions specified.
+/// Scan without parsed solutions fails only if `--fail-on-empty` is set
+fn scan_exit_code(cmd: &ArgMatches, scanned: usize) -> exitcode::ExitCode {
+    if scanned == 0 && cmd.get_flag(FAIL_ON_EMPTY_FLAG) {
+        exitcode::SOFTWARE
+    } else {
+        exitcode::OK
+    }
+}
+
+/// Scans solution file, directory or zip archive specified.
+/// Returns the number of successfully parsed solutions.
+/// Single file that cannot be parsed fails the scan
+fn scan_solutions<C: Consume>(
+    cmd: &ArgMatches,
+    path: &str,
+    consumer: &mut C,
+    options: &ParseOptions,
+) -> Result<usize> {
+    let metadata = fs::metadata(path).wrap_err_with(|| format!("Failed to use path: {path}"))?;
+    let empty = String::default();
+    let extension = cmd.get_one::<String>("ext").unwrap_or(&empty);
+    let mut scanned = 0;
+    if metadata.is_dir() {
+        let recursively = cmd.get_flag(RECURSIVELY_FLAG);
+        scan_into(cmd, consumer, |c| {
+            let mut walker = SolpWalker::new(c, extension).with_options(options.clone());
+            if let Some(head) = cmd.get_one::<usize>(HEAD_ARG) {
+                walker = walker.with_head(*head);
+            }
+            if let Some(tail) = cmd.get_one::<usize>(TAIL_ARG) {
+                walker = walker.with_tail(*tail);
+            }
+            scanned = walker.walk_and_parse(path, recursively);
+            Ok(())
+        })?;
+    } else if is_zip(path) {
+        let archive =
+            fs::File::open(path).wrap_err_with(|| format!("Failed to open archive: {path}"))?;
+        scan_into(cmd, consumer, |c| {
+            scanned = solv::parse_zip(BufReader::new(archive), extension, c, options)?;
+            Ok(())
+        })?;
+    } else {
+        scan_into(cmd, consumer, |c| {
+            solp::parse_file_with_options(path, c, options)?;
+            scanned = 1;
+            Ok(())
+        })?;
+    }
+    Ok(scanned)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn scan_paths_stream<C: Consume + Display + StatsJson, R: BufRead>(
+    cmd: &ArgMatches,
+    read: R,
+    consumer: &mut C,
+    options: &ParseOptions,
+) -> Result<()> {
+    let now = Instant::now();
+    let options = &with_encoding(cmd, options);
+    scan_into(cmd, consumer, |c| {
+        solv::parse_paths(read, c, options);
+        Ok(())
+    })?;
+    print!("{consumer}");
+    print_stats(cmd, consumer);
+
+    if cmd.get_flag("time") {
+        let duration = now.elapsed().as_millis();
+        let duration = Duration::from_millis(duration as u64);
+        println!(
+            " {:>2} {}",
+            "elapsed:",
+            humantime::format_duration(duration)
+        );
+    }
+    Ok(())
+}
+
+fn scan_stream<C: Consume + Display + StatsJson, R: Read>(
+    cmd: &ArgMatches,
+    read: R,
+    consumer: &mut C,
+    options: &ParseOptions,
+) -> Result<()> {
+    let options = &with_encoding(cmd, options);
     let mut br = BufReader::new(read);
-    br.read_to_string(&mut contents)
-        .wrap_err_with(|| "Failed to read content from stream")?;
-    let solution = solp::parse_str(&contents).wrap_err_with(|| "Failed to parse solution")?;
-    consumer.ok(&solution);
+    let contents = match options.encoding.as_deref() {
+        Some(label) => {
+            let mut bytes = vec![];
+            br.read_to_end(&mut bytes)
+                .wrap_err_with(|| "Failed to read content from stream")?;
+            solp::decode(&bytes, Some(label))?.into_owned()
+        }
+        None => {
+            let mut contents = String::new();
+            br.read_to_string(&mut contents)
+                .wrap_err_with(|| "Failed to read content from stream")?;
+            contents
+        }
+    };
+    let solution = solp::parse_str_with_options(&contents, options)
+        .wrap_err_with(|| "Failed to parse solution")?;
+    scan_into(cmd, consumer, |c| {
+        c.ok(&solution);
+        Ok(())
+    })?;
 
     print!("{consumer}");
+    print_stats(cmd, consumer);
 
     Ok(())
 }
 
+/// Feeds consumer specified and, if json output file requested,
+/// json serializer at the same time so that the scan isn't repeated.
+/// Parse failures are also written into stderr as json lines if requested
+fn scan_into<C: Consume>(
+    cmd: &ArgMatches,
+    consumer: &mut C,
+    scan: impl FnOnce(&mut dyn Consume) -> Result<()>,
+) -> Result<()> {
+    if cmd.get_flag(TIME_EACH_FLAG) {
+        let mut timed = TimeEach::new(consumer, io::stdout());
+        scan_into_errors(cmd, &mut timed, scan)
+    } else {
+        scan_into_errors(cmd, consumer, scan)
+    }
+}
+
+fn scan_into_errors(
+    cmd: &ArgMatches,
+    consumer: &mut dyn Consume,
+    scan: impl FnOnce(&mut dyn Consume) -> Result<()>,
+) -> Result<()> {
+    if cmd.get_flag(JSONL_ERRORS_FLAG) {
+        let mut errors = JsonlErrors::new(consumer, io::stderr());
+        scan_into_json(cmd, &mut errors, scan)
+    } else {
+        scan_into_json(cmd, consumer, scan)
+    }
+}
+
+fn scan_into_json(
+    cmd: &ArgMatches,
+    consumer: &mut dyn Consume,
+    scan: impl FnOnce(&mut dyn Consume) -> Result<()>,
+) -> Result<()> {
+    // Not every command accepts json output i.e. duplicates doesn't
+    match cmd.try_get_one::<PathBuf>(JSON_OUT_ARG).ok().flatten() {
+        Some(path) => {
+            let mut json = Json::new(false);
+            scan(&mut Tee::new(consumer, &mut json))?;
+            fs::write(path, json.to_string())
+                .wrap_err_with(|| format!("Failed to write json into: {}", path.display()))
+        }
+        None => scan(consumer),
+    }
+}
+
+fn is_zip(path: &str) -> bool {
+    std::path::Path::new(path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("zip"))
+}
+
+/// Applies encoding specified on the command line to the parse options
+fn with_encoding(cmd: &ArgMatches, options: &ParseOptions) -> ParseOptions {
+    ParseOptions {
+        encoding: cmd.get_one::<String>(ENCODING_ARG).cloned(),
+        ..options.clone()
+    }
+}
+
+fn print_stats<C: StatsJson>(cmd: &ArgMatches, consumer: &C) {
+    if cmd.get_flag(STATS_JSON_FLAG) {
+        println!("{}", consumer.stats_json());
+    }
+}
+
 fn print_completions(matches: &ArgMatches) {
     let mut cmd = build_cli();
     let bin_name = cmd.get_name().to_string();
@@ -137,7 +567,7 @@ fn print_completions(matches: &ArgMatches) {
 
 fn build_cli() -> Command {
     #![allow(non_upper_case_globals)]
-    command!(crate_name!())
+    let cli = command!(crate_name!())
         .arg_required_else_help(true)
         .version(crate_version!())
         .author(crate_authors!("\n"))
@@ -146,7 +576,16 @@ fn build_cli() -> Command {
         .subcommand(info_cmd())
         .subcommand(nuget_cmd())
         .subcommand(json_cmd())
-        .subcommand(completion_cmd())
+        .subcommand(toml_cmd())
+        .subcommand(buildorder_cmd())
+        .subcommand(stats_cmd())
+        .subcommand(analyze_cmd())
+        .subcommand(duplicates_cmd())
+        .subcommand(matrix_cmd())
+        .subcommand(configs_cmd());
+    #[cfg(feature = "export")]
+    let cli = cli.subcommand(export_cmd());
+    cli.subcommand(completion_cmd())
 }
 
 fn info_cmd() -> Command {
@@ -155,7 +594,72 @@ fn info_cmd() -> Command {
         .about("Get information about found solutions")
         .arg(extension_arg())
         .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
         .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
+        .arg(min_projects_arg())
+        .arg(base_arg())
+        .arg(name_filter_arg())
+        .arg(
+            arg!(--tests)
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("List test projects of each solution. False by default"),
+        )
+        .arg(
+            arg!(--"build-props")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Show SDK version from global.json and TargetFramework from Directory.Build.props. False by default"),
+        )
+        .arg(
+            arg!(--"config-counts")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Show configuration entries count of projects which configurations count differs from the solution's one sorted descending. False by default"),
+        )
+        .arg(
+            arg!(--assets)
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Load project files and show None and Content items count per project. False by default"),
+        )
+        .arg(
+            arg!(--bom)
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Show whether solution file starts with UTF-8 signature (BOM). False by default"),
+        )
+        .arg(
+            arg!(--"include-folders")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Count solution folders as projects of Solution Folder type. False by default"),
+        )
+        .arg(
+            arg!(--only <GUID>)
+                .required(false)
+                .help("Show only the project with the id specified, its configurations and dependencies in every solution that contains it"),
+        )
+        .arg(
+            arg!(--"count-by-type")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Print only total projects count by type across all solutions without per solution output. False by default"),
+        )
+        .arg(
+            arg!(--"stdin-paths")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .conflicts_with(PATH)
+                .help("Read newline separated solution paths from stdin instead of scanning PATH"),
+        )
         .arg(path_arg())
 }
 
@@ -170,9 +674,154 @@ fn validate_cmd() -> Command {
                 .action(ArgAction::SetTrue)
                 .help("Show only solutions with problems. Correct solutions will not be shown."),
         )
+        .arg(
+            arg!(--baseline <FILE>)
+                .required(false)
+                .help("Json file with known problems that will be neither reported nor counted"),
+        )
+        .arg(
+            arg!(--"compare-baseline" <FILE>)
+                .required(false)
+                .conflicts_with(BASELINE_ARG)
+                .help("Json file with known problems. Report only new problems and resolved ones and return not zero exit code if there are new problems"),
+        )
+        .arg(
+            arg!(--"write-baseline" <FILE>)
+                .required(false)
+                .help("Write all problems found into json file that can be used as baseline later"),
+        )
+        .arg(
+            arg!(--score)
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Print 0-100 health score of every solution calculated from the rules it fails. False by default"),
+        )
+        .arg(
+            arg!(--oneline)
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Print one grep-able status line per solution like CYCLE <path> or CYCLE <score> <path> if --score specified instead of tables"),
+        )
+        .arg(
+            arg!(--"max-problems" <N>)
+                .required(false)
+                .value_parser(clap::value_parser!(usize))
+                .help("Stop scanning and exit with non zero code after the number of solutions with problems specified"),
+        )
+        .arg(
+            arg!(--"ignore-project" <GUID>)
+                .required(false)
+                .action(ArgAction::Append)
+                .help("Don't report nor count problems involving project with the id specified. Can be used several times"),
+        )
+        .arg(
+            arg!(--"fail-on-parse-error")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Return not zero exit code if any solution cannot be read or parsed. False by default"),
+        )
+        .arg(
+            arg!(--"check-legacy-platforms")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Report solutions that still declare obsolete Mixed Platforms platform. False by default"),
+        )
+        .arg(
+            arg!(--"check-format-product")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Report solutions which format version disagrees with product comment. False by default"),
+        )
+        .arg(
+            arg!(--"check-source-control")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Report solutions that still contain legacy TFS source control bindings. False by default"),
+        )
+        .arg(
+            arg!(--"check-name-case")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Report projects which names differ only by case. False by default"),
+        )
+        .arg(
+            arg!(--"check-indentation")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Report solutions indented using spaces instead of tabs. False by default"),
+        )
+        .arg(
+            arg!(--"check-imports")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Load project files and report imports of .props or .targets files that don't exist. False by default"),
+        )
+        .arg(
+            arg!(--"strict-guids")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Report GUIDs that aren't in canonical {UPPERCASE-WITH-HYPHENS} form. False by default"),
+        )
+        .arg(
+            arg!(--"follow-project-references")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Load project files reachable through project references and report ones not included into solution. False by default"),
+        )
+        .arg(
+            arg!(--"check-outside-references")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Load project files and report references to projects that aren't included into solution. False by default"),
+        )
+        .arg(
+            arg!(--"check-reference-cycles")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Load project files and report cycles of project references. False by default"),
+        )
+        .arg(
+            arg!(--"check-output-paths")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Load project files and report projects that build into the same output directory. False by default"),
+        )
+        .arg(
+            arg!(--"check-solution-items")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Report solution items that don't exist on disk. False by default"),
+        )
+        .arg(
+            arg!(--"check-unknown-platforms")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Additionally report project configurations by platforms that solution doesn't declare. False by default"),
+        )
         .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
         .arg(time_arg())
-        .arg(path_arg().required(true))
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
+        .arg(
+            arg!(--"watch-dir")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Keep watching directory specified and re-validate solutions which directory subtree changed"),
+        )
+        .arg(
+            arg!(--explain <RULE>)
+                .required(false)
+                .help("Print explanation of the validation rule specified like cycles and exit"),
+        )
+        .arg(min_projects_arg())
+        .arg(base_arg())
+        .arg(path_arg().required_unless_present("explain"))
 }
 
 fn nuget_cmd() -> Command {
@@ -192,10 +841,42 @@ fn nuget_cmd() -> Command {
         arg!(-f --fail)
             .required(false)
             .action(ArgAction::SetTrue)
-            .help("Return not zero exit code if nuget mismatches found"),
+            .help("Return not zero exit code if nuget mismatches or forbidden packages found"),
+    )
+    .arg(
+        arg!(--"dedup-packages")
+            .required(false)
+            .action(ArgAction::SetTrue)
+            .help("Show every package only once with all versions found and solutions using them. False by default"),
+    )
+    .arg(
+        arg!(--"project-timeout" <MS>)
+            .required(false)
+            .value_parser(clap::value_parser!(u64))
+            .help("Skip and report project files which parsing takes longer than the number of milliseconds specified"),
+    )
+    .arg(
+        arg!(--"max-project-size" <BYTES>)
+            .required(false)
+            .value_parser(clap::value_parser!(u64))
+            .help("Skip and report project files larger than the number of bytes specified"),
+    )
+    .arg(
+        arg!(--forbid <FILE>)
+            .required(false)
+            .help("Report solutions that use packages listed in the file specified. Every line is a package name optionally followed by a version or a version range like [1.0,2.0)"),
     )
     .arg(recursively_arg())
+    .arg(head_arg())
+    .arg(tail_arg())
+    .arg(fail_on_empty_arg())
     .arg(time_arg())
+    .arg(time_each_arg())
+    .arg(stats_json_arg())
+    .arg(json_out_arg())
+    .arg(jsonl_errors_arg())
+    .arg(encoding_arg())
+    .arg(base_arg())
     .arg(path_arg().required(true))
 }
 
@@ -205,20 +886,234 @@ fn json_cmd() -> Command {
         .about("Converts solution(s) into json")
         .arg(extension_arg())
         .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
         .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
         .arg(
             arg!(-p - -pretty)
                 .required(false)
                 .action(ArgAction::SetTrue)
                 .help("Pretty-printed output. False by default"),
         )
+        .arg(
+            arg!(--positions)
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Add line and column of each project declaration. False by default"),
+        )
+        .arg(
+            arg!(--"resolve-sdk")
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Load project files and add whether each one is SDK-style. False by default"),
+        )
+        .arg(
+            arg!(--fields <FIELDS>)
+                .required(false)
+                .value_parser(solv::json::parse_project_fields)
+                .help("Emit only comma separated project fields specified like name,id,path,type"),
+        )
+        .arg(path_arg())
+}
+
+fn toml_cmd() -> Command {
+    Command::new("toml")
+        .about("Converts solution(s) into toml. Solutions are emitted as [[solutions]] array of tables")
+        .arg(extension_arg())
+        .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
+        .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
         .arg(path_arg())
 }
 
+fn buildorder_cmd() -> Command {
+    Command::new("buildorder")
+        .aliases(["bo"])
+        .about("Prints solution projects paths in build order i.e. dependencies first")
+        .arg(extension_arg())
+        .arg(
+            arg!(--root <GUID>)
+                .required(false)
+                .help("Print only the project with the id specified and projects it depends on"),
+        )
+        .arg(
+            arg!(--depth <N>)
+                .required(false)
+                .requires("root")
+                .value_parser(clap::value_parser!(usize))
+                .help("Limit the number of dependency levels printed from the root project. 1 means direct dependencies only"),
+        )
+        .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
+        .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
+        .arg(base_arg())
+        .arg(path_arg())
+}
+
+fn stats_cmd() -> Command {
+    Command::new("stats")
+        .about("Aggregates metrics across all solutions found")
+        .arg(extension_arg())
+        .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
+        .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
+        .arg(path_arg().required(true))
+}
+
+fn analyze_cmd() -> Command {
+    Command::new("analyze")
+        .about("Runs info, validation and nuget analysis in one pass and prints all findings per solution")
+        .arg(extension_arg())
+        .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
+        .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
+        .arg(base_arg())
+        .arg(
+            arg!(--markdown <FILE>)
+                .required(false)
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("Also write Markdown report with totals, problems and nuget mismatches into the file specified"),
+        )
+        .arg(path_arg().required(true))
+}
+
+fn duplicates_cmd() -> Command {
+    Command::new("duplicates")
+        .aliases(["dup"])
+        .about("Finds copies of the same solution by SolutionGuid or by projects and configurations if there is no SolutionGuid")
+        .arg(extension_arg())
+        .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
+        .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
+        .arg(base_arg())
+        .arg(path_arg().required(true))
+}
+
+#[cfg(feature = "export")]
+fn export_cmd() -> Command {
+    Command::new("export")
+        .about("Exports solutions, projects, configurations and dependencies into database for querying")
+        .arg(
+            arg!(--sqlite <FILE>)
+                .required(true)
+                .value_parser(clap::value_parser!(PathBuf))
+                .help("SQLite database file to write into. Tables are created if they don't exist"),
+        )
+        .arg(extension_arg())
+        .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
+        .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
+        .arg(base_arg())
+        .arg(path_arg().required(true))
+}
+
+fn matrix_cmd() -> Command {
+    Command::new("matrix")
+        .about("Prints projects adjacency matrix as CSV. Matrix has a row and a column per project so it may be huge for large solutions")
+        .arg(
+            arg!(--deps)
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Dependencies matrix where 1 marks that the row project depends on the column one"),
+        )
+        .group(ArgGroup::new("kind").args(["deps"]).required(true))
+        .arg(extension_arg())
+        .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
+        .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
+        .arg(path_arg())
+}
+
+fn configs_cmd() -> Command {
+    Command::new("configs")
+        .about("Audits solutions configurations sprawl")
+        .arg(
+            arg!(--grid)
+                .required(false)
+                .action(ArgAction::SetTrue)
+                .help("Print a row per solution with the number of configurations and platforms and whether Mixed Platforms or unusual platforms are present"),
+        )
+        .group(ArgGroup::new("kind").args(["grid"]).required(true))
+        .arg(extension_arg())
+        .arg(recursively_arg())
+        .arg(head_arg())
+        .arg(tail_arg())
+        .arg(fail_on_empty_arg())
+        .arg(time_arg())
+        .arg(time_each_arg())
+        .arg(stats_json_arg())
+        .arg(json_out_arg())
+        .arg(jsonl_errors_arg())
+        .arg(encoding_arg())
+        .arg(base_arg())
+        .arg(path_arg().required(true))
+}
+
 fn path_arg() -> Arg {
     arg!([PATH]).help(PATH_DESCR)
 }
 
+fn stats_json_arg() -> Arg {
+    arg!(--"stats-json")
+        .required(false)
+        .action(ArgAction::SetTrue)
+        .help("Print final statistic as a single compact json line after normal output. False by default")
+}
+
 fn time_arg() -> Arg {
     arg!(-t - -time)
         .required(false)
@@ -226,6 +1121,59 @@ fn time_arg() -> Arg {
         .help(BENCHMARK_DESCR)
 }
 
+fn time_each_arg() -> Arg {
+    arg!(--"time-each")
+        .required(false)
+        .action(ArgAction::SetTrue)
+        .help("Print time spent on parsing every solution next to its path. Useful to find slow solutions. False by default")
+}
+
+fn json_out_arg() -> Arg {
+    arg!(--"json-out" <FILE>)
+        .required(false)
+        .value_parser(clap::value_parser!(PathBuf))
+        .help("Also write all parsed solutions as json into the file specified")
+}
+
+fn jsonl_errors_arg() -> Arg {
+    arg!(--"jsonl-errors")
+        .required(false)
+        .action(ArgAction::SetTrue)
+        .help("Write every solution that cannot be parsed into stderr as a json line with path and message. False by default")
+}
+
+fn encoding_arg() -> Arg {
+    arg!(--encoding <LABEL>)
+        .required(false)
+        .value_parser(|label: &str| {
+            solp::decode(b"", Some(label))
+                .map(|_| label.to_owned())
+                .map_err(|e| e.to_string())
+        })
+        .help("Decode solutions using the encoding specified like windows-1251 instead of UTF-8")
+}
+
+fn base_arg() -> Arg {
+    arg!(--base <DIR>)
+        .required(false)
+        .value_parser(clap::value_parser!(PathBuf))
+        .help("Print paths relative to the directory specified. Paths outside it stay absolute")
+}
+
+fn min_projects_arg() -> Arg {
+    arg!(--"min-projects" <N>)
+        .required(false)
+        .value_parser(clap::value_parser!(usize))
+        .help("Skip solutions with less than the number of projects specified. Solution folders aren't counted")
+}
+
+fn name_filter_arg() -> Arg {
+    arg!(--"name-filter" <REGEX>)
+        .required(false)
+        .value_parser(Regex::new)
+        .help("Show only projects which name matches the regular expression specified")
+}
+
 fn extension_arg() -> Arg {
     arg!(-e --ext <EXTENSION>)
         .required(false)
@@ -242,6 +1190,31 @@ fn recursively_arg() -> Arg {
         .help(RECURSIVELY_DESCR)
 }
 
+fn fail_on_empty_arg() -> Arg {
+    arg!(--"fail-on-empty")
+        .required(false)
+        .requires(PATH)
+        .action(ArgAction::SetTrue)
+        .help("Return not zero exit code if no solutions parsed in directory or archive specified. Single file that cannot be parsed fails anyway. False by default")
+}
+
+fn head_arg() -> Arg {
+    arg!(--head <N>)
+        .required(false)
+        .requires(PATH)
+        .conflicts_with(TAIL_ARG)
+        .value_parser(clap::value_parser!(usize))
+        .help("Process only the first number of solutions found inside directory specified")
+}
+
+fn tail_arg() -> Arg {
+    arg!(--tail <N>)
+        .required(false)
+        .requires(PATH)
+        .value_parser(clap::value_parser!(usize))
+        .help("Process only the last number of solutions found inside directory specified")
+}
+
 fn completion_cmd() -> Command {
     Command::new("completion")
         .about("Generate the autocompletion script for the specified shell")
@@ -252,3 +1225,108 @@ fn completion_cmd() -> Command {
                 .index(1),
         )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("", 0)]
+    #[case(EMPTY_SOLUTION, 1)]
+    #[trace]
+    fn scan_solutions_dir(#[case] content: &str, #[case] expected: usize) {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        if !content.is_empty() {
+            fs::write(dir.path().join("a.sln"), content).unwrap();
+        }
+        fs::write(dir.path().join("a.csproj"), "").unwrap();
+        let path = dir.path().to_str().unwrap();
+        let matches = build_cli().get_matches_from(["solv", "validate", "--fail-on-empty", path]);
+        let cmd = matches.subcommand_matches("validate").unwrap();
+        let mut consumer = Validate::new(false);
+
+        // Act
+        let actual = scan_solutions(cmd, path, &mut consumer, &ParseOptions::default());
+
+        // Assert
+        assert_eq!(expected, actual.unwrap());
+        assert!(cmd.get_flag(FAIL_ON_EMPTY_FLAG));
+    }
+
+    #[rstest]
+    #[case("", &["--fail-on-empty"], exitcode::SOFTWARE)]
+    #[case("", &[], exitcode::OK)]
+    #[case(EMPTY_SOLUTION, &["--fail-on-empty"], exitcode::OK)]
+    #[trace]
+    fn scan_exit_code_tests(
+        #[case] content: &str,
+        #[case] flags: &[&str],
+        #[case] expected: exitcode::ExitCode,
+    ) {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        if !content.is_empty() {
+            fs::write(dir.path().join("a.sln"), content).unwrap();
+        }
+        let path = dir.path().to_str().unwrap();
+        let args = ["solv", "validate"]
+            .iter()
+            .chain(flags)
+            .chain(&[path])
+            .copied()
+            .collect::<Vec<&str>>();
+        let matches = build_cli().get_matches_from(args);
+        let cmd = matches.subcommand_matches("validate").unwrap();
+        let mut consumer = Validate::new(false);
+        let scanned = scan_solutions(cmd, path, &mut consumer, &ParseOptions::default()).unwrap();
+
+        // Act
+        let actual = scan_exit_code(cmd, scanned);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn scan_solutions_unparseable_file() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("a.sln");
+        // Legacy code page bytes that aren't valid UTF-8
+        fs::write(&path, [0xcf, 0xf0, 0xee]).unwrap();
+        let path = path.to_str().unwrap();
+        let matches = build_cli().get_matches_from(["solv", "validate", "--fail-on-empty", path]);
+        let cmd = matches.subcommand_matches("validate").unwrap();
+        let mut consumer = Validate::new(false);
+
+        // Act
+        let actual = scan_solutions(cmd, path, &mut consumer, &ParseOptions::default());
+
+        // Assert
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn duplicates_without_json_out() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.sln"), EMPTY_SOLUTION).unwrap();
+        let path = dir.path().to_str().unwrap();
+        let rejected =
+            build_cli().try_get_matches_from(["solv", "duplicates", "--json-out", "x.json", path]);
+        let matches = build_cli().get_matches_from(["solv", "duplicates", path]);
+        let cmd = matches.subcommand_matches("duplicates").unwrap();
+        let mut consumer = Duplicates::new();
+
+        // Act
+        let actual = scan_solutions(cmd, path, &mut consumer, &ParseOptions::default());
+
+        // Assert
+        assert!(rejected.is_err());
+        assert_eq!(1, actual.unwrap());
+    }
+
+    const EMPTY_SOLUTION: &str = "\nMicrosoft Visual Studio Solution File, Format Version 12.00\n";
+}
@@ -1,28 +1,42 @@
 use std::{
     cell::RefCell,
-    collections::{BTreeSet, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
     fmt::{self, Display},
     path::PathBuf,
+    sync::Arc,
+    time::Duration,
 };
 
+use color_eyre::Report;
 use comfy_table::{Attribute, Cell, Color, Row};
 use crossterm::style::Stylize;
 use itertools::Itertools;
 use solp::{
-    api::Solution,
-    msbuild::{self, PackagesConfig, Project},
+    api::{PathResolution, Solution},
+    msbuild::{self, PackagesConfig},
 };
 
-use crate::{error::Collector, ux, Consume};
+use crate::{error::Collector, forbidden::Forbidden, loader, ux, Consume, StatsJson};
 
 pub struct Nuget {
     show_only_mismatched: bool,
     pub mismatches_found: bool,
     errors: RefCell<Collector>,
+    base: Option<PathBuf>,
+    dedup_packages: bool,
+    /// package name -> version -> solution paths where it's used
+    packages: BTreeMap<String, BTreeMap<String, BTreeSet<String>>>,
+    /// solution path -> package name -> versions of mismatched packages
+    mismatched: BTreeMap<String, BTreeMap<String, BTreeSet<String>>>,
+    max_project_size: Option<u64>,
+    project_timeout: Option<Duration>,
+    forbidden: Option<Forbidden>,
+    /// solution path -> forbidden (package name, version) pairs used
+    forbidden_used: BTreeMap<String, BTreeSet<(String, String)>>,
 }
 
 struct MsbuildProject {
-    pub project: Option<msbuild::Project>,
+    pub project: Option<Arc<msbuild::Project>>,
     pub path: PathBuf,
 }
 
@@ -33,30 +47,141 @@ impl Nuget {
             show_only_mismatched,
             mismatches_found: false,
             errors: RefCell::new(Collector::new()),
+            base: None,
+            dedup_packages: false,
+            packages: BTreeMap::new(),
+            mismatched: BTreeMap::new(),
+            max_project_size: None,
+            project_timeout: None,
+            forbidden: None,
+            forbidden_used: BTreeMap::new(),
         }
     }
+
+    /// Shows every package once with all versions observed and solutions using them
+    /// instead of packages table per solution
+    #[must_use]
+    pub fn with_dedup_packages(mut self, dedup_packages: bool) -> Self {
+        self.dedup_packages = dedup_packages;
+        self
+    }
+
+    /// Skips and reports as not parsed project files larger than the size in bytes specified
+    #[must_use]
+    pub fn with_max_project_size(mut self, max_project_size: Option<u64>) -> Self {
+        self.max_project_size = max_project_size;
+        self
+    }
+
+    /// Skips and reports as not parsed project files which parsing takes longer than the timeout specified
+    #[must_use]
+    pub fn with_project_timeout(mut self, project_timeout: Option<Duration>) -> Self {
+        self.project_timeout = project_timeout;
+        self
+    }
+
+    /// Reports solutions that use any package from the forbidden list specified
+    #[must_use]
+    pub fn with_forbidden(mut self, forbidden: Option<Forbidden>) -> Self {
+        self.forbidden = forbidden;
+        self
+    }
+
+    /// Prints paths relative to the base directory specified
+    #[must_use]
+    pub fn with_base(mut self, base: Option<PathBuf>) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Packages with different versions in the same solution grouped by solution path
+    #[must_use]
+    pub fn mismatched_packages(&self) -> &BTreeMap<String, BTreeMap<String, BTreeSet<String>>> {
+        &self.mismatched
+    }
+
+    /// Whether any solution scanned uses forbidden packages
+    #[must_use]
+    pub fn forbidden_found(&self) -> bool {
+        !self.forbidden_used.is_empty()
+    }
+
+    fn forbidden_table(&self) -> comfy_table::Table {
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Solution").add_attribute(Attribute::Bold),
+            Cell::new("Forbidden package").add_attribute(Attribute::Bold),
+            Cell::new("Version").add_attribute(Attribute::Bold),
+        ]);
+        let rows = self.forbidden_used.iter().flat_map(|(path, packages)| {
+            packages.iter().map(move |(pkg, version)| {
+                Row::from([
+                    Cell::new(path),
+                    Cell::new(pkg).fg(Color::Red),
+                    Cell::new(version).add_attribute(Attribute::Italic),
+                ])
+            })
+        });
+        table.add_rows(rows);
+        table
+    }
+
+    fn packages_table(&self) -> comfy_table::Table {
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Package").add_attribute(Attribute::Bold),
+            Cell::new("Version").add_attribute(Attribute::Bold),
+            Cell::new("Solutions").add_attribute(Attribute::Bold),
+        ]);
+        let rows = self
+            .packages
+            .iter()
+            .sorted_unstable_by_key(|(pkg, _)| pkg.to_lowercase())
+            .flat_map(|(pkg, versions)| {
+                let mismatch = versions.len() > 1;
+                versions.iter().map(move |(version, paths)| {
+                    let mut version = Cell::new(version).add_attribute(Attribute::Italic);
+                    if mismatch {
+                        version = version.fg(Color::Red);
+                    }
+                    Row::from([Cell::new(pkg), version, Cell::new(paths.iter().join("\n"))])
+                })
+            });
+        table.add_rows(rows);
+        table
+    }
 }
 
-fn collect_msbuild_projects(solution: &Solution) -> Vec<MsbuildProject> {
+/// Loads solution's project files. Returns loaded projects and
+/// paths of ones skipped because of size or time limits with the reason
+fn collect_msbuild_projects(
+    solution: &Solution,
+    max_size: Option<u64>,
+    timeout: Option<Duration>,
+) -> (Vec<MsbuildProject>, Vec<(PathBuf, Report)>) {
     let dir = crate::parent_of(solution.path);
 
-    solution
+    let mut skipped = vec![];
+    let loaded = solution
         .iterate_projects_without_web_sites()
-        .filter_map(|p| crate::try_make_local_path(dir, p.path_or_uri))
-        .filter_map(|path| match Project::from_path(&path) {
-            Ok(project) => Some(MsbuildProject {
-                path,
-                project: Some(project),
-            }),
-            Err(e) => {
-                if cfg!(debug_assertions) {
-                    let p = path.to_str().unwrap_or_default();
-                    println!("{p}: {e:?}");
-                }
-                None
-            }
+        .filter_map(|p| match p.resolve_existing(dir) {
+            PathResolution::Exists(path) => Some(path),
+            _ => None,
         })
-        .collect()
+        .filter_map(
+            |path| match loader::load_msbuild_project_limited(&path, max_size, timeout) {
+                Ok(project) => Some(MsbuildProject {
+                    project: Some(project?),
+                    path,
+                }),
+                Err(e) => {
+                    skipped.push((path, e));
+                    None
+                }
+            },
+        )
+        .collect();
+    (loaded, skipped)
 }
 
 fn has_mismatches(versions: &BTreeSet<(Option<&String>, &String)>) -> bool {
@@ -69,7 +194,11 @@ fn has_mismatches(versions: &BTreeSet<(Option<&String>, &String)>) -> bool {
 
 impl Consume for Nuget {
     fn ok(&mut self, solution: &solp::api::Solution) {
-        let projects = collect_msbuild_projects(solution);
+        let (projects, skipped) =
+            collect_msbuild_projects(solution, self.max_project_size, self.project_timeout);
+        for (path, error) in &skipped {
+            self.err_with(&path.to_string_lossy(), error);
+        }
 
         let mut nugets = nugets(&projects);
         let nugets_from_packages_config = nugets_from_packages_configs(&projects);
@@ -81,7 +210,49 @@ impl Consume for Nuget {
         // merging packages from packages.config if any
         nugets.extend(nugets_from_packages_config);
 
+        let mismatched = nugets
+            .iter()
+            .filter(|(_, versions)| has_mismatches(versions))
+            .map(|(pkg, versions)| {
+                let versions = versions.iter().map(|(_, v)| (*v).clone()).collect();
+                ((*pkg).clone(), versions)
+            })
+            .collect::<BTreeMap<String, BTreeSet<String>>>();
+        if !mismatched.is_empty() {
+            let path = crate::relative_to(solution.path, self.base.as_deref());
+            self.mismatched.insert(path, mismatched);
+        }
+
+        if let Some(forbidden) = &self.forbidden {
+            let used = nugets
+                .iter()
+                .flat_map(|(pkg, versions)| versions.iter().map(move |(_, v)| (*pkg, *v)))
+                .filter(|(pkg, version)| forbidden.is_forbidden(pkg, version))
+                .map(|(pkg, version)| (pkg.clone(), version.clone()))
+                .collect::<BTreeSet<(String, String)>>();
+            if !used.is_empty() {
+                let path = crate::relative_to(solution.path, self.base.as_deref());
+                self.forbidden_used.insert(path, used);
+            }
+        }
+
+        if self.dedup_packages {
+            self.mismatches_found |= nugets.values().any(has_mismatches);
+            let path = crate::relative_to(solution.path, self.base.as_deref());
+            for (pkg, versions) in &nugets {
+                let observed = self.packages.entry((*pkg).clone()).or_default();
+                for (_, version) in versions {
+                    observed
+                        .entry((*version).clone())
+                        .or_default()
+                        .insert(path.clone());
+                }
+            }
+            return;
+        }
+
         if nugets.is_empty() {
+            // Solutions without packages are never shown because they cannot have mismatches
             return;
         }
 
@@ -126,7 +297,7 @@ impl Consume for Nuget {
             return;
         }
 
-        ux::print_solution_path(solution.path);
+        ux::print_solution_path(solution.path, self.base.as_deref());
         println!("{table}");
         println!();
     }
@@ -134,10 +305,40 @@ impl Consume for Nuget {
     fn err(&self, path: &str) {
         self.errors.borrow_mut().add_path(path);
     }
+
+    fn err_with(&self, path: &str, error: &Report) {
+        self.errors.borrow_mut().add_path_with(path, error);
+    }
+}
+
+impl StatsJson for Nuget {
+    fn stats_json(&self) -> String {
+        serde_json::json!({
+            "mismatches_found": self.mismatches_found,
+            "forbidden_found": self.forbidden_found(),
+            "not_parsed": self.errors.borrow().count(),
+        })
+        .to_string()
+    }
 }
 
 impl Display for Nuget {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.dedup_packages && !self.packages.is_empty() {
+            writeln!(f, "{}", self.packages_table())?;
+            writeln!(f)?;
+        }
+        if self.forbidden_found() {
+            writeln!(
+                f,
+                " {}",
+                "Solutions with forbidden nuget packages found"
+                    .dark_red()
+                    .bold()
+            )?;
+            writeln!(f, "{}", self.forbidden_table())?;
+            writeln!(f)?;
+        }
         if self.mismatches_found && !self.show_only_mismatched {
             writeln!(
                 f,
@@ -202,9 +403,11 @@ fn nugets_from_packages_configs(projects: &[MsbuildProject]) -> HashMap<String,
 mod tests {
     use std::path::PathBuf;
 
+    use rstest::rstest;
     use solp::msbuild::{ItemGroup, PackageReference, Project};
 
     use super::*;
+    use crate::fixtures::SINGLE_SDK_PROJECT_SOLUTION;
 
     #[test]
     fn nugets_no_mismatches() {
@@ -358,17 +561,178 @@ mod tests {
         condition: Option<String>,
     ) -> MsbuildProject {
         MsbuildProject {
-            project: Some(Project {
+            project: Some(Arc::new(Project {
                 sdk: Some("5".to_owned()),
                 item_group: Some(vec![ItemGroup {
                     project_reference: None,
                     package_reference: Some(packs),
+                    none: None,
+                    content: None,
                     condition,
                 }]),
                 imports: None,
                 import_group: None,
-            }),
+                property_group: None,
+            })),
             path: PathBuf::new(),
         }
     }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    #[trace]
+    fn solution_without_packages_tests(#[case] only_mismatched: bool) {
+        // arrange
+        let solution = solp::parse_str(SINGLE_SDK_PROJECT_SOLUTION).unwrap();
+        let mut nuget = Nuget::new(only_mismatched);
+
+        // act
+        nuget.ok(&solution);
+
+        // assert
+        assert!(nuget.mismatched.is_empty());
+        assert!(!nuget.mismatches_found);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn dedup_packages_across_solutions() {
+        // arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut paths = vec![];
+        for (name, version) in [("a", "1.0.0"), ("b", "2.0.0")] {
+            let solution_dir = dir.path().join(name);
+            std::fs::create_dir_all(solution_dir.join("a")).unwrap();
+            std::fs::write(
+                solution_dir.join("a").join("a.csproj"),
+                format!(
+                    r#"<Project Sdk="Microsoft.NET.Sdk">
+  <ItemGroup>
+    <PackageReference Include="xunit" Version="{version}" />
+  </ItemGroup>
+</Project>
+"#
+                ),
+            )
+            .unwrap();
+            let path = solution_dir.join("s.sln");
+            std::fs::write(&path, SINGLE_SDK_PROJECT_SOLUTION).unwrap();
+            paths.push(path.to_str().unwrap().to_owned());
+        }
+        let mut nuget = Nuget::new(false).with_dedup_packages(true);
+
+        // act
+        for path in &paths {
+            solp::parse_file(path, &mut nuget).unwrap();
+        }
+
+        // assert
+        assert_eq!(1, nuget.packages.len());
+        let versions = &nuget.packages["xunit"];
+        assert_eq!(vec!["1.0.0", "2.0.0"], versions.keys().collect_vec());
+        assert_eq!(BTreeSet::from([paths[0].clone()]), versions["1.0.0"]);
+        assert_eq!(BTreeSet::from([paths[1].clone()]), versions["2.0.0"]);
+        assert!(!nuget.mismatches_found);
+    }
+
+    #[test]
+    fn escaped_project_path_loaded() {
+        // arrange
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("My Project")).unwrap();
+        std::fs::write(
+            dir.path().join("My Project").join("a.csproj"),
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <ItemGroup>
+    <PackageReference Include="xunit" Version="1.0.0" />
+  </ItemGroup>
+</Project>
+"#,
+        )
+        .unwrap();
+        let path = dir.path().join("s.sln");
+        std::fs::write(
+            &path,
+            SINGLE_SDK_PROJECT_SOLUTION.replace(r"a\a.csproj", r"My%20Project\a.csproj"),
+        )
+        .unwrap();
+        let mut nuget = Nuget::new(false).with_dedup_packages(true);
+
+        // act
+        solp::parse_file(path.to_str().unwrap(), &mut nuget).unwrap();
+
+        // assert
+        assert_eq!(vec!["xunit"], nuget.packages.keys().collect_vec());
+    }
+
+    #[test]
+    fn too_large_projects_skipped() {
+        // arrange
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("a")).unwrap();
+        std::fs::write(
+            dir.path().join("a").join("a.csproj"),
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <ItemGroup>
+    <PackageReference Include="xunit" Version="1.0.0" />
+  </ItemGroup>
+</Project>
+"#,
+        )
+        .unwrap();
+        let path = dir.path().join("s.sln");
+        std::fs::write(&path, SINGLE_SDK_PROJECT_SOLUTION).unwrap();
+        let mut nuget = Nuget::new(false)
+            .with_dedup_packages(true)
+            .with_max_project_size(Some(10));
+
+        // act
+        solp::parse_file(path.to_str().unwrap(), &mut nuget).unwrap();
+
+        // assert
+        assert!(nuget.packages.is_empty());
+        assert_eq!(1, nuget.errors.borrow().count());
+        assert!(nuget
+            .errors
+            .borrow()
+            .reasons()
+            .all(|r| r.contains("exceeds 10 bytes limit")));
+        assert_eq!(1, nuget.errors.borrow().reasons().count());
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn forbidden_packages_found() {
+        // arrange
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("a")).unwrap();
+        std::fs::write(
+            dir.path().join("a").join("a.csproj"),
+            r#"<Project Sdk="Microsoft.NET.Sdk">
+  <ItemGroup>
+    <PackageReference Include="xunit" Version="2.4.1" />
+    <PackageReference Include="log4net" Version="1.2.10" />
+  </ItemGroup>
+</Project>
+"#,
+        )
+        .unwrap();
+        let path = dir.path().join("s.sln");
+        std::fs::write(&path, SINGLE_SDK_PROJECT_SOLUTION).unwrap();
+        let forbidden =
+            Forbidden::parse("# legacy logging\nlog4net (,2.0)\nxunit [1.0,2.0)\n").unwrap();
+        let mut nuget = Nuget::new(false).with_forbidden(Some(forbidden));
+
+        // act
+        solp::parse_file(path.to_str().unwrap(), &mut nuget).unwrap();
+
+        // assert
+        assert!(nuget.forbidden_found());
+        let used = nuget.forbidden_used.values().next().unwrap();
+        assert_eq!(
+            &BTreeSet::from([("log4net".to_owned(), "1.2.10".to_owned())]),
+            used
+        );
+    }
 }
@@ -0,0 +1,241 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::{self, Display};
+
+use comfy_table::{Attribute, Cell};
+use crossterm::style::Stylize;
+use num_format::{Locale, ToFormattedString};
+use serde::Serialize;
+use solp::api::Solution;
+use solp::Consume;
+
+use crate::error::Collector;
+use crate::{graph, loader, ux, StatsJson};
+
+/// Repository wide aggregate metrics over all solutions scanned
+pub struct Stats {
+    solutions: u64,
+    project_entries: u64,
+    unique_projects: HashSet<String>,
+    projects_by_type: BTreeMap<String, u64>,
+    with_cycles: u64,
+    with_danglings: u64,
+    errors: RefCell<Collector>,
+}
+
+#[derive(Serialize)]
+struct Summary<'a> {
+    solutions: u64,
+    unique_projects: usize,
+    average_projects: f64,
+    with_cycles: u64,
+    with_danglings: u64,
+    not_parsed: u64,
+    projects_by_type: &'a BTreeMap<String, u64>,
+}
+
+impl Stats {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            solutions: 0,
+            project_entries: 0,
+            unique_projects: HashSet::new(),
+            projects_by_type: BTreeMap::new(),
+            with_cycles: 0,
+            with_danglings: 0,
+            errors: RefCell::new(Collector::new()),
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn average_projects(&self) -> f64 {
+        if self.solutions == 0 {
+            0_f64
+        } else {
+            self.project_entries as f64 / self.solutions as f64
+        }
+    }
+
+    fn summary(&self) -> Summary<'_> {
+        Summary {
+            solutions: self.solutions,
+            unique_projects: self.unique_projects.len(),
+            average_projects: self.average_projects(),
+            with_cycles: self.with_cycles,
+            with_danglings: self.with_danglings,
+            not_parsed: self.errors.borrow().count(),
+            projects_by_type: &self.projects_by_type,
+        }
+    }
+}
+
+impl Default for Stats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Consume for Stats {
+    fn ok(&mut self, solution: &Solution) {
+        self.solutions += 1;
+        let dir = crate::parent_of(solution.path);
+        for project in solution.iterate_projects() {
+            self.project_entries += 1;
+            // The same project may be included into several solutions so use its full path as identity
+            let key = loader::project_path(dir, project).map_or_else(
+                || project.path_or_uri.to_lowercase(),
+                |p| loader::normalize_path(&p).to_string_lossy().to_lowercase(),
+            );
+            if self.unique_projects.insert(key) {
+                *self
+                    .projects_by_type
+                    .entry(project.type_description.to_owned())
+                    .or_insert(0) += 1;
+            }
+        }
+        if graph::topological_order(solution).is_err() {
+            self.with_cycles += 1;
+        }
+        if solution.dangling_project_configurations.is_some() {
+            self.with_danglings += 1;
+        }
+    }
+
+    fn err(&self, path: &str) {
+        self.errors.borrow_mut().add_path(path);
+    }
+}
+
+impl StatsJson for Stats {
+    fn stats_json(&self) -> String {
+        serde_json::to_string(&self.summary()).unwrap_or_default()
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, " {}", "Statistic:".dark_red().bold())?;
+
+        let summary = self.summary();
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Metric").add_attribute(Attribute::Bold),
+            Cell::new("Value").add_attribute(Attribute::Bold),
+        ]);
+        table.add_row([
+            Cell::new("Total solutions"),
+            Cell::new(summary.solutions.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+        ]);
+        table.add_row([
+            Cell::new("Total unique projects"),
+            Cell::new(summary.unique_projects.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+        ]);
+        table.add_row([
+            Cell::new("Average projects per solution"),
+            Cell::new(format!("{:.2}", summary.average_projects)).add_attribute(Attribute::Italic),
+        ]);
+        table.add_row([
+            Cell::new("Solutions with dependencies cycles"),
+            Cell::new(summary.with_cycles.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+        ]);
+        table.add_row([
+            Cell::new("Solutions with dangling project configurations"),
+            Cell::new(summary.with_danglings.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+        ]);
+        table.add_row([
+            Cell::new("Not parsed"),
+            Cell::new(summary.not_parsed.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+        ]);
+        writeln!(f, "{table}")?;
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project type").add_attribute(Attribute::Bold),
+            Cell::new("Unique projects").add_attribute(Attribute::Bold),
+        ]);
+        for (project_type, count) in summary.projects_by_type {
+            table.add_row([
+                Cell::new(project_type),
+                Cell::new(count.to_formatted_string(&Locale::en)).add_attribute(Attribute::Italic),
+            ]);
+        }
+        writeln!(f, "{table}")?;
+
+        write!(f, "{}", self.errors.borrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_over_several_solutions() {
+        // Arrange
+        let mut first = solp::parse_str(FIRST_SOLUTION).unwrap();
+        first.path = "/repo/first.sln";
+        let mut second = solp::parse_str(SECOND_SOLUTION).unwrap();
+        second.path = "/repo/second.sln";
+        let mut stats = Stats::new();
+
+        // Act
+        stats.ok(&first);
+        stats.ok(&second);
+        stats.err("/repo/bad.sln");
+
+        // Assert
+        let summary = stats.summary();
+        assert_eq!(2, summary.solutions);
+        assert_eq!(3, summary.unique_projects);
+        assert!((summary.average_projects - 2.0).abs() < f64::EPSILON);
+        assert_eq!(1, summary.with_cycles);
+        assert_eq!(1, summary.with_danglings);
+        assert_eq!(1, summary.not_parsed);
+        assert_eq!(Some(&3), summary.projects_by_type.get("C#"));
+    }
+
+    const FIRST_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SECOND_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+	ProjectSection(ProjectDependencies) = postProject
+		{3AF54C8A-10BF-4332-9147-F68ED9862032} = {3AF54C8A-10BF-4332-9147-F68ED9862032}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "c", "c\c.csproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+	ProjectSection(ProjectDependencies) = postProject
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144} = {D9523F4D-6CB7-4431-85F6-8122F55EB144}
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+}
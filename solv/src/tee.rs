@@ -0,0 +1,94 @@
+use color_eyre::Report;
+use solp::api::Solution;
+use solp::Consume;
+use std::time::Duration;
+
+/// Passes every solution parsed and every failure to both consumers
+/// so that the single scan feeds several outputs
+pub struct Tee<'a> {
+    first: &'a mut dyn Consume,
+    second: &'a mut dyn Consume,
+}
+
+impl<'a> Tee<'a> {
+    #[must_use]
+    pub fn new(first: &'a mut dyn Consume, second: &'a mut dyn Consume) -> Self {
+        Self { first, second }
+    }
+}
+
+impl Consume for Tee<'_> {
+    fn ok(&mut self, solution: &Solution) {
+        self.first.ok(solution);
+        self.second.ok(solution);
+    }
+
+    fn err(&self, path: &str) {
+        self.first.err(path);
+        self.second.err(path);
+    }
+
+    fn err_with(&self, path: &str, error: &Report) {
+        self.first.err_with(path, error);
+        self.second.err_with(path, error);
+    }
+
+    fn should_stop(&self) -> bool {
+        self.first.should_stop() || self.second.should_stop()
+    }
+
+    fn timing(&self, path: &str, elapsed: Duration) {
+        self.first.timing(path, elapsed);
+        self.second.timing(path, elapsed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::info::Info;
+    use crate::json::Json;
+    use crate::StatsJson;
+    use std::fs;
+
+    #[test]
+    fn json_file_matches_table_data() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION).unwrap();
+        let mut info = Info::new();
+        let mut json = Json::new(false);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.json");
+
+        // Act
+        Tee::new(&mut info, &mut json).ok(&solution);
+        fs::write(&path, json.to_string()).unwrap();
+
+        // Assert
+        let written = fs::read_to_string(&path).unwrap();
+        let deserialized = serde_json::from_str::<solp::api::Solution>(&written).unwrap();
+        let stats = serde_json::from_str::<serde_json::Value>(&info.stats_json()).unwrap();
+        assert_eq!(1, stats["solutions"]);
+        assert_eq!(stats["projects"], deserialized.iterate_projects().count());
+    }
+
+    const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "folder", "folder", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+}
@@ -0,0 +1,87 @@
+use std::cell::RefCell;
+use std::io::Write;
+use std::time::Duration;
+
+use color_eyre::Report;
+use solp::api::Solution;
+use solp::Consume;
+
+/// Writes the time spent on parsing every solution next to its path into the writer specified
+/// and passes all results further to the consumer wrapped
+pub struct TimeEach<'a, W: Write> {
+    consumer: &'a mut dyn Consume,
+    out: RefCell<W>,
+}
+
+impl<'a, W: Write> TimeEach<'a, W> {
+    #[must_use]
+    pub fn new(consumer: &'a mut dyn Consume, out: W) -> Self {
+        Self {
+            consumer,
+            out: RefCell::new(out),
+        }
+    }
+}
+
+impl<W: Write> Consume for TimeEach<'_, W> {
+    fn ok(&mut self, solution: &Solution) {
+        self.consumer.ok(solution);
+    }
+
+    fn err(&self, path: &str) {
+        self.consumer.err(path);
+    }
+
+    fn err_with(&self, path: &str, error: &Report) {
+        self.consumer.err_with(path, error);
+    }
+
+    fn should_stop(&self) -> bool {
+        self.consumer.should_stop()
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn timing(&self, path: &str, elapsed: Duration) {
+        let elapsed = Duration::from_micros(elapsed.as_micros() as u64);
+        let _ = writeln!(
+            self.out.borrow_mut(),
+            " {path} {}",
+            humantime::format_duration(elapsed)
+        );
+        self.consumer.timing(path, elapsed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::SINGLE_PROJECT_SOLUTION;
+    use crate::stats::Stats;
+    use crate::StatsJson;
+    use std::fs;
+
+    #[test]
+    fn time_each_keeps_scanned_count() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("a.sln"), SINGLE_PROJECT_SOLUTION).unwrap();
+        fs::write(dir.path().join("b.sln"), SINGLE_PROJECT_SOLUTION).unwrap();
+        let path = dir.path().to_str().unwrap();
+        let mut stats = Stats::new();
+        let mut timed_stats = Stats::new();
+        let mut out = vec![];
+
+        // Act
+        let scanned = solp::parse_dir(path, "sln", &mut stats);
+        let timed_scanned =
+            solp::parse_dir(path, "sln", &mut TimeEach::new(&mut timed_stats, &mut out));
+
+        // Assert
+        assert_eq!(2, scanned);
+        assert_eq!(scanned, timed_scanned);
+        assert_eq!(stats.stats_json(), timed_stats.stats_json());
+        let written = String::from_utf8(out).unwrap();
+        assert_eq!(2, written.lines().count());
+        assert!(written.lines().all(|line| line.contains(".sln ")));
+    }
+}
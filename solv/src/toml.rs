@@ -0,0 +1,162 @@
+use std::fmt::{self, Display};
+
+use crossterm::style::Stylize;
+use serde::Serialize;
+use solp::api::Solution;
+use solp::Consume;
+
+use crate::StatsJson;
+
+/// Converts solutions into TOML. Because TOML document cannot be an array
+/// all solutions are emitted as `[[solutions]]` array of tables,
+/// their projects as `[[solutions.projects]]` and project configurations
+/// as `[[solutions.projects.configurations]]`. Fields without value are omitted
+pub struct Toml {
+    solutions: Vec<::toml::Value>,
+    not_converted: usize,
+}
+
+impl Toml {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            solutions: vec![],
+            not_converted: 0,
+        }
+    }
+
+    /// Converts value into TOML. Values that TOML cannot represent are reported into stderr and counted
+    fn add(&mut self, path: &str, value: &impl Serialize) {
+        match ::toml::Value::try_from(value) {
+            Ok(value) => self.solutions.push(value),
+            Err(e) => {
+                self.not_converted += 1;
+                eprintln!(
+                    "{path} {}: {e}",
+                    "cannot be converted into TOML".dark_red().bold()
+                );
+            }
+        }
+    }
+}
+
+impl Default for Toml {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Consume for Toml {
+    fn ok(&mut self, solution: &Solution) {
+        self.add(solution.path, solution);
+    }
+
+    fn err(&self, _path: &str) {}
+}
+
+impl StatsJson for Toml {
+    fn stats_json(&self) -> String {
+        serde_json::json!({
+            "solutions": self.solutions.len(),
+            "not_converted": self.not_converted,
+        })
+        .to_string()
+    }
+}
+
+impl Display for Toml {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut document = ::toml::Table::new();
+        document.insert(
+            "solutions".to_owned(),
+            ::toml::Value::Array(self.solutions.clone()),
+        );
+        let serialized = ::toml::to_string(&document).map_err(|_| fmt::Error)?;
+        write!(f, "{serialized}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION).unwrap();
+        let mut toml = Toml::new();
+
+        // Act
+        toml.ok(&solution);
+
+        // Assert
+        let s = format!("{toml}");
+        let deserialized = ::toml::from_str::<::toml::Table>(&s).unwrap();
+        let solutions = deserialized["solutions"].as_array().unwrap();
+        assert_eq!(1, solutions.len());
+        assert_eq!(
+            serde_json::to_value(&solution).unwrap(),
+            serde_json::to_value(&solutions[0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn several_solutions() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION).unwrap();
+        let mut toml = Toml::new();
+
+        // Act
+        toml.ok(&solution);
+        toml.ok(&solution);
+
+        // Assert
+        let s = format!("{toml}");
+        let deserialized = ::toml::from_str::<::toml::Table>(&s).unwrap();
+        assert_eq!(2, deserialized["solutions"].as_array().unwrap().len());
+        assert_eq!(r#"{"not_converted":0,"solutions":2}"#, toml.stats_json());
+    }
+
+    #[test]
+    fn not_convertible_value_counted() {
+        // Arrange
+        let mut toml = Toml::new();
+        // TOML table keys can only be strings
+        let value = std::collections::BTreeMap::from([(1, 1)]);
+
+        // Act
+        toml.add("s.sln", &value);
+
+        // Assert
+        assert_eq!(r#"{"not_converted":1,"solutions":0}"#, toml.stats_json());
+    }
+
+    const SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+VisualStudioVersion = 17.0.31903.59
+MinimumVisualStudioVersion = 10.0.40219.1
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+	ProjectSection(ProjectDependencies) = postProject
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {78965571-A6C2-4161-95B1-813B46610EA7}
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+}
@@ -1,5 +1,6 @@
 use comfy_table::{presets, Attribute, Cell, ContentArrangement, Row, Table, TableComponent};
 use crossterm::style::{style, Color, Stylize};
+use std::path::Path;
 
 #[must_use]
 pub fn new_table() -> Table {
@@ -56,21 +57,21 @@ pub fn create_one_column_table<I: ExactSizeIterator<Item = S>, S: ToString>(
 }
 
 #[must_use]
-pub fn create_solution_table(path: &str) -> Table {
+pub fn create_solution_table(path: &str, base: Option<&Path>) -> Table {
     let mut table = new_table();
-    table.set_header([Cell::new(path).add_attribute(Attribute::Bold).fg(
-        comfy_table::Color::Rgb {
+    table.set_header([Cell::new(crate::relative_to(path, base))
+        .add_attribute(Attribute::Bold)
+        .fg(comfy_table::Color::Rgb {
             r: 0xAA,
             g: 0xAA,
             b: 0xAA,
-        },
-    )]);
+        })]);
     table.set_style(TableComponent::HeaderLines, ' ');
     table
 }
 
-pub fn print_solution_path(path: &str) {
-    let path = style(path)
+pub fn print_solution_path(path: &str, base: Option<&Path>) {
+    let path = style(crate::relative_to(path, base))
         .with(Color::Rgb {
             r: 0xAA,
             g: 0xAA,
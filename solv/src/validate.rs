@@ -1,42 +1,209 @@
+use crate::baseline::Baseline;
 use crate::error::Collector;
-use crate::{calculate_percent, ux, Consume};
+use crate::{calculate_percent, loader, ux, Consume, StatsJson};
 use comfy_table::{Attribute, Cell};
 use crossterm::style::Stylize;
 use itertools::Itertools;
 use num_format::{Locale, ToFormattedString};
-use petgraph::algo::DfsSpace;
-use petgraph::prelude::DiGraphMap;
-use solp::api::{Solution, SolutionConfiguration};
-use std::cell::RefCell;
-use std::collections::{BTreeSet, HashMap};
+use regex::Regex;
+use serde::Serialize;
+use solp::api::{
+    normalize_guid, PathResolution, Project, ProjectConfiguration, Solution, SolutionConfiguration,
+    SourceControlBindings,
+};
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt;
 use std::fmt::Display;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
 trait Validator {
     /// does validation
     fn validate(&mut self, statistic: &mut Statistic);
     /// will return true if validation succeeded false otherwise
     fn validation_result(&self) -> bool;
-    /// prints validation results if any
-    fn print_results(&self);
+    /// prints validation results if any. Paths are printed relative to the base directory if it's specified
+    fn print_results(&self, base: Option<&Path>);
+    /// rule name used to identify validator's problems in baseline
+    fn rule(&self) -> &'static str;
+    /// identifiers of all problems found
+    fn problems(&self) -> Vec<String> {
+        self.involved()
+            .into_iter()
+            .map(|(problem, _)| problem)
+            .collect()
+    }
+    /// identifiers of all problems found along with projects each problem involves.
+    /// Problems of the whole solution like malformed header involve no projects
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)>;
+    /// removes problems which identifiers match predicate specified
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool);
+    /// short grep-able status used in one line output mode
+    fn status(&self) -> &'static str;
+}
+
+/// Solution project involved into a problem as validator knows it
+enum Involved<'a> {
+    Id(&'a str),
+    Name(&'a str),
+    Path(&'a Path),
 }
 
 pub struct Validate {
     show_only_problems: bool,
     errors: RefCell<Collector>,
     statistic: RefCell<Statistic>,
+    baseline: Option<Baseline>,
+    recorded: Option<Baseline>,
+    check_legacy_platforms: bool,
+    check_format_product: bool,
+    check_source_control: bool,
+    check_name_case: bool,
+    check_indentation: bool,
+    check_imports: bool,
+    strict_guids: bool,
+    follow_project_references: bool,
+    check_outside_references: bool,
+    check_reference_cycles: bool,
+    check_output_paths: bool,
+    check_solution_items: bool,
+    check_unknown_platforms: bool,
+    oneline: bool,
+    base: Option<PathBuf>,
+    max_problems: Option<usize>,
+    problem_solutions: usize,
+    refused: Cell<bool>,
+    ignored_projects: BTreeSet<String>,
+    min_projects: Option<usize>,
+    show_score: bool,
 }
 
-#[derive(Default)]
+#[derive(Default, Clone, Serialize)]
 struct Statistic {
     cycles: u64,
+    reference_cycles: u64,
     dangings: u64,
     not_found: u64,
     missings: u64,
+    unknown_platforms: u64,
+    same_path_guids: u64,
+    legacy_platforms: u64,
+    format_mismatches: u64,
+    source_control_bindings: u64,
+    name_case_collisions: u64,
+    space_indentations: u64,
+    outside_references: u64,
+    shared_output_paths: u64,
+    absolute_paths: u64,
+    configuration_conflicts: u64,
+    diverging_build_configurations: u64,
+    folder_dependencies: u64,
+    missing_solution_items: u64,
+    malformed_headers: u64,
+    orphaned_nestings: u64,
+    empty_projects: u64,
+    duplicate_dependencies: u64,
+    transitive_references: u64,
+    missing_imports: u64,
+    non_canonical_guids: u64,
     parsed: u64,
     not_parsed: u64,
     total: u64,
+    projects: u64,
+    never_built_projects: u64,
+    deploy_only_projects: u64,
+}
+
+impl Statistic {
+    fn add(&mut self, other: &Statistic) {
+        self.cycles += other.cycles;
+        self.reference_cycles += other.reference_cycles;
+        self.dangings += other.dangings;
+        self.not_found += other.not_found;
+        self.missings += other.missings;
+        self.unknown_platforms += other.unknown_platforms;
+        self.same_path_guids += other.same_path_guids;
+        self.legacy_platforms += other.legacy_platforms;
+        self.format_mismatches += other.format_mismatches;
+        self.source_control_bindings += other.source_control_bindings;
+        self.name_case_collisions += other.name_case_collisions;
+        self.space_indentations += other.space_indentations;
+        self.outside_references += other.outside_references;
+        self.shared_output_paths += other.shared_output_paths;
+        self.absolute_paths += other.absolute_paths;
+        self.configuration_conflicts += other.configuration_conflicts;
+        self.diverging_build_configurations += other.diverging_build_configurations;
+        self.folder_dependencies += other.folder_dependencies;
+        self.missing_solution_items += other.missing_solution_items;
+        self.malformed_headers += other.malformed_headers;
+        self.orphaned_nestings += other.orphaned_nestings;
+        self.empty_projects += other.empty_projects;
+        self.duplicate_dependencies += other.duplicate_dependencies;
+        self.transitive_references += other.transitive_references;
+        self.missing_imports += other.missing_imports;
+        self.non_canonical_guids += other.non_canonical_guids;
+    }
+}
+
+impl Statistic {
+    /// Counts solution's projects that aren't built in any solution configuration
+    /// and ones that are deployed without being built
+    fn count_projects(&mut self, solution: &Solution) {
+        for p in solution.iterate_projects() {
+            self.projects += 1;
+            let configurations = p.configurations.iter().flatten();
+            if !configurations
+                .clone()
+                .any(ProjectConfiguration::is_buildable)
+            {
+                self.never_built_projects += 1;
+            }
+            if configurations.any(|c| c.is_deployable() && !c.is_buildable()) {
+                self.deploy_only_projects += 1;
+            }
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn projects_table(&self) -> comfy_table::Table {
+        let mut table = ux::new_table();
+
+        table.set_header([
+            Cell::new("Category").add_attribute(Attribute::Bold),
+            Cell::new("# Projects").add_attribute(Attribute::Bold),
+            Cell::new("%").add_attribute(Attribute::Bold),
+        ]);
+
+        let never_built_percent =
+            calculate_percent(self.never_built_projects as i32, self.projects as i32);
+        let deploy_only_percent =
+            calculate_percent(self.deploy_only_projects as i32, self.projects as i32);
+        let projects_percent = calculate_percent(self.projects as i32, self.projects as i32);
+
+        table.add_row([
+            Cell::new("Never built in any configuration"),
+            Cell::new(self.never_built_projects.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{never_built_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Deployed without being built"),
+            Cell::new(self.deploy_only_projects.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{deploy_only_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row(["", "", ""]);
+        table.add_row([
+            Cell::new("Total"),
+            Cell::new(self.projects.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{projects_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+        table
+    }
 }
 
 impl Display for Statistic {
@@ -53,9 +220,55 @@ impl Display for Statistic {
         ]);
 
         let cycles_percent = calculate_percent(self.cycles as i32, self.total as i32);
+        let reference_cycles_percent =
+            calculate_percent(self.reference_cycles as i32, self.total as i32);
         let missings_percent = calculate_percent(self.missings as i32, self.total as i32);
+        let unknown_platforms_percent =
+            calculate_percent(self.unknown_platforms as i32, self.total as i32);
         let dangings_percent = calculate_percent(self.dangings as i32, self.total as i32);
         let not_found_percent = calculate_percent(self.not_found as i32, self.total as i32);
+        let same_path_guids_percent =
+            calculate_percent(self.same_path_guids as i32, self.total as i32);
+        let legacy_platforms_percent =
+            calculate_percent(self.legacy_platforms as i32, self.total as i32);
+        let format_mismatches_percent =
+            calculate_percent(self.format_mismatches as i32, self.total as i32);
+        let source_control_bindings_percent =
+            calculate_percent(self.source_control_bindings as i32, self.total as i32);
+        let name_case_collisions_percent =
+            calculate_percent(self.name_case_collisions as i32, self.total as i32);
+        let space_indentations_percent =
+            calculate_percent(self.space_indentations as i32, self.total as i32);
+        let outside_references_percent =
+            calculate_percent(self.outside_references as i32, self.total as i32);
+        let shared_output_paths_percent =
+            calculate_percent(self.shared_output_paths as i32, self.total as i32);
+        let absolute_paths_percent =
+            calculate_percent(self.absolute_paths as i32, self.total as i32);
+        let configuration_conflicts_percent =
+            calculate_percent(self.configuration_conflicts as i32, self.total as i32);
+        let diverging_build_configurations_percent = calculate_percent(
+            self.diverging_build_configurations as i32,
+            self.total as i32,
+        );
+        let folder_dependencies_percent =
+            calculate_percent(self.folder_dependencies as i32, self.total as i32);
+        let missing_solution_items_percent =
+            calculate_percent(self.missing_solution_items as i32, self.total as i32);
+        let malformed_headers_percent =
+            calculate_percent(self.malformed_headers as i32, self.total as i32);
+        let orphaned_nestings_percent =
+            calculate_percent(self.orphaned_nestings as i32, self.total as i32);
+        let empty_projects_percent =
+            calculate_percent(self.empty_projects as i32, self.total as i32);
+        let duplicate_dependencies_percent =
+            calculate_percent(self.duplicate_dependencies as i32, self.total as i32);
+        let transitive_references_percent =
+            calculate_percent(self.transitive_references as i32, self.total as i32);
+        let missing_imports_percent =
+            calculate_percent(self.missing_imports as i32, self.total as i32);
+        let non_canonical_guids_percent =
+            calculate_percent(self.non_canonical_guids as i32, self.total as i32);
         let parsed_percent = calculate_percent(self.parsed as i32, self.total as i32);
         let not_parsed_percent = calculate_percent(self.not_parsed as i32, self.total as i32);
         let total_percent = calculate_percent(self.total as i32, self.total as i32);
@@ -74,6 +287,13 @@ impl Display for Statistic {
             Cell::new(format!("{cycles_percent:.2}%")).add_attribute(Attribute::Italic),
         ]);
 
+        table.add_row([
+            Cell::new("Contain project references cycles"),
+            Cell::new(self.reference_cycles.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{reference_cycles_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
         table.add_row([
             Cell::new("Contain project configurations outside solution's list"),
             Cell::new(self.missings.to_formatted_string(&Locale::en))
@@ -81,6 +301,13 @@ impl Display for Statistic {
             Cell::new(format!("{missings_percent:.2}%")).add_attribute(Attribute::Italic),
         ]);
 
+        table.add_row([
+            Cell::new("Contain project configurations for platforms outside solution's list"),
+            Cell::new(self.unknown_platforms.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{unknown_platforms_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
         table.add_row([
             Cell::new("Contain dangling project configurations"),
             Cell::new(self.dangings.to_formatted_string(&Locale::en))
@@ -95,6 +322,165 @@ impl Display for Statistic {
             Cell::new(format!("{not_found_percent:.2}%")).add_attribute(Attribute::Italic),
         ]);
 
+        table.add_row([
+            Cell::new("Contain projects referenced under several GUIDs"),
+            Cell::new(self.same_path_guids.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{same_path_guids_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain obsolete Mixed Platforms platform"),
+            Cell::new(self.legacy_platforms.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{legacy_platforms_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain format version that disagrees with product"),
+            Cell::new(self.format_mismatches.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{format_mismatches_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain legacy TFS source control bindings"),
+            Cell::new(
+                self.source_control_bindings
+                    .to_formatted_string(&Locale::en),
+            )
+            .add_attribute(Attribute::Italic),
+            Cell::new(format!("{source_control_bindings_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain project names differing only by case"),
+            Cell::new(self.name_case_collisions.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{name_case_collisions_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Indented using spaces instead of tabs"),
+            Cell::new(self.space_indentations.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{space_indentations_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain references to projects outside solution"),
+            Cell::new(self.outside_references.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{outside_references_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain projects sharing output path"),
+            Cell::new(self.shared_output_paths.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{shared_output_paths_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain absolute project paths"),
+            Cell::new(self.absolute_paths.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{absolute_paths_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain conflicting project configurations"),
+            Cell::new(
+                self.configuration_conflicts
+                    .to_formatted_string(&Locale::en),
+            )
+            .add_attribute(Attribute::Italic),
+            Cell::new(format!("{configuration_conflicts_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain projects building other than active configurations"),
+            Cell::new(
+                self.diverging_build_configurations
+                    .to_formatted_string(&Locale::en),
+            )
+            .add_attribute(Attribute::Italic),
+            Cell::new(format!("{diverging_build_configurations_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain projects depending on solution folders"),
+            Cell::new(self.folder_dependencies.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{folder_dependencies_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain missing solution items"),
+            Cell::new(self.missing_solution_items.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{missing_solution_items_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Have malformed header"),
+            Cell::new(self.malformed_headers.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{malformed_headers_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain orphaned nesting entries"),
+            Cell::new(self.orphaned_nestings.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{orphaned_nestings_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain projects with empty name or path"),
+            Cell::new(self.empty_projects.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{empty_projects_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain duplicate project dependencies"),
+            Cell::new(self.duplicate_dependencies.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{duplicate_dependencies_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Transitively reference projects outside solution"),
+            Cell::new(self.transitive_references.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{transitive_references_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain projects importing missing files"),
+            Cell::new(self.missing_imports.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{missing_imports_percent:.2}%")).add_attribute(Attribute::Italic),
+        ]);
+
+        table.add_row([
+            Cell::new("Contain GUIDs in non canonical form"),
+            Cell::new(self.non_canonical_guids.to_formatted_string(&Locale::en))
+                .add_attribute(Attribute::Italic),
+            Cell::new(format!("{non_canonical_guids_percent:.2}%"))
+                .add_attribute(Attribute::Italic),
+        ]);
+
         table.add_row([
             Cell::new("Not parsed"),
             Cell::new(self.not_parsed.to_formatted_string(&Locale::en))
@@ -109,7 +495,8 @@ impl Display for Statistic {
             Cell::new(format!("{total_percent:.2}%")).add_attribute(Attribute::Italic),
         ]);
 
-        writeln!(f, "{table}")
+        writeln!(f, "{table}")?;
+        writeln!(f, "{}", self.projects_table())
     }
 }
 
@@ -120,33 +507,324 @@ impl Validate {
             show_only_problems,
             errors: RefCell::new(Collector::new()),
             statistic: RefCell::new(Statistic::default()),
+            baseline: None,
+            recorded: None,
+            check_legacy_platforms: false,
+            check_format_product: false,
+            check_source_control: false,
+            check_name_case: false,
+            check_indentation: false,
+            check_imports: false,
+            strict_guids: false,
+            follow_project_references: false,
+            check_outside_references: false,
+            check_reference_cycles: false,
+            check_output_paths: false,
+            check_solution_items: false,
+            check_unknown_platforms: false,
+            oneline: false,
+            base: None,
+            max_problems: None,
+            problem_solutions: 0,
+            refused: Cell::new(false),
+            ignored_projects: BTreeSet::new(),
+            min_projects: None,
+            show_score: false,
         }
     }
-}
 
-impl Consume for Validate {
-    fn ok(&mut self, solution: &Solution) {
-        let mut validators: [Box<dyn Validator>; 4] = [
+    /// Enables one status line per solution output instead of tables
+    #[must_use]
+    pub fn with_oneline(mut self, oneline: bool) -> Self {
+        self.oneline = oneline;
+        self
+    }
+
+    /// Prints paths relative to the base directory specified
+    #[must_use]
+    pub fn with_base(mut self, base: Option<PathBuf>) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Creates validators to run against solution in their severity order
+    fn validators<'a>(&self, solution: &'a Solution<'a>) -> Vec<Box<dyn Validator + 'a>> {
+        let mut validators: Vec<Box<dyn Validator + 'a>> = vec![
             Box::new(Cycles::new(solution)),
             Box::new(Danglings::new(solution)),
             Box::new(NotFouund::new(solution)),
             Box::new(Missings::new(solution)),
+            Box::new(SamePathGuids::new(solution)),
+            Box::new(AbsolutePaths::new(solution)),
+            Box::new(ConflictingConfigurations::new(solution)),
+            Box::new(DivergingBuildConfigurations::new(solution)),
+            Box::new(FolderDependencies::new(solution)),
+            Box::new(MalformedHeader::new(solution)),
+            Box::new(OrphanedNesting::new(solution)),
+            Box::new(EmptyProjects::new(solution)),
+            Box::new(DuplicateDependencies::new(solution)),
         ];
+        if self.check_legacy_platforms {
+            validators.push(Box::new(LegacyPlatforms::new(solution)));
+        }
+        if self.check_format_product {
+            validators.push(Box::new(FormatProduct::new(solution)));
+        }
+        if self.check_source_control {
+            validators.push(Box::new(SourceControl::new(solution)));
+        }
+        if self.check_name_case {
+            validators.push(Box::new(NameCase::new(solution)));
+        }
+        if self.check_indentation {
+            validators.push(Box::new(SpaceIndentation::new(solution)));
+        }
+        if self.check_imports {
+            validators.push(Box::new(MissingImports::new(solution)));
+        }
+        if self.strict_guids {
+            validators.push(Box::new(NonCanonicalGuids::new(solution)));
+        }
+        if self.follow_project_references {
+            validators.push(Box::new(TransitiveReferences::new(solution)));
+        }
+        if self.check_outside_references {
+            validators.push(Box::new(OutsideReferences::new(solution)));
+        }
+        if self.check_reference_cycles {
+            validators.push(Box::new(ReferenceCycles::new(solution)));
+        }
+        if self.check_output_paths {
+            validators.push(Box::new(SharedOutputPaths::new(solution)));
+        }
+        if self.check_solution_items {
+            validators.push(Box::new(MissingSolutionItems::new(solution)));
+        }
+        if self.check_unknown_platforms {
+            validators.push(Box::new(UnknownPlatforms::new(solution)));
+        }
+        validators
+    }
+
+    /// Enables opt-in check of the obsolete Mixed Platforms platform
+    #[must_use]
+    pub fn with_legacy_platforms_check(mut self, check: bool) -> Self {
+        self.check_legacy_platforms = check;
+        self
+    }
+
+    /// Enables opt-in cross check of the format version against the product comment
+    #[must_use]
+    pub fn with_format_product_check(mut self, check: bool) -> Self {
+        self.check_format_product = check;
+        self
+    }
+
+    /// Enables opt-in check of leftover TFS source control bindings
+    #[must_use]
+    pub fn with_source_control_check(mut self, check: bool) -> Self {
+        self.check_source_control = check;
+        self
+    }
+
+    /// Enables opt-in check of project names that differ only by case
+    #[must_use]
+    pub fn with_name_case_check(mut self, check: bool) -> Self {
+        self.check_name_case = check;
+        self
+    }
+
+    /// Enables opt-in check of GUIDs that aren't in canonical uppercase form with braces and hyphens
+    #[must_use]
+    pub fn with_strict_guids(mut self, strict: bool) -> Self {
+        self.strict_guids = strict;
+        self
+    }
+
+    /// Enables opt-in check of sections indented using spaces instead of tabs.
+    /// Solutions must be parsed with indentation detection enabled in [`solp::ParseOptions`]
+    #[must_use]
+    pub fn with_indentation_check(mut self, check: bool) -> Self {
+        self.check_indentation = check;
+        self
+    }
+
+    /// Enables opt-in loading of project files to find imports of not existing files
+    #[must_use]
+    pub fn with_imports_check(mut self, check: bool) -> Self {
+        self.check_imports = check;
+        self
+    }
+
+    /// Enables opt-in loading of all project files reachable through `ProjectReference` items
+    /// to find projects that are built as part of solution but aren't included into it
+    #[must_use]
+    pub fn with_follow_project_references(mut self, follow: bool) -> Self {
+        self.follow_project_references = follow;
+        self
+    }
+
+    /// Enables opt-in loading of project files to find project references to projects outside of solution
+    #[must_use]
+    pub fn with_outside_references_check(mut self, check: bool) -> Self {
+        self.check_outside_references = check;
+        self
+    }
+
+    /// Enables opt-in loading of project files to find cycles made by `ProjectReference` items
+    #[must_use]
+    pub fn with_reference_cycles_check(mut self, check: bool) -> Self {
+        self.check_reference_cycles = check;
+        self
+    }
+
+    /// Enables opt-in loading of project files to find projects that build into the same output directory
+    #[must_use]
+    pub fn with_output_paths_check(mut self, check: bool) -> Self {
+        self.check_output_paths = check;
+        self
+    }
+
+    /// Enables opt-in check of solution items that don't exist on disk
+    #[must_use]
+    pub fn with_solution_items_check(mut self, check: bool) -> Self {
+        self.check_solution_items = check;
+        self
+    }
+
+    /// Enables opt-in per platform report of project configurations that missings check
+    /// already reports per configuration|platform pair
+    #[must_use]
+    pub fn with_unknown_platforms_check(mut self, check: bool) -> Self {
+        self.check_unknown_platforms = check;
+        self
+    }
+
+    /// Sets known-acceptable problems that must be neither reported nor counted
+    #[must_use]
+    pub fn with_baseline(mut self, baseline: Option<Baseline>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Enables recording of all problems found so they can be saved as a baseline later
+    #[must_use]
+    pub fn with_baseline_recording(mut self, record: bool) -> Self {
+        self.recorded = record.then(Baseline::new);
+        self
+    }
+
+    /// All problems found if recording enabled
+    #[must_use]
+    pub fn recorded_baseline(&self) -> Option<&Baseline> {
+        self.recorded.as_ref()
+    }
+
+    /// Stops scanning after the number of solutions with problems specified
+    #[must_use]
+    pub fn with_max_problems(mut self, max_problems: Option<usize>) -> Self {
+        self.max_problems = max_problems;
+        self
+    }
+
+    /// Suppresses problems that involve projects with ids specified.
+    /// Project is involved if problem is found in it or refers to it by id, name or path
+    #[must_use]
+    pub fn with_ignored_projects(mut self, ids: &[String]) -> Self {
+        self.ignored_projects = ids.iter().map(|id| normalize_guid(id)).collect();
+        self
+    }
+
+    /// Skips solutions with less than the number of projects specified. Solution folders aren't counted
+    #[must_use]
+    pub fn with_min_projects(mut self, min_projects: Option<usize>) -> Self {
+        self.min_projects = min_projects;
+        self
+    }
+
+    /// Prints 0-100 health score of every solution, in one line mode too. See [`health_score`] for details
+    #[must_use]
+    pub fn with_score(mut self, show_score: bool) -> Self {
+        self.show_score = show_score;
+        self
+    }
+
+    /// Whether scanning was stopped because of solutions with problems limit reached
+    /// i.e. a further solution was refused to be scanned
+    #[must_use]
+    pub fn truncated(&self) -> bool {
+        self.refused.get()
+    }
+
+    /// Whether any solution failed to be read or parsed during the scan
+    #[must_use]
+    pub fn parse_errors_found(&self) -> bool {
+        self.errors.borrow().count() > 0
+    }
+}
+
+impl Consume for Validate {
+    fn ok(&mut self, solution: &Solution) {
+        if !crate::has_min_projects(solution, self.min_projects) {
+            return;
+        }
+        let mut validators = self.validators(solution);
+        let ignored = IgnoredProjects::new(solution, &self.ignored_projects);
+        // Baseline keeps paths relative to the base directory so that it's valid on every machine
+        let solution_path = crate::relative_to(solution.path, self.base.as_deref());
 
         let valid_solution = validators.iter_mut().fold(true, |mut res, validator| {
-            validator.validate(&mut self.statistic.borrow_mut());
+            let mut statistic = Statistic::default();
+            validator.validate(&mut statistic);
+            let rule = validator.rule();
+            if let Some(recorded) = self.recorded.as_mut() {
+                for problem in validator.problems() {
+                    recorded.add(&solution_path, rule, &problem);
+                }
+            }
+            if let Some(baseline) = &self.baseline {
+                validator.suppress(&|problem| baseline.contains(&solution_path, rule, problem));
+            }
+            if !ignored.is_empty() {
+                let suppressed = validator
+                    .involved()
+                    .into_iter()
+                    .filter(|(_, projects)| projects.iter().any(|p| ignored.contains(p)))
+                    .map(|(problem, _)| problem)
+                    .collect::<HashSet<String>>();
+                validator.suppress(&|problem| suppressed.contains(problem));
+            }
+            if !validator.validation_result() {
+                self.statistic.borrow_mut().add(&statistic);
+            }
             res &= validator.validation_result();
             res
         });
+        self.statistic.borrow_mut().total += 1;
+        self.statistic.borrow_mut().count_projects(solution);
+        if !valid_solution {
+            self.problem_solutions += 1;
+        }
+
+        if self.oneline {
+            if !self.show_only_problems || !valid_solution {
+                let path = crate::relative_to(solution.path, self.base.as_deref());
+                println!("{}", status_line(&validators, &path, self.show_score));
+            }
+            return;
+        }
 
         if !self.show_only_problems || !valid_solution {
-            ux::print_solution_path(solution.path);
+            ux::print_solution_path(solution.path, self.base.as_deref());
         }
         for v in &validators {
             if !v.validation_result() {
-                v.print_results();
+                v.print_results(self.base.as_deref());
             }
         }
+        if self.show_score && (!self.show_only_problems || !valid_solution) {
+            print_score(solution_score(&validators));
+        }
 
         if !self.show_only_problems && valid_solution {
             println!(
@@ -158,36 +836,257 @@ impl Consume for Validate {
         if !valid_solution {
             println!();
         }
-        self.statistic.borrow_mut().total += 1;
     }
 
     fn err(&self, path: &str) {
+        if self.oneline {
+            println!("{}", not_parsed_line(path, self.base.as_deref()));
+        }
         self.errors.borrow_mut().add_path(path);
     }
-}
 
-impl Display for Validate {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut statistic = self.statistic.borrow_mut();
-        statistic.not_parsed = self.errors.borrow().count();
-        statistic.parsed = statistic.total;
-        statistic.total += statistic.not_parsed;
-        write!(f, "{statistic}")?;
-        if self.errors.borrow().count() > 0 {
-            write!(f, "{}", self.errors.borrow())
-        } else {
-            Ok(())
+    fn should_stop(&self) -> bool {
+        let stop = self
+            .max_problems
+            .is_some_and(|max| self.problem_solutions >= max);
+        if stop {
+            self.refused.set(true);
         }
+        stop
     }
 }
 
-struct NotFouund<'a> {
-    solution: &'a Solution<'a>,
-    bad_paths: BTreeSet<PathBuf>,
+/// Ids, names and paths of the projects which problems are ignored
+struct IgnoredProjects<'a> {
+    ids: &'a BTreeSet<String>,
+    names: BTreeSet<&'a str>,
+    paths: BTreeSet<String>,
 }
 
-impl<'a> NotFouund<'a> {
-    pub fn new(solution: &'a Solution<'a>) -> Self {
+impl<'a> IgnoredProjects<'a> {
+    fn new(solution: &'a Solution<'a>, ids: &'a BTreeSet<String>) -> Self {
+        let dir = crate::parent_of(solution.path);
+        let projects = solution
+            .projects
+            .iter()
+            .filter(|p| ids.contains(&p.guid_normalized()))
+            .collect::<Vec<_>>();
+        Self {
+            ids,
+            names: projects.iter().map(|p| p.name).collect(),
+            paths: projects
+                .iter()
+                .filter_map(|p| loader::project_path(dir, p))
+                .map(|p| loader::normalize_path(&p))
+                .map(|p| normalize_project_path(&p.to_string_lossy()))
+                .collect(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Whether project involved into a problem is one of the ignored projects
+    fn contains(&self, project: &Involved) -> bool {
+        match project {
+            Involved::Id(id) => self.ids.contains(&normalize_guid(id)),
+            Involved::Name(name) => self.names.contains(name),
+            Involved::Path(path) => {
+                let path = loader::normalize_path(path);
+                self.paths
+                    .contains(&normalize_project_path(&path.to_string_lossy()))
+            }
+        }
+    }
+}
+
+/// Health score deductions per failed rule. Problems that break builds or corrupt solution
+/// weigh the most, configuration problems less and hygiene ones the least.
+/// Rules that aren't listed deduct [`DEFAULT_DEDUCTION`]
+const DEDUCTIONS: [(&str, u8); 13] = [
+    ("cycles", 30),
+    ("reference-cycles", 25),
+    ("not-found", 20),
+    ("malformed-header", 20),
+    ("danglings", 15),
+    ("missings", 15),
+    ("same-path-guids", 15),
+    ("empty-projects", 15),
+    ("unknown-platforms", 10),
+    ("conflicting-configurations", 10),
+    ("shared-output-paths", 10),
+    ("outside-references", 10),
+    ("missing-imports", 10),
+];
+const DEFAULT_DEDUCTION: u8 = 5;
+const MAX_SCORE: u8 = 100;
+const GOOD_SCORE: u8 = 80;
+const FAIR_SCORE: u8 = 50;
+
+/// Calculates solution health score from 0 to 100 using the rules solution fails.
+/// Every failed rule deducts its weight once regardless of the number of its problems
+#[must_use]
+pub fn health_score<'r>(failed_rules: impl IntoIterator<Item = &'r str>) -> u8 {
+    failed_rules.into_iter().fold(MAX_SCORE, |score, rule| {
+        let deduction = DEDUCTIONS
+            .iter()
+            .find(|(r, _)| *r == rule)
+            .map_or(DEFAULT_DEDUCTION, |(_, d)| *d);
+        score.saturating_sub(deduction)
+    })
+}
+
+fn solution_score(validators: &[Box<dyn Validator + '_>]) -> u8 {
+    health_score(
+        validators
+            .iter()
+            .filter(|v| !v.validation_result())
+            .map(|v| v.rule()),
+    )
+}
+
+fn print_score(score: u8) {
+    let text = format!("Health score: {score}");
+    let text = if score >= GOOD_SCORE {
+        text.dark_green()
+    } else if score >= FAIR_SCORE {
+        text.dark_yellow()
+    } else {
+        text.dark_red()
+    };
+    println!("   {}", text.bold());
+}
+
+const STATUS_OK: &str = "OK";
+const STATUS_NOT_PARSED: &str = "NOTPARSED";
+
+/// Makes `STATUS path` line or `STATUS score path` one if score requested.
+/// Path goes last so that paths with spaces don't break the line parsing
+fn status_line(validators: &[Box<dyn Validator + '_>], path: &str, show_score: bool) -> String {
+    let status = validators
+        .iter()
+        .find(|v| !v.validation_result())
+        .map_or(STATUS_OK, |v| v.status());
+    if show_score {
+        format!("{status} {} {path}", solution_score(validators))
+    } else {
+        format!("{status} {path}")
+    }
+}
+
+/// Makes `NOTPARSED path` line of the solution that failed to be read or parsed
+fn not_parsed_line(path: &str, base: Option<&Path>) -> String {
+    format!("{STATUS_NOT_PARSED} {}", crate::relative_to(path, base))
+}
+
+/// Makes path printed by validators relative to the base directory if it's specified
+fn display_path(path: &Path, base: Option<&Path>) -> String {
+    crate::relative_to(&path.to_string_lossy(), base)
+}
+
+impl Validate {
+    /// Final statistic that includes solutions that weren't parsed
+    fn summary(&self) -> Statistic {
+        let mut statistic = self.statistic.borrow().clone();
+        statistic.not_parsed = self.errors.borrow().count();
+        statistic.parsed = statistic.total;
+        statistic.total += statistic.not_parsed;
+        statistic
+    }
+}
+
+impl StatsJson for Validate {
+    fn stats_json(&self) -> String {
+        serde_json::to_string(&self.summary()).unwrap_or_default()
+    }
+}
+
+impl Display for Validate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.oneline {
+            return Ok(());
+        }
+        let statistic = self.summary();
+        write!(f, "{statistic}")?;
+        if self.truncated() {
+            writeln!(
+                f,
+                " {}",
+                format!(
+                    "Scan was truncated after {} solutions with problems found",
+                    self.problem_solutions
+                )
+                .dark_yellow()
+                .bold()
+            )?;
+        }
+        if self.errors.borrow().count() > 0 {
+            write!(f, "{}", self.errors.borrow())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Returns all validation rules with explanations of what they check,
+/// why it matters and how to fix the problems found
+#[must_use]
+pub fn explanations() -> Vec<(&'static str, &'static str)> {
+    vec![
+        ("not-found", NotFouund::EXPLANATION),
+        ("danglings", Danglings::EXPLANATION),
+        ("missings", Missings::EXPLANATION),
+        ("unknown-platforms", UnknownPlatforms::EXPLANATION),
+        ("cycles", Cycles::EXPLANATION),
+        ("reference-cycles", ReferenceCycles::EXPLANATION),
+        ("same-path-guids", SamePathGuids::EXPLANATION),
+        ("outside-references", OutsideReferences::EXPLANATION),
+        ("shared-output-paths", SharedOutputPaths::EXPLANATION),
+        ("absolute-paths", AbsolutePaths::EXPLANATION),
+        (
+            "conflicting-configurations",
+            ConflictingConfigurations::EXPLANATION,
+        ),
+        (
+            "diverging-build-configurations",
+            DivergingBuildConfigurations::EXPLANATION,
+        ),
+        ("folder-dependencies", FolderDependencies::EXPLANATION),
+        ("missing-solution-items", MissingSolutionItems::EXPLANATION),
+        ("malformed-header", MalformedHeader::EXPLANATION),
+        ("orphaned-nesting", OrphanedNesting::EXPLANATION),
+        ("empty-projects", EmptyProjects::EXPLANATION),
+        ("duplicate-dependencies", DuplicateDependencies::EXPLANATION),
+        ("transitive-references", TransitiveReferences::EXPLANATION),
+        ("legacy-platforms", LegacyPlatforms::EXPLANATION),
+        ("format-product", FormatProduct::EXPLANATION),
+        ("source-control", SourceControl::EXPLANATION),
+        ("name-case", NameCase::EXPLANATION),
+        ("space-indentation", SpaceIndentation::EXPLANATION),
+        ("missing-imports", MissingImports::EXPLANATION),
+        ("non-canonical-guids", NonCanonicalGuids::EXPLANATION),
+    ]
+}
+
+/// Returns explanation of the validation rule specified if there is such a rule
+#[must_use]
+pub fn explain(rule: &str) -> Option<&'static str> {
+    explanations()
+        .into_iter()
+        .find(|(r, _)| *r == rule)
+        .map(|(_, explanation)| explanation)
+}
+
+struct NotFouund<'a> {
+    solution: &'a Solution<'a>,
+    bad_paths: BTreeSet<PathBuf>,
+}
+
+impl<'a> NotFouund<'a> {
+    const EXPLANATION: &'static str = "Solution references project files that don't exist on disk. Visual Studio shows such projects as unloaded and the build fails or silently skips them. Fix project paths or remove projects from the solution.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
         Self {
             solution,
             bad_paths: BTreeSet::new(),
@@ -201,13 +1100,9 @@ impl Validator for NotFouund<'_> {
         self.bad_paths = self
             .solution
             .iterate_projects_without_web_sites()
-            .filter_map(|p| crate::try_make_local_path(dir, p.path_or_uri))
-            .filter_map(|full_path| {
-                if full_path.canonicalize().is_ok() {
-                    None
-                } else {
-                    Some(full_path)
-                }
+            .filter_map(|p| match p.resolve_existing(dir) {
+                PathResolution::Missing(full_path) => Some(full_path),
+                _ => None,
             })
             .collect();
         if !self.validation_result() {
@@ -219,49 +1114,92 @@ impl Validator for NotFouund<'_> {
         self.bad_paths.is_empty()
     }
 
-    fn print_results(&self) {
-        let items: Vec<&str> = self
-            .bad_paths
-            .iter()
-            .filter_map(|p| p.as_path().to_str())
-            .collect();
+    fn print_results(&self, base: Option<&Path>) {
         ux::print_one_column_table(
             "Unexist project path",
             Some(comfy_table::Color::DarkYellow),
-            items.into_iter(),
+            self.bad_paths.iter().map(|p| display_path(p, base)),
         );
     }
+
+    fn rule(&self) -> &'static str {
+        "not-found"
+    }
+
+    fn status(&self) -> &'static str {
+        "NOTFOUND"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        let dir = crate::parent_of(self.solution.path);
+        self.bad_paths
+            .iter()
+            .map(|p| (solution_relative(dir, p), vec![Involved::Path(p)]))
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        let dir = crate::parent_of(self.solution.path);
+        self.bad_paths
+            .retain(|p| !suppressed(&solution_relative(dir, p)));
+    }
 }
 
 struct Danglings<'a> {
     solution: &'a Solution<'a>,
+    danglings: Vec<&'a str>,
 }
 
 impl<'a> Danglings<'a> {
+    const EXPLANATION: &'static str = "Solution has ProjectConfigurationPlatforms entries for project GUIDs that aren't declared by any Project block. They usually remain after a project was removed by hand or a merge and only bloat the file. Remove these lines or re-save the solution in Visual Studio.";
+
     pub fn new(solution: &'a Solution<'a>) -> Self {
-        Self { solution }
+        Self {
+            solution,
+            danglings: vec![],
+        }
     }
 }
 
 impl Validator for Danglings<'_> {
     fn validate(&mut self, statistic: &mut Statistic) {
+        if let Some(danglings) = &self.solution.dangling_project_configurations {
+            self.danglings = danglings.iter().map(String::as_str).collect();
+        }
         if !self.validation_result() {
             statistic.dangings += 1;
         }
     }
 
     fn validation_result(&self) -> bool {
-        self.solution.dangling_project_configurations.is_none()
+        self.danglings.is_empty()
     }
 
-    fn print_results(&self) {
-        if let Some(danglings) = &self.solution.dangling_project_configurations {
-            ux::print_one_column_table(
-                "Dangling project configurations that can be safely removed",
-                Some(comfy_table::Color::DarkYellow),
-                danglings.iter().map(std::string::String::as_str),
-            );
-        }
+    fn print_results(&self, _base: Option<&Path>) {
+        ux::print_one_column_table(
+            "Dangling project configurations that can be safely removed",
+            Some(comfy_table::Color::DarkYellow),
+            self.danglings.iter(),
+        );
+    }
+
+    fn rule(&self) -> &'static str {
+        "danglings"
+    }
+
+    fn status(&self) -> &'static str {
+        "DANGLING"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.danglings
+            .iter()
+            .map(|d| ((*d).to_owned(), vec![Involved::Id(d)]))
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.danglings.retain(|d| !suppressed(d));
     }
 }
 
@@ -271,6 +1209,8 @@ struct Missings<'a> {
 }
 
 impl<'a> Missings<'a> {
+    const EXPLANATION: &'static str = "Projects have configurations mapped to solution configuration|platform pairs that the SolutionConfigurationPlatforms section doesn't declare. Such configurations can never be selected. Add the pairs into the solution or remove them from projects.";
+
     pub fn new(solution: &'a Solution<'a>) -> Self {
         Self {
             solution,
@@ -324,7 +1264,7 @@ impl Validator for Missings<'_> {
         self.missings.is_empty()
     }
 
-    fn print_results(&self) {
+    fn print_results(&self, _base: Option<&Path>) {
         println!("  {}", "Solution contains project configurations that are outside solution's configuration|platform list:".dark_yellow().bold());
 
         let mut table = ux::new_table();
@@ -344,264 +1284,4243 @@ impl Validator for Missings<'_> {
 
         println!("{table}");
     }
+
+    fn rule(&self) -> &'static str {
+        "missings"
+    }
+
+    fn status(&self) -> &'static str {
+        "MISSING"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.missings
+            .iter()
+            .flat_map(|(id, configs)| {
+                configs
+                    .iter()
+                    .map(move |c| (missing_identifier(id, c), vec![Involved::Id(id)]))
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        for (id, configs) in &mut self.missings {
+            configs.retain(|c| !suppressed(&missing_identifier(id, c)));
+        }
+        self.missings.retain(|_, configs| !configs.is_empty());
+    }
 }
 
-struct Cycles<'a> {
+fn missing_identifier(id: &str, config: &SolutionConfiguration) -> String {
+    format!("{id}|{}|{}", config.configuration, config.platform)
+}
+
+struct UnknownPlatforms<'a> {
     solution: &'a Solution<'a>,
-    cycles_detected: bool,
+    platforms: BTreeMap<&'a str, BTreeSet<&'a str>>,
 }
 
-impl<'a> Cycles<'a> {
+impl<'a> UnknownPlatforms<'a> {
+    const EXPLANATION: &'static str = "Project configurations use platforms that the solution doesn't declare. Building such configurations from the solution is impossible. Add the platforms into the solution or remove them from projects.";
+
     pub fn new(solution: &'a Solution<'a>) -> Self {
         Self {
             solution,
-            cycles_detected: false,
+            platforms: BTreeMap::new(),
         }
     }
 }
 
-impl<'a> Validator for Cycles<'a> {
+impl Validator for UnknownPlatforms<'_> {
     fn validate(&mut self, statistic: &mut Statistic) {
-        let mut graph = DiGraphMap::<&'a str, ()>::new();
-        for p in &self.solution.projects {
-            graph.add_node(p.id);
-            if let Some(depends_from) = &p.depends_from {
-                for d in depends_from {
-                    if !graph.contains_node(d) {
-                        graph.add_node(d);
-                    }
-                    graph.add_edge(d, p.id, ());
-                }
+        let solution_platforms = self
+            .solution
+            .configurations
+            .iter()
+            .map(|c| c.platform)
+            .collect::<HashSet<&str>>();
+
+        self.platforms = BTreeMap::new();
+        for project in &self.solution.projects {
+            let platforms = project
+                .configurations
+                .iter()
+                .flatten()
+                .map(|c| c.platform)
+                .filter(|platform| !solution_platforms.contains(platform));
+            for platform in platforms {
+                self.platforms
+                    .entry(platform)
+                    .or_default()
+                    .insert(project.name);
             }
         }
 
-        let mut space = DfsSpace::new(&graph);
-        self.cycles_detected = petgraph::algo::toposort(&graph, Some(&mut space)).is_err();
         if !self.validation_result() {
-            statistic.cycles += 1;
+            statistic.unknown_platforms += 1;
         }
     }
 
     fn validation_result(&self) -> bool {
-        !self.cycles_detected
+        self.platforms.is_empty()
     }
 
-    fn print_results(&self) {
-        println!(
-            "   {}",
-            "Solution contains project dependencies cycles"
-                .dark_red()
-                .bold()
-        );
-    }
-}
+    fn print_results(&self, _base: Option<&Path>) {
+        println!("  {}", "Solution contains project configurations for platforms that are outside solution's platform list. Add these platforms into solution or remove them from projects:".dark_yellow().bold());
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Platform").add_attribute(Attribute::Bold),
+            Cell::new("Projects").add_attribute(Attribute::Bold),
+        ]);
 
-    #[test]
-    fn integration_test_correct_solution() {
-        // Arrange
-        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
-        let mut validator = Validate::new(false);
+        for (platform, projects) in &self.platforms {
+            table.add_row([Cell::new(*platform), Cell::new(projects.iter().join(", "))]);
+        }
 
-        // Act
-        validator.ok(&solution);
+        println!("{table}");
+    }
 
-        // Assert
+    fn rule(&self) -> &'static str {
+        "unknown-platforms"
     }
 
-    #[test]
-    fn integration_test_solution_with_danglings() {
-        // Arrange
-        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
-        let mut validator = Validate::new(false);
+    fn status(&self) -> &'static str {
+        "PLATFORM"
+    }
 
-        // Act
-        validator.ok(&solution);
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.platforms
+            .iter()
+            .flat_map(|(platform, projects)| {
+                projects.iter().map(move |p| {
+                    (
+                        unknown_platform_identifier(platform, p),
+                        vec![Involved::Name(p)],
+                    )
+                })
+            })
+            .collect()
+    }
 
-        // Assert
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        for (platform, projects) in &mut self.platforms {
+            projects.retain(|p| !suppressed(&unknown_platform_identifier(platform, p)));
+        }
+        self.platforms.retain(|_, projects| !projects.is_empty());
     }
+}
 
-    #[test]
-    fn integration_test_solution_with_missings() {
-        // Arrange
-        let solution = solp::parse_str(SOLUTION_WITH_MISSING_PROJECT_CONFIGS).unwrap();
-        let mut validator = Validate::new(false);
+fn unknown_platform_identifier(platform: &str, project: &str) -> String {
+    format!("{platform}|{project}")
+}
 
-        // Act
-        validator.ok(&solution);
+struct Cycles<'a> {
+    solution: &'a Solution<'a>,
+    cycles: Vec<Vec<&'a str>>,
+}
 
-        // Assert
+impl<'a> Cycles<'a> {
+    const EXPLANATION: &'static str = "Projects depend on each other through ProjectDependencies sections forming a cycle. No build order exists for such projects so MSBuild and Visual Studio fail to build them. Remove one of the dependencies in every cycle.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            cycles: vec![],
+        }
     }
+}
 
-    #[test]
-    fn integration_test_solution_with_cycles() {
-        // Arrange
-        let solution = solp::parse_str(SOLUTION_WITH_CYCLES).unwrap();
-        let mut validator = Validate::new(false);
+fn cycle_identifier(cycle: &[&str]) -> String {
+    cycle.join(",")
+}
 
-        // Act
-        validator.ok(&solution);
+impl Validator for Cycles<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        let graph = crate::graph::dependency_graph(self.solution);
 
-        // Assert
+        // Every strongly connected component with more then one node
+        // or a node that depends from itself is a cycle
+        self.cycles = petgraph::algo::tarjan_scc(&graph)
+            .into_iter()
+            .filter(|scc| scc.len() > 1 || graph.contains_edge(scc[0], scc[0]))
+            .map(|mut scc| {
+                scc.sort_unstable();
+                scc
+            })
+            .collect();
+        if !self.validation_result() {
+            statistic.cycles += 1;
+        }
     }
 
-    #[test]
-    fn dangling_validation_correct() {
-        // Arrange
-        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
-        let mut validator = Danglings::new(&solution);
-        let mut statistic = Statistic::default();
+    fn validation_result(&self) -> bool {
+        self.cycles.is_empty()
+    }
 
-        // Act
-        validator.validate(&mut statistic);
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "   {}",
+            "Solution contains project dependencies cycles"
+                .dark_red()
+                .bold()
+        );
+    }
 
-        // Assert
-        assert!(validator.validation_result());
+    fn rule(&self) -> &'static str {
+        "cycles"
     }
 
-    #[test]
-    fn cycles_validation_correct() {
-        // Arrange
-        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
-        let mut validator = Cycles::new(&solution);
-        let mut statistic = Statistic::default();
+    fn status(&self) -> &'static str {
+        "CYCLE"
+    }
 
-        // Act
-        validator.validate(&mut statistic);
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.cycles
+            .iter()
+            .map(|c| {
+                (
+                    cycle_identifier(c),
+                    c.iter().map(|id| Involved::Id(id)).collect(),
+                )
+            })
+            .collect()
+    }
 
-        // Assert
-        assert!(validator.validation_result());
-        assert_eq!(0, statistic.cycles);
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.cycles.retain(|c| !suppressed(&cycle_identifier(c)));
     }
+}
 
-    #[test]
-    fn cycles_validation_incorrect() {
-        // Arrange
-        let solution = solp::parse_str(SOLUTION_WITH_CYCLES).unwrap();
-        let mut validator = Cycles::new(&solution);
-        let mut statistic = Statistic::default();
+struct ReferenceCycles<'a> {
+    solution: &'a Solution<'a>,
+    cycles: Vec<Vec<PathBuf>>,
+}
 
-        // Act
-        validator.validate(&mut statistic);
+impl<'a> ReferenceCycles<'a> {
+    const EXPLANATION: &'static str = "Project files reference each other through ProjectReference items forming a cycle. MSBuild cannot build such projects. Remove one of the references in every cycle, for example by extracting shared code into a separate project.";
 
-        // Assert
-        assert!(!validator.validation_result());
-        assert_eq!(1, statistic.cycles);
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            cycles: vec![],
+        }
     }
 
-    #[test]
-    fn missing_validation_correct() {
-        // Arrange
-        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
-        let mut validator = Missings::new(&solution);
-        let mut statistic = Statistic::default();
+    /// Paths of projects that are already inside solution level dependencies cycles
+    fn solution_cycles_paths(&self) -> HashSet<PathBuf> {
+        let graph = crate::graph::dependency_graph(self.solution);
+        let in_cycles = petgraph::algo::tarjan_scc(&graph)
+            .into_iter()
+            .filter(|scc| scc.len() > 1 || graph.contains_edge(scc[0], scc[0]))
+            .flatten()
+            .collect::<HashSet<&str>>();
+        let dir = crate::parent_of(self.solution.path);
+        self.solution
+            .iterate_projects()
+            .filter(|p| in_cycles.contains(p.id))
+            .filter_map(|p| loader::project_path(dir, p))
+            .map(|p| loader::normalize_path(&p))
+            .collect()
+    }
+}
 
-        // Act
-        validator.validate(&mut statistic);
+fn reference_cycle_identifier(dir: &Path, cycle: &[PathBuf]) -> String {
+    cycle.iter().map(|p| solution_relative(dir, p)).join(",")
+}
 
-        // Assert
-        assert!(validator.validation_result());
-        assert_eq!(0, statistic.missings);
+impl Validator for ReferenceCycles<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        let known = self.solution_cycles_paths();
+        // Report only cycles that solution level dependencies graph missed
+        self.cycles = loader::project_reference_cycles(self.solution)
+            .into_iter()
+            .filter(|cycle| !cycle.iter().all(|p| known.contains(p)))
+            .collect();
+        if !self.validation_result() {
+            statistic.reference_cycles += 1;
+        }
     }
 
-    #[test]
-    fn missing_validation_incorrect() {
-        // Arrange
-        let solution = solp::parse_str(SOLUTION_WITH_MISSING_PROJECT_CONFIGS).unwrap();
-        let mut validator = Missings::new(&solution);
-        let mut statistic = Statistic::default();
+    fn validation_result(&self) -> bool {
+        self.cycles.is_empty()
+    }
 
-        // Act
-        validator.validate(&mut statistic);
+    fn print_results(&self, base: Option<&Path>) {
+        println!(
+            "   {}",
+            "Solution's project files contain project references cycles:"
+                .dark_red()
+                .bold()
+        );
+        ux::print_one_column_table(
+            "Cycle",
+            None,
+            self.cycles
+                .iter()
+                .map(|c| c.iter().map(|p| display_path(p, base)).join(" -> ")),
+        );
+    }
 
-        // Assert
-        assert!(!validator.validation_result());
-        assert_eq!(1, statistic.missings);
+    fn rule(&self) -> &'static str {
+        "reference-cycles"
     }
 
-    #[test]
-    fn dangling_validation_incorrect() {
-        // Arrange
-        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
-        let mut validator = Danglings::new(&solution);
-        let mut statistic = Statistic::default();
+    fn status(&self) -> &'static str {
+        "REFCYCLE"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        let dir = crate::parent_of(self.solution.path);
+        self.cycles
+            .iter()
+            .map(|c| {
+                (
+                    reference_cycle_identifier(dir, c),
+                    c.iter().map(|p| Involved::Path(p)).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        let dir = crate::parent_of(self.solution.path);
+        self.cycles
+            .retain(|c| !suppressed(&reference_cycle_identifier(dir, c)));
+    }
+}
+
+struct SamePathGuids<'a> {
+    solution: &'a Solution<'a>,
+    collisions: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl<'a> SamePathGuids<'a> {
+    const EXPLANATION: &'static str = "The same project file is included into the solution several times under different GUIDs so it's built several times and may race with itself. Keep one Project block and point dependencies to its GUID.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            collisions: BTreeMap::new(),
+        }
+    }
+}
+
+/// Makes path relative to the solution directory with `/` separators
+/// so that problem identifiers don't depend on checkout location or scan root
+fn solution_relative(dir: &Path, path: &Path) -> String {
+    let absolute = |p: &Path| {
+        std::path::absolute(p).map_or_else(|_| p.to_path_buf(), |a| loader::normalize_path(&a))
+    };
+    let path = absolute(path);
+    let relative = pathdiff::diff_paths(&path, absolute(dir)).unwrap_or(path);
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+/// Normalizes project path so that different spellings of the same file compare equal
+fn normalize_project_path(path: &str) -> String {
+    let path = path.replace('/', "\\").to_lowercase();
+    path.trim_start_matches(".\\").to_string()
+}
+
+impl Validator for SamePathGuids<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        let mut by_path: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for p in self.solution.iterate_projects_without_web_sites() {
+            by_path
+                .entry(normalize_project_path(p.path_or_uri))
+                .or_default()
+                .insert(p.guid_normalized());
+        }
+        by_path.retain(|_, guids| guids.len() > 1);
+        self.collisions = by_path;
+
+        if !self.validation_result() {
+            statistic.same_path_guids += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.collisions.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution references the same project under several GUIDs so it will be built several times:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project path").add_attribute(Attribute::Bold),
+            Cell::new("Project IDs").add_attribute(Attribute::Bold),
+        ]);
+
+        for (path, guids) in &self.collisions {
+            table.add_row([Cell::new(path), Cell::new(guids.iter().join("\n"))]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "same-path-guids"
+    }
+
+    fn status(&self) -> &'static str {
+        "DUPGUID"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.collisions
+            .iter()
+            .map(|(path, guids)| {
+                (
+                    path.clone(),
+                    guids.iter().map(|g| Involved::Id(g)).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.collisions.retain(|path, _| !suppressed(path));
+    }
+}
+
+struct OutsideReferences<'a> {
+    solution: &'a Solution<'a>,
+    references: BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+}
+
+impl<'a> OutsideReferences<'a> {
+    const EXPLANATION: &'static str = "Solution projects reference projects that aren't included into the solution. They are built implicitly and their configurations can't be controlled from the solution. Add the referenced projects into the solution.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            references: BTreeMap::new(),
+        }
+    }
+}
+
+fn reference_identifier(dir: &Path, project: &Path, reference: &Path) -> String {
+    format!(
+        "{}|{}",
+        solution_relative(dir, project),
+        solution_relative(dir, reference)
+    )
+}
+
+impl Validator for OutsideReferences<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.references = loader::references_outside_solution(self.solution);
+        if !self.validation_result() {
+            statistic.outside_references += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.references.is_empty()
+    }
+
+    fn print_results(&self, base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution projects reference projects that aren't included into solution:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project").add_attribute(Attribute::Bold),
+            Cell::new("Referenced project").add_attribute(Attribute::Bold),
+        ]);
+
+        for (project, references) in &self.references {
+            for reference in references {
+                table.add_row([
+                    Cell::new(display_path(project, base)),
+                    Cell::new(display_path(reference, base)),
+                ]);
+            }
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "outside-references"
+    }
+
+    fn status(&self) -> &'static str {
+        "OUTSIDEREF"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        let dir = crate::parent_of(self.solution.path);
+        self.references
+            .iter()
+            .flat_map(|(p, refs)| {
+                refs.iter()
+                    .map(move |r| (reference_identifier(dir, p, r), vec![Involved::Path(p)]))
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        let dir = crate::parent_of(self.solution.path);
+        for (project, references) in &mut self.references {
+            references.retain(|r| !suppressed(&reference_identifier(dir, project, r)));
+        }
+        self.references
+            .retain(|_, references| !references.is_empty());
+    }
+}
+
+struct SharedOutputPaths<'a> {
+    solution: &'a Solution<'a>,
+    outputs: BTreeMap<(String, PathBuf), BTreeSet<&'a str>>,
+}
+
+impl<'a> SharedOutputPaths<'a> {
+    const EXPLANATION: &'static str = "Several projects set the same OutputPath for the same solution configuration. Their build results overwrite each other so the content of the directory depends on build order and parallelism. Give every project its own output directory.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            outputs: BTreeMap::new(),
+        }
+    }
+}
+
+fn shared_output_identifier(dir: &Path, configuration: &str, output: &Path) -> String {
+    format!("{configuration}|{}", solution_relative(dir, output))
+}
+
+impl Validator for SharedOutputPaths<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.outputs = loader::shared_output_paths(self.solution);
+        if !self.validation_result() {
+            statistic.shared_output_paths += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.outputs.is_empty()
+    }
+
+    fn print_results(&self, base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution projects build into the same output directory:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Configuration").add_attribute(Attribute::Bold),
+            Cell::new("Output path").add_attribute(Attribute::Bold),
+            Cell::new("Projects").add_attribute(Attribute::Bold),
+        ]);
+
+        for ((configuration, output), projects) in &self.outputs {
+            table.add_row([
+                Cell::new(configuration),
+                Cell::new(display_path(output, base)),
+                Cell::new(projects.iter().join(", ")),
+            ]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "shared-output-paths"
+    }
+
+    fn status(&self) -> &'static str {
+        "SHAREDOUT"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        let dir = crate::parent_of(self.solution.path);
+        self.outputs
+            .iter()
+            .map(|((configuration, output), projects)| {
+                (
+                    shared_output_identifier(dir, configuration, output),
+                    projects.iter().map(|p| Involved::Name(p)).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        let dir = crate::parent_of(self.solution.path);
+        self.outputs.retain(|(configuration, output), _| {
+            !suppressed(&shared_output_identifier(dir, configuration, output))
+        });
+    }
+}
+
+struct AbsolutePaths<'a> {
+    solution: &'a Solution<'a>,
+    projects: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> AbsolutePaths<'a> {
+    const EXPLANATION: &'static str = "Projects are included using absolute paths so the solution works only on the machine where it was created. Use paths relative to the solution directory.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            projects: vec![],
+        }
+    }
+}
+
+/// Whether path is absolute either on Windows i.e. starts from drive letter or UNC share
+/// or on Unix i.e. starts from root. URIs aren't considered as absolute paths
+fn is_absolute_path(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    let drive = bytes.len() > 2
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && matches!(bytes[2], b'\\' | b'/');
+    drive || path.starts_with('/') || path.starts_with("\\\\")
+}
+
+fn absolute_path_identifier(name: &str, path: &str) -> String {
+    format!("{name}|{path}")
+}
+
+impl Validator for AbsolutePaths<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.projects = self
+            .solution
+            .iterate_projects()
+            .filter(|p| is_absolute_path(p.path_or_uri))
+            .map(|p| (p.name, p.path_or_uri))
+            .collect();
+        if !self.validation_result() {
+            statistic.absolute_paths += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.projects.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution contains projects with absolute paths that make it non portable:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project").add_attribute(Attribute::Bold),
+            Cell::new("Path").add_attribute(Attribute::Bold),
+        ]);
+
+        for (name, path) in &self.projects {
+            table.add_row([Cell::new(*name), Cell::new(*path)]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "absolute-paths"
+    }
+
+    fn status(&self) -> &'static str {
+        "ABSPATH"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.projects
+            .iter()
+            .map(|(name, path)| {
+                (
+                    absolute_path_identifier(name, path),
+                    vec![Involved::Name(name)],
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.projects
+            .retain(|(name, path)| !suppressed(&absolute_path_identifier(name, path)));
+    }
+}
+
+/// Project, solution configuration, solution platform and project configurations it's mapped to
+type ConfigurationConflict<'a> = (&'a str, &'a str, &'a str, BTreeSet<&'a str>);
+
+struct ConflictingConfigurations<'a> {
+    solution: &'a Solution<'a>,
+    conflicts: Vec<ConfigurationConflict<'a>>,
+}
+
+impl<'a> ConflictingConfigurations<'a> {
+    const EXPLANATION: &'static str = "A project maps the same solution configuration|platform pair to different project configurations by the same kind of lines (ActiveCfg, Build.0 or Deploy.0). Visual Studio keeps only one of them so the build depends on lines order. Leave a single mapping per pair.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            conflicts: vec![],
+        }
+    }
+}
+
+fn configuration_conflict_identifier(name: &str, configuration: &str, platform: &str) -> String {
+    format!("{name}|{configuration}|{platform}")
+}
+
+impl Validator for ConflictingConfigurations<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.conflicts = self
+            .solution
+            .iterate_projects()
+            .flat_map(|p| {
+                p.configurations
+                    .iter()
+                    .flatten()
+                    // ActiveCfg (None tag), Build.0 and Deploy.0 lines are compared separately
+                    // because their mismatch is reported by DivergingBuildConfigurations
+                    .flat_map(|c| {
+                        c.active
+                            .then_some(None)
+                            .into_iter()
+                            .chain(c.tags.iter().map(Some))
+                            .map(move |tag| (tag, c))
+                    })
+                    .into_grouping_map_by(|(tag, c)| (c.solution_configuration, c.platform, *tag))
+                    .fold(BTreeSet::new(), |mut acc, _key, (_, c)| {
+                        acc.insert(c.configuration);
+                        acc
+                    })
+                    .into_iter()
+                    .filter(|(_, configurations)| configurations.len() > 1)
+                    .into_grouping_map_by(|((configuration, platform, _, _), _)| {
+                        (*configuration, *platform)
+                    })
+                    .fold(BTreeSet::new(), |mut acc, _key, (_, configurations)| {
+                        acc.extend(configurations);
+                        acc
+                    })
+                    .into_iter()
+                    .map(move |((configuration, platform), configurations)| {
+                        (p.name, configuration, platform, configurations)
+                    })
+            })
+            .sorted()
+            .collect();
+        if !self.validation_result() {
+            statistic.configuration_conflicts += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution contains projects that map the same configuration to different project configurations:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project").add_attribute(Attribute::Bold),
+            Cell::new("Solution configuration").add_attribute(Attribute::Bold),
+            Cell::new("Project configurations").add_attribute(Attribute::Bold),
+        ]);
+
+        for (name, configuration, platform, configurations) in &self.conflicts {
+            table.add_row([
+                Cell::new(*name),
+                Cell::new(format!("{configuration}|{platform}")),
+                Cell::new(configurations.iter().join(", ")).fg(comfy_table::Color::Red),
+            ]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "conflicting-configurations"
+    }
+
+    fn status(&self) -> &'static str {
+        "CONFCONFLICT"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.conflicts
+            .iter()
+            .map(|(name, configuration, platform, _)| {
+                (
+                    configuration_conflict_identifier(name, configuration, platform),
+                    vec![Involved::Name(name)],
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.conflicts.retain(|(name, configuration, platform, _)| {
+            !suppressed(&configuration_conflict_identifier(
+                name,
+                configuration,
+                platform,
+            ))
+        });
+    }
+}
+
+/// Project, solution configuration, platform, active and built project configurations
+type BuildDivergence<'a> = (&'a str, &'a str, &'a str, &'a str, &'a str);
+
+struct DivergingBuildConfigurations<'a> {
+    solution: &'a Solution<'a>,
+    divergences: Vec<BuildDivergence<'a>>,
+}
+
+impl<'a> DivergingBuildConfigurations<'a> {
+    const EXPLANATION: &'static str = "A project builds a configuration that differs from the one selected by ActiveCfg line for the same solution configuration. Building from command line and from Visual Studio gives different results. Make Build.0 lines use the active configuration.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            divergences: vec![],
+        }
+    }
+}
+
+impl Validator for DivergingBuildConfigurations<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.divergences = self
+            .solution
+            .iterate_projects()
+            .flat_map(|p| {
+                p.diverging_build_configurations().into_iter().map(
+                    move |(configuration, platform, active, built)| {
+                        (p.name, configuration, platform, active, built)
+                    },
+                )
+            })
+            .sorted()
+            .collect();
+        if !self.validation_result() {
+            statistic.diverging_build_configurations += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution contains projects that build configurations other than active ones:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project").add_attribute(Attribute::Bold),
+            Cell::new("Solution configuration").add_attribute(Attribute::Bold),
+            Cell::new("ActiveCfg").add_attribute(Attribute::Bold),
+            Cell::new("Build").add_attribute(Attribute::Bold),
+        ]);
+
+        for (name, configuration, platform, active, built) in &self.divergences {
+            table.add_row([
+                Cell::new(*name),
+                Cell::new(format!("{configuration}|{platform}")),
+                Cell::new(*active),
+                Cell::new(*built).fg(comfy_table::Color::Red),
+            ]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "diverging-build-configurations"
+    }
+
+    fn status(&self) -> &'static str {
+        "BUILDCFG"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.divergences
+            .iter()
+            .map(|(name, configuration, platform, _, _)| {
+                (
+                    configuration_conflict_identifier(name, configuration, platform),
+                    vec![Involved::Name(name)],
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.divergences
+            .retain(|(name, configuration, platform, _, _)| {
+                !suppressed(&configuration_conflict_identifier(
+                    name,
+                    configuration,
+                    platform,
+                ))
+            });
+    }
+}
+
+struct FolderDependencies<'a> {
+    solution: &'a Solution<'a>,
+    dependencies: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> FolderDependencies<'a> {
+    const EXPLANATION: &'static str = "Projects declare dependencies on solution folders. Folders are never built so such dependencies do nothing and usually point to a removed or moved project. Depend on projects instead.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            dependencies: vec![],
+        }
+    }
+}
+
+fn folder_dependency_identifier(project: &str, folder: &str) -> String {
+    format!("{project}|{folder}")
+}
+
+impl Validator for FolderDependencies<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        let folders = &self
+            .solution
+            .iterate_folders()
+            .map(|f| (f.guid_normalized(), f.name))
+            .collect::<HashMap<String, &str>>();
+        self.dependencies = self
+            .solution
+            .iterate_projects()
+            .flat_map(|p| {
+                p.depends_from
+                    .iter()
+                    .flatten()
+                    .filter_map(move |id| folders.get(&normalize_guid(id)).copied())
+                    .map(move |folder| (p.name, folder))
+            })
+            .sorted()
+            .collect();
+        if !self.validation_result() {
+            statistic.folder_dependencies += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.dependencies.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution contains projects that depend on solution folders instead of projects:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project").add_attribute(Attribute::Bold),
+            Cell::new("Solution folder").add_attribute(Attribute::Bold),
+        ]);
+
+        for (project, folder) in &self.dependencies {
+            table.add_row([Cell::new(*project), Cell::new(*folder)]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "folder-dependencies"
+    }
+
+    fn status(&self) -> &'static str {
+        "FOLDERDEP"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.dependencies
+            .iter()
+            .map(|(project, folder)| {
+                (
+                    folder_dependency_identifier(project, folder),
+                    vec![Involved::Name(project)],
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.dependencies.retain(|(project, folder)| {
+            !suppressed(&folder_dependency_identifier(project, folder))
+        });
+    }
+}
+
+struct MissingSolutionItems<'a> {
+    solution: &'a Solution<'a>,
+    items: Vec<&'a str>,
+}
+
+impl<'a> MissingSolutionItems<'a> {
+    const EXPLANATION: &'static str = "Solution items listed in SolutionItems sections don't exist on disk. Visual Studio shows them as missing. Restore the files or remove them from the solution.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            items: vec![],
+        }
+    }
+}
+
+impl Validator for MissingSolutionItems<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        let dir = crate::parent_of(self.solution.path);
+        self.items = self.solution.unreferenced_solution_items(dir);
+        if !self.validation_result() {
+            statistic.missing_solution_items += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        ux::print_one_column_table(
+            "Unexist solution item",
+            Some(comfy_table::Color::DarkYellow),
+            self.items.iter().copied(),
+        );
+    }
+
+    fn rule(&self) -> &'static str {
+        "missing-solution-items"
+    }
+
+    fn status(&self) -> &'static str {
+        "MISSITEM"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.items
+            .iter()
+            .map(|i| {
+                let folders = self
+                    .solution
+                    .iterate_folders()
+                    .filter(|f| f.items.iter().flatten().any(|item| item == i))
+                    .map(|f| Involved::Id(f.id))
+                    .collect();
+                ((*i).to_owned(), folders)
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.items.retain(|i| !suppressed(i));
+    }
+}
+
+struct MalformedHeader<'a> {
+    solution: &'a Solution<'a>,
+    malformed: bool,
+}
+
+impl<'a> MalformedHeader<'a> {
+    const EXPLANATION: &'static str = "The first line of the file isn't the Microsoft Visual Studio Solution File header. The file may be not a solution at all or it was damaged. Restore the header or exclude the file from the scan.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            malformed: false,
+        }
+    }
+}
+
+impl Validator for MalformedHeader<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.malformed = !self.solution.has_valid_header();
+        if !self.validation_result() {
+            statistic.malformed_headers += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        !self.malformed
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        ux::print_one_column_table(
+            "Unexpected first line. File may be not a solution at all",
+            Some(comfy_table::Color::DarkYellow),
+            [self.solution.header].into_iter(),
+        );
+    }
+
+    fn rule(&self) -> &'static str {
+        "malformed-header"
+    }
+
+    fn status(&self) -> &'static str {
+        "BADHEADER"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        if self.malformed {
+            vec![(self.solution.header.to_owned(), vec![])]
+        } else {
+            vec![]
+        }
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.malformed &= !suppressed(self.solution.header);
+    }
+}
+
+struct OrphanedNesting<'a> {
+    solution: &'a Solution<'a>,
+    orphans: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> OrphanedNesting<'a> {
+    const EXPLANATION: &'static str = "NestedProjects section places projects that don't exist in the solution into folders. Such entries remain after a project was removed by hand and indicate a corrupted solution. Remove these entries.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            orphans: vec![],
+        }
+    }
+}
+
+fn orphaned_nesting_identifier(child: &str, parent: &str) -> String {
+    format!("{child}|{parent}")
+}
+
+impl Validator for OrphanedNesting<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.orphans = self.solution.orphaned_nested_projects();
+        if !self.validation_result() {
+            statistic.orphaned_nestings += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.orphans.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution contains nesting entries for projects that are not in the solution:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Child").add_attribute(Attribute::Bold),
+            Cell::new("Parent").add_attribute(Attribute::Bold),
+        ]);
+
+        for (child, parent) in &self.orphans {
+            table.add_row([Cell::new(*child), Cell::new(*parent)]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "orphaned-nesting"
+    }
+
+    fn status(&self) -> &'static str {
+        "ORPHANNEST"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.orphans
+            .iter()
+            .map(|(child, parent)| {
+                (
+                    orphaned_nesting_identifier(child, parent),
+                    vec![Involved::Id(child), Involved::Id(parent)],
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.orphans
+            .retain(|(child, parent)| !suppressed(&orphaned_nesting_identifier(child, parent)));
+    }
+}
+
+struct EmptyProjects<'a> {
+    solution: &'a Solution<'a>,
+    empty: Vec<&'a Project<'a>>,
+}
+
+impl<'a> EmptyProjects<'a> {
+    const EXPLANATION: &'static str = "Project is declared with empty name or path. Visual Studio never writes such entries so they are usually left by truncated or corrupted edits. Fix or remove these project declarations.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            empty: vec![],
+        }
+    }
+}
+
+impl Validator for EmptyProjects<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        // Solution folders legitimately repeat name as path so they aren't checked
+        self.empty = self
+            .solution
+            .iterate_projects()
+            .filter(|p| p.name.trim().is_empty() || p.path_or_uri.trim().is_empty())
+            .collect();
+        if !self.validation_result() {
+            statistic.empty_projects += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.empty.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution contains projects declared with empty name or path:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Id").add_attribute(Attribute::Bold),
+            Cell::new("Name").add_attribute(Attribute::Bold),
+            Cell::new("Path").add_attribute(Attribute::Bold),
+        ]);
+
+        for p in &self.empty {
+            table.add_row([Cell::new(p.id), Cell::new(p.name), Cell::new(p.path_or_uri)]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "empty-projects"
+    }
+
+    fn status(&self) -> &'static str {
+        "EMPTYPRJ"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.empty
+            .iter()
+            .map(|p| (p.id.to_owned(), vec![Involved::Id(p.id)]))
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.empty.retain(|p| !suppressed(p.id));
+    }
+}
+
+struct DuplicateDependencies<'a> {
+    solution: &'a Solution<'a>,
+    duplicates: Vec<(&'a Project<'a>, &'a str)>,
+}
+
+impl<'a> DuplicateDependencies<'a> {
+    const EXPLANATION: &'static str = "ProjectDependencies section of a project lists the same dependency several times. It's redundant and often hides a copy-paste error where another dependency was meant. Remove duplicate lines and check that all intended dependencies are listed.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            duplicates: vec![],
+        }
+    }
+}
+
+fn duplicate_dependency_identifier(project: &Project, dependency: &str) -> String {
+    format!("{}|{}", project.id, dependency)
+}
+
+impl Validator for DuplicateDependencies<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.duplicates = self
+            .solution
+            .projects
+            .iter()
+            .flat_map(|p| {
+                p.depends_from
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .into_group_map_by(|d| normalize_guid(d))
+                    .into_values()
+                    .filter(|same| same.len() > 1)
+                    .map(move |same| (p, same[0]))
+                    .sorted_unstable_by_key(|(_, d)| normalize_guid(d))
+            })
+            .collect();
+        if !self.validation_result() {
+            statistic.duplicate_dependencies += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.duplicates.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution contains projects that list the same dependency several times:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project").add_attribute(Attribute::Bold),
+            Cell::new("Duplicate dependency").add_attribute(Attribute::Bold),
+        ]);
+
+        for (project, dependency) in &self.duplicates {
+            table.add_row([Cell::new(project.name), Cell::new(*dependency)]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "duplicate-dependencies"
+    }
+
+    fn status(&self) -> &'static str {
+        "DUPDEPS"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.duplicates
+            .iter()
+            .map(|(p, d)| {
+                (
+                    duplicate_dependency_identifier(p, d),
+                    vec![Involved::Id(p.id), Involved::Id(d)],
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.duplicates
+            .retain(|(p, d)| !suppressed(&duplicate_dependency_identifier(p, d)));
+    }
+}
+
+struct TransitiveReferences<'a> {
+    solution: &'a Solution<'a>,
+    references: BTreeSet<PathBuf>,
+}
+
+impl<'a> TransitiveReferences<'a> {
+    const EXPLANATION: &'static str = "Project files reachable through ProjectReference items from solution projects aren't included into the solution. They are built implicitly as part of their referencing projects. Add them into the solution to control their configurations.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            references: BTreeSet::new(),
+        }
+    }
+}
+
+impl Validator for TransitiveReferences<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.references = loader::transitive_references_outside_solution(self.solution);
+        if !self.validation_result() {
+            statistic.transitive_references += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.references.is_empty()
+    }
+
+    fn print_results(&self, base: Option<&Path>) {
+        ux::print_one_column_table(
+            "Project reachable through references but not included into solution",
+            Some(comfy_table::Color::DarkYellow),
+            self.references.iter().map(|p| display_path(p, base)),
+        );
+    }
+
+    fn rule(&self) -> &'static str {
+        "transitive-references"
+    }
+
+    fn status(&self) -> &'static str {
+        "TRANSREF"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        let dir = crate::parent_of(self.solution.path);
+        self.references
+            .iter()
+            .map(|p| (solution_relative(dir, p), vec![Involved::Path(p)]))
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        let dir = crate::parent_of(self.solution.path);
+        self.references
+            .retain(|p| !suppressed(&solution_relative(dir, p)));
+    }
+}
+
+struct MissingImports<'a> {
+    solution: &'a Solution<'a>,
+    imports: BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+}
+
+impl<'a> MissingImports<'a> {
+    const EXPLANATION: &'static str = "Projects import .props or .targets files that don't exist. MSBuild fails to load such projects or, if the import is optional, silently builds them without shared settings. Fix import paths or restore the imported files.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            imports: BTreeMap::new(),
+        }
+    }
+}
+
+impl Validator for MissingImports<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.imports = loader::missing_imports(self.solution);
+        if !self.validation_result() {
+            statistic.missing_imports += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.imports.is_empty()
+    }
+
+    fn print_results(&self, base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution projects import files that don't exist:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Project").add_attribute(Attribute::Bold),
+            Cell::new("Missing import").add_attribute(Attribute::Bold),
+        ]);
+
+        for (project, imports) in &self.imports {
+            for import in imports {
+                table.add_row([
+                    Cell::new(display_path(project, base)),
+                    Cell::new(display_path(import, base)),
+                ]);
+            }
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "missing-imports"
+    }
+
+    fn status(&self) -> &'static str {
+        "IMPORTS"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        let dir = crate::parent_of(self.solution.path);
+        self.imports
+            .iter()
+            .flat_map(|(p, imports)| {
+                imports
+                    .iter()
+                    .map(move |i| (reference_identifier(dir, p, i), vec![Involved::Path(p)]))
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        let dir = crate::parent_of(self.solution.path);
+        for (project, imports) in &mut self.imports {
+            imports.retain(|i| !suppressed(&reference_identifier(dir, project, i)));
+        }
+        self.imports.retain(|_, imports| !imports.is_empty());
+    }
+}
+
+/// Legacy platform that Visual Studio adds to solutions mixing .NET and native projects
+pub(crate) const MIXED_PLATFORMS: &str = "Mixed Platforms";
+
+struct LegacyPlatforms<'a> {
+    solution: &'a Solution<'a>,
+    configurations: Vec<&'a SolutionConfiguration<'a>>,
+    projects: BTreeSet<&'a str>,
+}
+
+impl<'a> LegacyPlatforms<'a> {
+    const EXPLANATION: &'static str = "Solution declares the obsolete Mixed Platforms platform that modern Visual Studio doesn't create anymore. It duplicates other platforms and slows down configuration management. Remove it from the solution and projects.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            configurations: vec![],
+            projects: BTreeSet::new(),
+        }
+    }
+}
+
+fn configuration_identifier(configuration: &SolutionConfiguration) -> String {
+    format!("{}|{}", configuration.configuration, configuration.platform)
+}
+
+impl Validator for LegacyPlatforms<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.configurations = self
+            .solution
+            .configurations
+            .iter()
+            .filter(|c| c.platform.eq_ignore_ascii_case(MIXED_PLATFORMS))
+            .collect();
+        self.projects = self
+            .solution
+            .iterate_projects()
+            .filter(|p| {
+                p.configurations
+                    .iter()
+                    .flatten()
+                    .any(|c| c.platform.eq_ignore_ascii_case(MIXED_PLATFORMS))
+            })
+            .map(|p| p.name)
+            .collect();
+
+        if !self.validation_result() {
+            statistic.legacy_platforms += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.configurations.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution declares obsolete Mixed Platforms platform that can be safely removed:"
+                .dark_yellow()
+                .bold()
+        );
+        ux::print_one_column_table(
+            "Configuration|Platform",
+            None,
+            self.configurations
+                .iter()
+                .map(|c| configuration_identifier(c)),
+        );
+        ux::print_one_column_table("Affected project", None, self.projects.iter());
+    }
+
+    fn rule(&self) -> &'static str {
+        "legacy-platforms"
+    }
+
+    fn status(&self) -> &'static str {
+        "LEGACY"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.configurations
+            .iter()
+            .map(|c| (configuration_identifier(c), vec![]))
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.configurations
+            .retain(|c| !suppressed(&configuration_identifier(c)));
+        if self.configurations.is_empty() {
+            self.projects.clear();
+        }
+    }
+}
+
+/// Product comment's last word (year or major version) and the format version it writes
+const PRODUCT_FORMATS: [(&str, &str); 15] = [
+    ("2002", "7.00"),
+    ("2003", "8.00"),
+    ("2005", "9.00"),
+    ("2008", "10.00"),
+    ("2010", "11.00"),
+    ("2012", "12.00"),
+    ("2013", "12.00"),
+    ("2015", "12.00"),
+    ("2017", "12.00"),
+    ("2019", "12.00"),
+    ("2022", "12.00"),
+    ("14", "12.00"),
+    ("15", "12.00"),
+    ("16", "12.00"),
+    ("17", "12.00"),
+];
+
+fn product_format(product: &str) -> Option<&'static str> {
+    let version = product.split_whitespace().last()?;
+    PRODUCT_FORMATS
+        .iter()
+        .find(|(v, _)| *v == version)
+        .map(|(_, format)| *format)
+}
+
+struct FormatProduct<'a> {
+    solution: &'a Solution<'a>,
+    expected: Option<&'static str>,
+}
+
+impl<'a> FormatProduct<'a> {
+    const EXPLANATION: &'static str = "Solution format version disagrees with the product comment, for example format 12.00 with a Visual Studio 2008 comment. Usually the file was edited by hand or a tool. Re-save the solution to make them consistent.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            expected: None,
+        }
+    }
+
+    fn identifier(&self) -> String {
+        format!("{}|{}", self.solution.format, self.solution.product)
+    }
+}
+
+impl Validator for FormatProduct<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        // Unknown products cannot be checked so they are considered correct
+        self.expected = product_format(self.solution.product)
+            .filter(|format| *format != self.solution.format.trim());
+        if !self.validation_result() {
+            statistic.format_mismatches += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.expected.is_none()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution format version disagrees with product comment:"
+                .dark_yellow()
+                .bold()
+        );
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("Declared format").add_attribute(Attribute::Bold),
+            Cell::new("Product").add_attribute(Attribute::Bold),
+            Cell::new("Product's format").add_attribute(Attribute::Bold),
+        ]);
+        table.add_row([
+            self.solution.format,
+            self.solution.product,
+            self.expected.unwrap_or_default(),
+        ]);
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "format-product"
+    }
+
+    fn status(&self) -> &'static str {
+        "FORMAT"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        if self.validation_result() {
+            vec![]
+        } else {
+            vec![(self.identifier(), vec![])]
+        }
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        if suppressed(&self.identifier()) {
+            self.expected = None;
+        }
+    }
+}
+
+const TFS_SECTION: &str = "TeamFoundationVersionControl";
+
+struct SourceControl<'a> {
+    solution: &'a Solution<'a>,
+    bindings: Option<&'a SourceControlBindings<'a>>,
+}
+
+impl<'a> SourceControl<'a> {
+    const EXPLANATION: &'static str = "Solution still contains TeamFoundationVersionControl section with legacy TFS bindings. They are useless after migrating to another source control and make Visual Studio look for a TFS server. Remove the section.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            bindings: None,
+        }
+    }
+}
+
+fn source_control_identifier(bindings: &SourceControlBindings) -> String {
+    bindings.server.unwrap_or(TFS_SECTION).to_owned()
+}
+
+impl Validator for SourceControl<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.bindings = self.solution.source_control_bindings.as_ref();
+        if !self.validation_result() {
+            statistic.source_control_bindings += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.bindings.is_none()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        let Some(bindings) = self.bindings else {
+            return;
+        };
+        println!(
+            "  {}",
+            "Solution still contains legacy TFS source control bindings that can be safely removed:"
+                .dark_yellow()
+                .bold()
+        );
+        if let Some(server) = bindings.server {
+            println!("  Server: {server}");
+        }
+
+        let mut table = ux::new_table();
+        table.set_header([
+            Cell::new("#").add_attribute(Attribute::Bold),
+            Cell::new("Bound project").add_attribute(Attribute::Bold),
+            Cell::new("Local path").add_attribute(Attribute::Bold),
+        ]);
+        for project in &bindings.projects {
+            table.add_row([
+                project.index.to_string(),
+                project.unique_name.unwrap_or_default().to_owned(),
+                project.local_path.unwrap_or_default().to_owned(),
+            ]);
+        }
+
+        println!("{table}");
+    }
+
+    fn rule(&self) -> &'static str {
+        "source-control"
+    }
+
+    fn status(&self) -> &'static str {
+        "TFS"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.bindings
+            .iter()
+            .map(|b| (source_control_identifier(b), vec![]))
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.bindings = self
+            .bindings
+            .filter(|b| !suppressed(&source_control_identifier(b)));
+    }
+}
+
+struct NameCase<'a> {
+    solution: &'a Solution<'a>,
+    collisions: Vec<BTreeSet<&'a str>>,
+}
+
+impl<'a> NameCase<'a> {
+    const EXPLANATION: &'static str = "Several projects have names that differ only by case. It breaks case-insensitive file systems and tools that use project names as keys. Rename the projects.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            collisions: vec![],
+        }
+    }
+}
+
+fn name_case_identifier(variants: &BTreeSet<&str>) -> String {
+    variants.iter().join(",")
+}
+
+impl Validator for NameCase<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        // Exactly equal names are the same variant so only different spellings collide
+        self.collisions = self
+            .solution
+            .iterate_projects()
+            .map(|p| (p.name.to_lowercase(), p.name))
+            .into_grouping_map()
+            .collect::<BTreeSet<&str>>()
+            .into_iter()
+            .filter(|(_, variants)| variants.len() > 1)
+            .sorted_unstable_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, variants)| variants)
+            .collect();
+        if !self.validation_result() {
+            statistic.name_case_collisions += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.collisions.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution contains projects which names differ only by case:"
+                .dark_yellow()
+                .bold()
+        );
+        ux::print_one_column_table(
+            "Name variants",
+            None,
+            self.collisions.iter().map(|v| v.iter().join(", ")),
+        );
+    }
+
+    fn rule(&self) -> &'static str {
+        "name-case"
+    }
+
+    fn status(&self) -> &'static str {
+        "NAMECASE"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.collisions
+            .iter()
+            .map(|c| {
+                (
+                    name_case_identifier(c),
+                    c.iter().map(|n| Involved::Name(n)).collect(),
+                )
+            })
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.collisions
+            .retain(|v| !suppressed(&name_case_identifier(v)));
+    }
+}
+
+static CANONICAL_GUID: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\{[0-9A-F]{8}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{4}-[0-9A-F]{12}\}$").unwrap()
+});
+
+struct NonCanonicalGuids<'a> {
+    solution: &'a Solution<'a>,
+    guids: BTreeSet<&'a str>,
+}
+
+impl<'a> NonCanonicalGuids<'a> {
+    const EXPLANATION: &'static str = "Solution contains GUIDs that aren't in canonical {UPPERCASE-WITH-HYPHENS} form. Visual Studio never writes such GUIDs so they are usually left by hand edits. Replace them with canonical ones.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            guids: BTreeSet::new(),
+        }
+    }
+}
+
+impl Validator for NonCanonicalGuids<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        // Only project declarations and dependencies are checked.
+        // GUIDs of configuration lines and NestedProjects section aren't
+        self.guids = self
+            .solution
+            .projects
+            .iter()
+            .flat_map(|p| {
+                [p.type_id, p.id]
+                    .into_iter()
+                    .chain(p.depends_from.iter().flatten().copied())
+            })
+            .filter(|guid| !CANONICAL_GUID.is_match(guid))
+            .collect();
+        if !self.validation_result() {
+            statistic.non_canonical_guids += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        self.guids.is_empty()
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution contains GUIDs in non canonical form:"
+                .dark_yellow()
+                .bold()
+        );
+        ux::print_one_column_table("GUID", None, self.guids.iter());
+    }
+
+    fn rule(&self) -> &'static str {
+        "non-canonical-guids"
+    }
+
+    fn status(&self) -> &'static str {
+        "GUIDS"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        self.guids
+            .iter()
+            .map(|g| ((*g).to_owned(), vec![Involved::Id(g)]))
+            .collect()
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.guids.retain(|g| !suppressed(g));
+    }
+}
+
+struct SpaceIndentation<'a> {
+    solution: &'a Solution<'a>,
+    space_indented: bool,
+}
+
+impl<'a> SpaceIndentation<'a> {
+    const EXPLANATION: &'static str = "Solution is indented using spaces while Visual Studio writes tabs. Re-saving it in Visual Studio rewrites almost every line and produces huge diffs. Replace leading spaces with tabs before committing.";
+
+    pub fn new(solution: &'a Solution<'a>) -> Self {
+        Self {
+            solution,
+            space_indented: false,
+        }
+    }
+}
+
+impl Validator for SpaceIndentation<'_> {
+    fn validate(&mut self, statistic: &mut Statistic) {
+        self.space_indented = self.solution.space_indented;
+        if !self.validation_result() {
+            statistic.space_indentations += 1;
+        }
+    }
+
+    fn validation_result(&self) -> bool {
+        !self.space_indented
+    }
+
+    fn print_results(&self, _base: Option<&Path>) {
+        println!(
+            "  {}",
+            "Solution is indented using spaces instead of tabs. Re-saving it in Visual Studio will rewrite most of the lines"
+                .dark_yellow()
+                .bold()
+        );
+    }
+
+    fn rule(&self) -> &'static str {
+        "space-indentation"
+    }
+
+    fn status(&self) -> &'static str {
+        "SPACES"
+    }
+
+    fn involved(&self) -> Vec<(String, Vec<Involved<'_>>)> {
+        if self.space_indented {
+            vec![("spaces".to_owned(), vec![])]
+        } else {
+            vec![]
+        }
+    }
+
+    fn suppress(&mut self, suppressed: &dyn Fn(&str) -> bool) {
+        self.space_indented &= !suppressed("spaces");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{
+        PROJECT_REFERENCING_A, PROJECT_WITH_SHARED_OUTPUT, SOLUTION_WITH_CONFIGURED_PROJECTS,
+    };
+    use rstest::rstest;
+    use solp::fixtures::SOLUTION_WITH_ORPHANED_NESTED_PROJECT;
+
+    #[test]
+    fn integration_test_correct_solution() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = Validate::new(false);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+    }
+
+    #[test]
+    fn integration_test_solution_with_danglings() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
+        let mut validator = Validate::new(false);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+    }
+
+    #[test]
+    fn integration_test_solution_with_missings() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_MISSING_PROJECT_CONFIGS).unwrap();
+        let mut validator = Validate::new(false);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+    }
+
+    #[test]
+    fn integration_test_solution_with_cycles() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_CYCLES).unwrap();
+        let mut validator = Validate::new(false);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+    }
+
+    #[test]
+    fn dangling_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = Danglings::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+    }
+
+    #[test]
+    fn cycles_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = Cycles::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.cycles);
+    }
+
+    #[test]
+    fn cycles_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_CYCLES).unwrap();
+        let mut validator = Cycles::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.cycles);
+    }
+
+    #[test]
+    fn missing_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = Missings::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.missings);
+    }
+
+    #[test]
+    fn missing_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_MISSING_PROJECT_CONFIGS).unwrap();
+        let mut validator = Missings::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.missings);
+    }
+
+    #[test]
+    fn unknown_platforms_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = UnknownPlatforms::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.unknown_platforms);
+    }
+
+    #[test]
+    fn unknown_platforms_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_PROJECT_ONLY_PLATFORM).unwrap();
+        let mut validator = UnknownPlatforms::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.unknown_platforms);
+        assert_eq!(vec!["ARM|b".to_owned()], validator.problems());
+    }
+
+    #[test]
+    fn integration_test_unknown_platforms_opt_in() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_PROJECT_ONLY_PLATFORM).unwrap();
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_unknown_platforms_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(1, default.statistic.borrow().missings);
+        assert_eq!(0, default.statistic.borrow().unknown_platforms);
+        assert_eq!(1, validator.statistic.borrow().unknown_platforms);
+    }
+
+    #[test]
+    fn dangling_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
+        let mut validator = Danglings::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.dangings);
+    }
+
+    #[test]
+    fn print_statistic_test() {
+        // Arrange
+        let s = Statistic::default();
+
+        // Act
+        println!("{s}");
+
+        // Assert
+    }
+
+    #[test]
+    fn same_path_guids_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = SamePathGuids::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.same_path_guids);
+    }
+
+    #[test]
+    fn same_path_guids_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_SAME_PATH_DIFFERENT_GUIDS).unwrap();
+        let mut validator = SamePathGuids::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.same_path_guids);
+        assert_eq!(1, validator.collisions.len());
+        let guids = validator.collisions.get("a\\a.csproj").unwrap();
+        assert_eq!(2, guids.len());
+    }
+
+    #[test]
+    fn integration_test_solution_with_same_path_different_guids() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_SAME_PATH_DIFFERENT_GUIDS).unwrap();
+        let mut validator = Validate::new(false);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+    }
+
+    #[test]
+    fn cycles_validation_problems() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_CYCLES).unwrap();
+        let mut validator = Cycles::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert_eq!(1, validator.problems().len());
+    }
+
+    #[test]
+    fn max_problems_stops_scan() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        for i in 0..4 {
+            std::fs::write(dir.path().join(format!("{i}.sln")), SOLUTION_WITH_DANGLINGS).unwrap();
+        }
+        let mut validator = Validate::new(true).with_max_problems(Some(2));
+
+        // Act
+        solp::parse_dir(dir.path().to_str().unwrap(), "sln", &mut validator);
+
+        // Assert
+        assert!(validator.truncated());
+        assert_eq!(2, validator.statistic.borrow().total);
+        assert_eq!(2, validator.statistic.borrow().dangings);
+    }
+
+    #[test]
+    fn max_problems_reached_by_last_solution() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        for i in 0..2 {
+            std::fs::write(dir.path().join(format!("{i}.sln")), SOLUTION_WITH_DANGLINGS).unwrap();
+        }
+        let mut validator = Validate::new(true).with_max_problems(Some(2));
+
+        // Act
+        solp::parse_dir(dir.path().to_str().unwrap(), "sln", &mut validator);
+
+        // Assert
+        assert!(!validator.truncated());
+        assert_eq!(2, validator.statistic.borrow().dangings);
+    }
+
+    #[test]
+    fn max_problems_not_reached() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
+        let mut validator = Validate::new(true).with_max_problems(Some(2));
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert!(!validator.truncated());
+        assert!(!validator.should_stop());
+    }
+
+    #[test]
+    fn parse_errors_found_malformed_solution_in_dir() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("correct.sln"), CORRECT_SOLUTION).unwrap();
+        std::fs::write(dir.path().join("malformed.sln"), MALFORMED_SOLUTION).unwrap();
+        let mut validator = Validate::new(true);
+
+        // Act
+        solp::parse_dir(dir.path().to_str().unwrap(), "sln", &mut validator);
+
+        // Assert
+        assert!(validator.parse_errors_found());
+        assert_eq!(1, validator.statistic.borrow().total);
+    }
+
+    #[test]
+    fn parse_errors_not_found() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = Validate::new(true);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert!(!validator.parse_errors_found());
+    }
+
+    #[test]
+    fn baseline_recording() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
+        let mut validator = Validate::new(false).with_baseline_recording(true);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        let recorded = validator.recorded_baseline().unwrap();
+        assert!(!recorded.is_empty());
+        assert_eq!(1, validator.statistic.borrow().dangings);
+    }
+
+    #[test]
+    fn problem_ids_stable_across_runs() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
+        let ids = || {
+            let mut validator = Validate::new(false).with_baseline_recording(true);
+            validator.ok(&solution);
+            validator
+                .recorded_baseline()
+                .unwrap()
+                .iter()
+                .map(crate::baseline::Entry::problem_id)
+                .collect::<Vec<_>>()
+        };
+
+        // Act
+        let first = ids();
+        let second = ids();
+
+        // Assert
+        assert!(!first.is_empty());
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn not_found_validation_escaped_path() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("My Project")).unwrap();
+        std::fs::write(dir.path().join("My Project").join("a.csproj"), "").unwrap();
+        let sln = dir.path().join("s.sln");
+        let content = crate::fixtures::SINGLE_PROJECT_SOLUTION
+            .replace(r"a\a.csproj", r"My%20Project\a.csproj");
+        let mut solution = solp::parse_str(&content).unwrap();
+        solution.path = sln.to_str().unwrap();
+        let mut validator = NotFouund::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.not_found);
+        assert_eq!(
+            Some(dir.path().join("My Project").join("a.csproj")),
+            loader::project_path(dir.path(), &solution.projects[0])
+        );
+    }
+
+    #[test]
+    fn baseline_matches_moved_solution() {
+        // Arrange
+        let checkout = || {
+            let dir = tempfile::tempdir().unwrap();
+            let root = dir.path().join("repo");
+            std::fs::create_dir_all(root.join("a")).unwrap();
+            std::fs::create_dir(root.join("b")).unwrap();
+            std::fs::write(
+                root.join("a").join("a.csproj"),
+                PROJECT_IMPORTING_MISSING_PROPS,
+            )
+            .unwrap();
+            std::fs::write(root.join("b").join("b.csproj"), PROJECT_REFERENCING_A).unwrap();
+            dir
+        };
+        let original = checkout();
+        let moved = checkout();
+        let original_sln = original.path().join("repo").join("s.sln");
+        let moved_sln = moved.path().join("repo").join("s.sln");
+        let mut solution = solp::parse_str(SOLUTION_WITH_REFERENCING_PROJECTS).unwrap();
+        solution.path = original_sln.to_str().unwrap();
+        let mut recorder = Validate::new(false)
+            .with_imports_check(true)
+            .with_base(Some(original.path().to_path_buf()))
+            .with_baseline_recording(true);
+        recorder.ok(&solution);
+        let mut baseline = Baseline::new();
+        for entry in recorder.recorded_baseline().unwrap().iter() {
+            baseline.add(&entry.solution, &entry.rule, &entry.identifier);
+        }
+        solution.path = moved_sln.to_str().unwrap();
+        let mut validator = Validate::new(false)
+            .with_imports_check(true)
+            .with_base(Some(moved.path().to_path_buf()))
+            .with_baseline(Some(baseline));
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(1, recorder.statistic.borrow().missing_imports);
+        assert_eq!(0, validator.statistic.borrow().missing_imports);
+    }
+
+    #[test]
+    fn baseline_suppresses_known_problems() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
+        let mut baseline = Baseline::new();
+        let mut danglings = Danglings::new(&solution);
+        danglings.validate(&mut Statistic::default());
+        for problem in danglings.problems() {
+            baseline.add(solution.path, danglings.rule(), &problem);
+        }
+        let mut validator = Validate::new(false).with_baseline(Some(baseline));
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, validator.statistic.borrow().dangings);
+    }
+
+    #[test]
+    fn ignored_project_suppresses_dangling() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
+        let mut danglings = Danglings::new(&solution);
+        danglings.validate(&mut Statistic::default());
+        let ignored = danglings.problems();
+        let mut validator =
+            Validate::new(false).with_ignored_projects(&[ignored[0].to_lowercase()]);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(1, ignored.len());
+        assert_eq!(0, validator.statistic.borrow().dangings);
+    }
+
+    #[test]
+    fn min_projects_skips_small_solution() {
+        // Arrange
+        let mut solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
+        solution.projects.truncate(1);
+        let mut validator = Validate::new(false).with_min_projects(Some(2));
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, validator.statistic.borrow().total);
+        assert_eq!(0, validator.statistic.borrow().dangings);
+    }
+
+    #[test]
+    fn ignored_project_involvement() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let project = &solution.projects[0];
+        let ids = BTreeSet::from([project.guid_normalized()]);
+        let path = solp::api::local_path(Path::new(""), project.path_or_uri);
+        let nested = Path::new("nested").join(&path);
+
+        // Act
+        let ignored = IgnoredProjects::new(&solution, &ids);
+
+        // Assert
+        assert!(ignored.contains(&Involved::Id(&project.id.to_lowercase())));
+        assert!(ignored.contains(&Involved::Name(project.name)));
+        assert!(ignored.contains(&Involved::Path(&path)));
+        assert!(!ignored.contains(&Involved::Name(solution.projects[1].name)));
+        assert!(!ignored.contains(&Involved::Path(&nested)));
+    }
+
+    #[test]
+    fn ignored_project_keeps_problems_of_other_projects() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DANGLINGS).unwrap();
+        let mut danglings = Danglings::new(&solution);
+        danglings.validate(&mut Statistic::default());
+        let unrelated = solution
+            .projects
+            .iter()
+            .map(|p| p.guid_normalized())
+            .collect::<Vec<_>>();
+        let mut validator = Validate::new(false).with_ignored_projects(&unrelated);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert!(!danglings.validation_result());
+        assert_eq!(1, validator.statistic.borrow().dangings);
+    }
+
+    #[test]
+    fn legacy_platforms_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = LegacyPlatforms::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.legacy_platforms);
+    }
+
+    #[test]
+    fn legacy_platforms_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut validator = LegacyPlatforms::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.legacy_platforms);
+        assert_eq!(2, validator.configurations.len());
+        assert!(!validator.projects.is_empty());
+    }
+
+    #[test]
+    fn integration_test_legacy_platforms_opt_in() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut validator = Validate::new(false).with_legacy_platforms_check(true);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(1, validator.statistic.borrow().legacy_platforms);
+    }
+
+    #[rstest]
+    #[case("Visual Studio 2010", Some("11.00"))]
+    #[case("Visual C++ Express 2010", Some("11.00"))]
+    #[case("Visual Studio 15", Some("12.00"))]
+    #[case("Visual Studio Version 17", Some("12.00"))]
+    #[case("Visual Studio 2005", Some("9.00"))]
+    #[case("", None)]
+    #[case("Some IDE", None)]
+    #[trace]
+    fn product_format_tests(#[case] product: &str, #[case] expected: Option<&str>) {
+        // Arrange
+
+        // Act
+        let actual = product_format(product);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn format_product_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut validator = FormatProduct::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.format_mismatches);
+    }
+
+    #[test]
+    fn format_product_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_FORMAT_PRODUCT_MISMATCH).unwrap();
+        let mut validator = FormatProduct::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.format_mismatches);
+        assert_eq!(Some("11.00"), validator.expected);
+        assert_eq!(
+            vec!["12.00|Visual Studio 2010".to_owned()],
+            validator.problems()
+        );
+    }
+
+    #[test]
+    fn integration_test_format_product_opt_in() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_FORMAT_PRODUCT_MISMATCH).unwrap();
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_format_product_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().format_mismatches);
+        assert_eq!(1, validator.statistic.borrow().format_mismatches);
+    }
+
+    #[test]
+    fn source_control_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut validator = SourceControl::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.source_control_bindings);
+    }
+
+    #[test]
+    fn source_control_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_TFS_BINDINGS).unwrap();
+        let mut validator = SourceControl::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.source_control_bindings);
+        assert_eq!(
+            vec!["http://tfs:8080/tfs/defaultcollection".to_owned()],
+            validator.problems()
+        );
+    }
+
+    #[test]
+    fn integration_test_source_control_opt_in() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_TFS_BINDINGS).unwrap();
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_source_control_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().source_control_bindings);
+        assert_eq!(1, validator.statistic.borrow().source_control_bindings);
+    }
+
+    #[test]
+    fn reference_cycles_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = ReferenceCycles::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.reference_cycles);
+    }
+
+    #[test]
+    fn reference_cycles_validation_incorrect() {
+        // Arrange
+        let (_dir, sln) =
+            solution_dir(&[("a", PROJECT_REFERENCING_B), ("b", PROJECT_REFERENCING_A)]);
+        let solution = parse_at(SOLUTION_WITH_REFERENCING_PROJECTS, &sln);
+        let mut validator = ReferenceCycles::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.reference_cycles);
+        assert_eq!(1, validator.cycles.len());
+    }
+
+    #[test]
+    fn integration_test_reference_cycles_opt_in() {
+        // Arrange
+        let (_dir, sln) =
+            solution_dir(&[("a", PROJECT_REFERENCING_B), ("b", PROJECT_REFERENCING_A)]);
+        let solution = parse_at(SOLUTION_WITH_REFERENCING_PROJECTS, &sln);
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_reference_cycles_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().reference_cycles);
+        assert_eq!(1, validator.statistic.borrow().reference_cycles);
+    }
+
+    #[test]
+    fn name_case_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut validator = NameCase::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.name_case_collisions);
+    }
+
+    #[test]
+    fn name_case_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_NAMES_DIFFERENT_BY_CASE).unwrap();
+        let mut validator = NameCase::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.name_case_collisions);
+        assert_eq!(vec!["LIB,Lib,lib".to_owned()], validator.problems());
+    }
+
+    #[test]
+    fn integration_test_name_case_opt_in() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_NAMES_DIFFERENT_BY_CASE).unwrap();
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_name_case_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().name_case_collisions);
+        assert_eq!(1, validator.statistic.borrow().name_case_collisions);
+    }
+
+    #[test]
+    fn non_canonical_guids_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut validator = NonCanonicalGuids::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.non_canonical_guids);
+    }
+
+    #[test]
+    fn non_canonical_guids_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_LOWERCASE_GUIDS).unwrap();
+        let mut validator = NonCanonicalGuids::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.non_canonical_guids);
+        assert_eq!(
+            vec!["{78965571-a6c2-4161-95b1-813b46610ea7}".to_owned()],
+            validator.problems()
+        );
+    }
+
+    #[test]
+    fn integration_test_strict_guids_opt_in() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_LOWERCASE_GUIDS).unwrap();
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_strict_guids(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().non_canonical_guids);
+        assert_eq!(1, validator.statistic.borrow().non_canonical_guids);
+    }
+
+    #[test]
+    fn empty_projects_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = EmptyProjects::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.empty_projects);
+    }
+
+    #[test]
+    fn empty_projects_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_EMPTY_PROJECT_NAME).unwrap();
+        let mut validator = EmptyProjects::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.empty_projects);
+        assert_eq!(
+            vec!["{D9523F4D-6CB7-4431-85F6-8122F55EB144}".to_owned()],
+            validator.problems()
+        );
+    }
+
+    #[test]
+    fn duplicate_dependencies_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(REAL_SOLUTION).unwrap();
+        let mut validator = DuplicateDependencies::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.duplicate_dependencies);
+    }
+
+    #[test]
+    fn duplicate_dependencies_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DUPLICATE_DEPENDENCY).unwrap();
+        let mut validator = DuplicateDependencies::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.duplicate_dependencies);
+        assert_eq!(
+            vec![
+                "{D9523F4D-6CB7-4431-85F6-8122F55EB144}|{78965571-A6C2-4161-95B1-813B46610EA7}"
+                    .to_owned()
+            ],
+            validator.problems()
+        );
+    }
+
+    #[rstest]
+    #[case(&[], 100)]
+    #[case(&["cycles"], 70)]
+    #[case(&["cycles", "danglings"], 55)]
+    #[case(&["name-case"], 95)]
+    #[case(&["cycles", "reference-cycles", "not-found", "malformed-header", "danglings"], 0)]
+    #[trace]
+    fn health_score_deductions(#[case] failed: &[&str], #[case] expected: u8) {
+        // Arrange
+
+        // Act
+        let actual = health_score(failed.iter().copied());
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn health_score_correct_solution() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("correct.sln");
+        std::fs::write(&path, CORRECT_SOLUTION).unwrap();
+        for project in [
+            "gtest.vcproj",
+            "gtest_main.vcproj",
+            "gtest_unittest.vcproj",
+            "gtest_prod_test.vcproj",
+        ] {
+            std::fs::write(dir.path().join(project), "").unwrap();
+        }
+        let mut solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        solution.path = path.to_str().unwrap();
+        let mut validators = Validate::new(false).validators(&solution);
+
+        // Act
+        for v in &mut validators {
+            v.validate(&mut Statistic::default());
+        }
+
+        // Assert
+        assert_eq!(100, solution_score(&validators));
+    }
+
+    #[test]
+    fn health_score_solution_with_cycles() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_CYCLES).unwrap();
+        let mut validators = Validate::new(false).validators(&solution);
+
+        // Act
+        for v in &mut validators {
+            v.validate(&mut Statistic::default());
+        }
+
+        // Assert
+        assert!(solution_score(&validators) <= 70);
+    }
+
+    #[test]
+    fn explain_cycles() {
+        // Act
+        let actual = explain("cycles");
+
+        // Assert
+        assert_eq!(Some(Cycles::EXPLANATION), actual);
+        assert!(actual.unwrap().contains("cycle"));
+    }
+
+    #[test]
+    fn explain_unknown_rule() {
+        // Act
+        let actual = explain("unknown");
+
+        // Assert
+        assert!(actual.is_none());
+    }
+
+    #[test]
+    fn every_rule_explained() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let validate = Validate::new(false)
+            .with_legacy_platforms_check(true)
+            .with_format_product_check(true)
+            .with_source_control_check(true)
+            .with_name_case_check(true)
+            .with_indentation_check(true)
+            .with_imports_check(true)
+            .with_strict_guids(true)
+            .with_follow_project_references(true)
+            .with_outside_references_check(true)
+            .with_reference_cycles_check(true)
+            .with_output_paths_check(true)
+            .with_solution_items_check(true)
+            .with_unknown_platforms_check(true);
+
+        // Act
+        let validators = validate.validators(&solution);
+
+        // Assert
+        assert_eq!(explanations().len(), validators.len());
+        for v in &validators {
+            assert!(explain(v.rule()).is_some(), "{} not explained", v.rule());
+        }
+    }
+
+    #[test]
+    fn project_counts_accumulated_across_solutions() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_UNBUILT_PROJECTS).unwrap();
+        let mut validator = Validate::new(false);
+
+        // Act
+        validator.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        let statistic = validator.summary();
+        assert_eq!(2, statistic.total);
+        assert_eq!(6, statistic.projects);
+        assert_eq!(4, statistic.never_built_projects);
+        assert_eq!(2, statistic.deploy_only_projects);
+    }
+
+    #[test]
+    fn space_indentation_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = SpaceIndentation::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.space_indentations);
+    }
+
+    #[test]
+    fn space_indentation_validation_incorrect() {
+        // Arrange
+        let options = solp::ParseOptions {
+            indentation: true,
+            ..Default::default()
+        };
+        let solution = solp::parse_str_with_options(SPACE_INDENTED_SOLUTION, &options).unwrap();
+        let mut validator = SpaceIndentation::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.space_indentations);
+        assert_eq!(vec!["spaces".to_owned()], validator.problems());
+    }
+
+    #[test]
+    fn integration_test_space_indentation_opt_in() {
+        // Arrange
+        let options = solp::ParseOptions {
+            indentation: true,
+            ..Default::default()
+        };
+        let solution = solp::parse_str_with_options(SPACE_INDENTED_SOLUTION, &options).unwrap();
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_indentation_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().space_indentations);
+        assert_eq!(1, validator.statistic.borrow().space_indentations);
+    }
+
+    #[rstest]
+    #[case("C:\\src\\a\\a.csproj", true)]
+    #[case("d:/src/a/a.csproj", true)]
+    #[case("\\\\server\\share\\a.csproj", true)]
+    #[case("/home/user/src/a.csproj", true)]
+    #[case("a\\a.csproj", false)]
+    #[case("..\\a\\a.csproj", false)]
+    #[case("http://localhost:8080/site/", false)]
+    #[case("c:", false)]
+    #[case("", false)]
+    #[trace]
+    fn is_absolute_path_tests(#[case] path: &str, #[case] expected: bool) {
+        // Arrange
+
+        // Act
+        let actual = is_absolute_path(path);
+
+        // Assert
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn conflicting_configurations_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = ConflictingConfigurations::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.configuration_conflicts);
+    }
+
+    #[test]
+    fn conflicting_configurations_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_CONFLICTING_CONFIGURATIONS).unwrap();
+        let mut validator = ConflictingConfigurations::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.configuration_conflicts);
+        assert_eq!(vec!["a|Debug|Any CPU".to_owned()], validator.problems());
+        assert_eq!(
+            BTreeSet::from(["Debug", "Release"]),
+            validator.conflicts[0].3
+        );
+    }
+
+    #[test]
+    fn conflicting_configurations_ignores_diverging_build() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DIVERGING_BUILD_CONFIGURATION).unwrap();
+        let mut validator = ConflictingConfigurations::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.configuration_conflicts);
+    }
+
+    #[test]
+    fn diverging_build_configurations_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = DivergingBuildConfigurations::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.diverging_build_configurations);
+    }
+
+    #[test]
+    fn diverging_build_configurations_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_DIVERGING_BUILD_CONFIGURATION).unwrap();
+        let mut validator = DivergingBuildConfigurations::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.diverging_build_configurations);
+        assert_eq!(
+            vec![("a", "Debug", "Any CPU", "Debug", "Release")],
+            validator.divergences
+        );
+        assert_eq!(vec!["a|Debug|Any CPU".to_owned()], validator.problems());
+    }
+
+    #[test]
+    fn folder_dependencies_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = FolderDependencies::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.folder_dependencies);
+    }
+
+    #[test]
+    fn folder_dependencies_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_FOLDER_DEPENDENCY).unwrap();
+        let mut validator = FolderDependencies::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.folder_dependencies);
+        assert_eq!(vec!["b|folder".to_owned()], validator.problems());
+    }
+
+    #[test]
+    fn missing_solution_items_validation_correct() {
+        // Arrange
+        let (dir, sln) = solution_dir(&[]);
+        std::fs::write(dir.path().join(".editorconfig"), "root = true").unwrap();
+        let solution = parse_at(SOLUTION_WITH_SOLUTION_ITEMS, &sln);
+        let mut validator = MissingSolutionItems::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.missing_solution_items);
+    }
+
+    #[test]
+    fn missing_solution_items_validation_incorrect() {
+        // Arrange
+        let (_dir, sln) = solution_dir(&[]);
+        let solution = parse_at(SOLUTION_WITH_SOLUTION_ITEMS, &sln);
+        let mut validator = MissingSolutionItems::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.missing_solution_items);
+        assert_eq!(vec![".editorconfig".to_owned()], validator.problems());
+    }
+
+    #[test]
+    fn missing_solution_items_validation_escaped_path() {
+        // Arrange
+        let (dir, sln) = solution_dir(&[]);
+        std::fs::create_dir(dir.path().join("build files")).unwrap();
+        std::fs::write(dir.path().join("build files").join(".editorconfig"), "").unwrap();
+        let content = SOLUTION_WITH_SOLUTION_ITEMS.replace(
+            ".editorconfig = .editorconfig",
+            r"build%20files\.editorconfig = build%20files\.editorconfig",
+        );
+        let solution = parse_at(&content, &sln);
+        let mut validator = MissingSolutionItems::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.missing_solution_items);
+    }
+
+    #[test]
+    fn ignored_folder_suppresses_missing_solution_items() {
+        // Arrange
+        let (_dir, sln) = solution_dir(&[]);
+        let solution = parse_at(SOLUTION_WITH_SOLUTION_ITEMS, &sln);
+        let mut validator = Validate::new(false)
+            .with_solution_items_check(true)
+            .with_ignored_projects(&["{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}".to_owned()]);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, validator.statistic.borrow().missing_solution_items);
+    }
+
+    #[test]
+    fn integration_test_solution_items_opt_in() {
+        // Arrange
+        let (_dir, sln) = solution_dir(&[]);
+        let solution = parse_at(SOLUTION_WITH_SOLUTION_ITEMS, &sln);
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_solution_items_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().missing_solution_items);
+        assert_eq!(1, validator.statistic.borrow().missing_solution_items);
+    }
+
+    #[test]
+    fn malformed_header_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = MalformedHeader::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.malformed_headers);
+    }
+
+    #[test]
+    fn malformed_header_validation_incorrect() {
+        // Arrange
+        let content = CORRECT_SOLUTION.replace("Solution File, Format", "Project File,");
+        let solution = solp::parse_str(&content).unwrap();
+        let mut validator = MalformedHeader::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.malformed_headers);
+        assert_eq!(
+            vec!["Microsoft Visual Studio Project File, Version 8.00".to_owned()],
+            validator.problems()
+        );
+    }
+
+    #[test]
+    fn orphaned_nesting_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = OrphanedNesting::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.orphaned_nestings);
+    }
+
+    #[test]
+    fn orphaned_nesting_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_ORPHANED_NESTED_PROJECT).unwrap();
+        let mut validator = OrphanedNesting::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.orphaned_nestings);
+        assert_eq!(
+            vec![
+                "{3AF54C8A-10BF-4332-9147-F68ED9862032}|{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+                    .to_owned()
+            ],
+            validator.problems()
+        );
+    }
+
+    #[test]
+    fn transitive_references_validation_correct() {
+        // Arrange
+        let (_dir, sln) =
+            solution_dir(&[("a", PROJECT_REFERENCING_B), ("b", PROJECT_REFERENCING_A)]);
+        let solution = parse_at(SOLUTION_WITH_REFERENCING_PROJECTS, &sln);
+        let mut validator = TransitiveReferences::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.transitive_references);
+    }
+
+    #[test]
+    fn integration_test_follow_project_references_opt_in() {
+        // Arrange
+        let (_dir, sln) =
+            solution_dir(&[("a", PROJECT_REFERENCING_B), ("b", PROJECT_REFERENCING_A)]);
+        let mut solution = parse_at(SOLUTION_WITH_REFERENCING_PROJECTS, &sln);
+        solution.projects.truncate(1);
+        let mut validator = Validate::new(false).with_follow_project_references(true);
+
+        // Act
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(1, validator.statistic.borrow().transitive_references);
+    }
+
+    #[test]
+    fn missing_imports_validation_incorrect() {
+        // Arrange
+        let (_dir, sln) = solution_dir(&[
+            ("a", PROJECT_IMPORTING_MISSING_PROPS),
+            ("b", PROJECT_REFERENCING_A),
+        ]);
+        let solution = parse_at(SOLUTION_WITH_REFERENCING_PROJECTS, &sln);
+        let mut validator = MissingImports::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.missing_imports);
+        assert_eq!(
+            vec!["a/a.csproj|build/missing.props".to_owned()],
+            validator.problems()
+        );
+    }
+
+    #[test]
+    fn integration_test_imports_check_opt_in() {
+        // Arrange
+        let (_dir, sln) = solution_dir(&[
+            ("a", PROJECT_IMPORTING_MISSING_PROPS),
+            ("b", PROJECT_REFERENCING_A),
+        ]);
+        let solution = parse_at(SOLUTION_WITH_REFERENCING_PROJECTS, &sln);
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_imports_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().missing_imports);
+        assert_eq!(1, validator.statistic.borrow().missing_imports);
+    }
+
+    #[test]
+    fn shared_output_paths_validation_incorrect() {
+        // Arrange
+        let (_dir, sln) = solution_dir(&[
+            ("a", PROJECT_WITH_SHARED_OUTPUT),
+            ("b", PROJECT_WITH_SHARED_OUTPUT),
+        ]);
+        let solution = parse_at(SOLUTION_WITH_CONFIGURED_PROJECTS, &sln);
+        let mut validator = SharedOutputPaths::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.shared_output_paths);
+        assert_eq!(vec!["Debug|Any CPU|out".to_owned()], validator.problems());
+    }
+
+    #[test]
+    fn shared_output_paths_uses_mapped_project_platform() {
+        // Arrange
+        let (_dir, sln) = solution_dir(&[
+            ("a", PROJECT_WITH_PLATFORM_OUTPUTS),
+            ("b", PROJECT_WITH_PLATFORM_OUTPUTS),
+        ]);
+        let solution = parse_at(SOLUTION_WITH_PROJECTS_MAPPED_TO_PLATFORMS, &sln);
+        let mut validator = SharedOutputPaths::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.shared_output_paths);
+    }
+
+    #[test]
+    fn integration_test_output_paths_opt_in() {
+        // Arrange
+        let (_dir, sln) = solution_dir(&[
+            ("a", PROJECT_WITH_SHARED_OUTPUT),
+            ("b", PROJECT_WITH_SHARED_OUTPUT),
+        ]);
+        let solution = parse_at(SOLUTION_WITH_CONFIGURED_PROJECTS, &sln);
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_output_paths_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().shared_output_paths);
+        assert_eq!(1, validator.statistic.borrow().shared_output_paths);
+    }
+
+    #[test]
+    fn absolute_paths_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = AbsolutePaths::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.absolute_paths);
+    }
+
+    #[test]
+    fn absolute_paths_validation_incorrect() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_ABSOLUTE_PATH).unwrap();
+        let mut validator = AbsolutePaths::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(!validator.validation_result());
+        assert_eq!(1, statistic.absolute_paths);
+        assert_eq!(
+            vec!["b|C:\\src\\b\\b.csproj".to_owned()],
+            validator.problems()
+        );
+    }
+
+    #[test]
+    fn outside_references_validation_correct() {
+        // Arrange
+        let solution = solp::parse_str(CORRECT_SOLUTION).unwrap();
+        let mut validator = OutsideReferences::new(&solution);
+        let mut statistic = Statistic::default();
+
+        // Act
+        validator.validate(&mut statistic);
+
+        // Assert
+        assert!(validator.validation_result());
+        assert_eq!(0, statistic.outside_references);
+    }
+
+    #[test]
+    fn integration_test_outside_references_opt_in() {
+        // Arrange
+        let (_dir, sln) =
+            solution_dir(&[("a", PROJECT_REFERENCING_B), ("b", PROJECT_REFERENCING_A)]);
+        let mut solution = parse_at(SOLUTION_WITH_REFERENCING_PROJECTS, &sln);
+        solution.projects.truncate(1);
+        let mut default = Validate::new(false);
+        let mut validator = Validate::new(false).with_outside_references_check(true);
+
+        // Act
+        default.ok(&solution);
+        validator.ok(&solution);
+
+        // Assert
+        assert_eq!(0, default.statistic.borrow().outside_references);
+        assert_eq!(1, validator.statistic.borrow().outside_references);
+    }
+
+    #[test]
+    fn oneline_status_of_solution_with_cycles() {
+        // Arrange
+        let mut solution = solp::parse_str(SOLUTION_WITH_CYCLES).unwrap();
+        solution.path = "cycles.sln";
+        let validate = Validate::new(false).with_oneline(true);
+        let mut validators = validate.validators(&solution);
+
+        // Act
+        for v in &mut validators {
+            v.validate(&mut Statistic::default());
+        }
+        let actual = status_line(&validators, solution.path, false);
+
+        // Assert
+        assert_eq!("CYCLE cycles.sln", actual);
+    }
+
+    #[test]
+    fn oneline_status_with_score() {
+        // Arrange
+        let mut solution = solp::parse_str(SOLUTION_WITH_CYCLES).unwrap();
+        solution.path = "cycles.sln";
+        let validate = Validate::new(false).with_oneline(true).with_score(true);
+        let mut validators = validate.validators(&solution);
+
+        // Act
+        for v in &mut validators {
+            v.validate(&mut Statistic::default());
+        }
+        let actual = status_line(&validators, solution.path, true);
+
+        // Assert
+        let score = solution_score(&validators);
+        assert_eq!(format!("CYCLE {score} cycles.sln"), actual);
+        assert!(score < 100);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[rstest]
+    #[case("/base/x/bad.sln", Some("/base"), "NOTPARSED x/bad.sln")]
+    #[case("/other/bad.sln", Some("/base"), "NOTPARSED /other/bad.sln")]
+    #[case("/base/x/bad.sln", None, "NOTPARSED /base/x/bad.sln")]
+    #[trace]
+    fn not_parsed_line_tests(
+        #[case] path: &str,
+        #[case] base: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        // Arrange
+        let base = base.map(Path::new);
+
+        // Act
+        let actual = not_parsed_line(path, base);
+
+        // Assert
+        assert_eq!(expected, actual);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn display_path_relative_to_base() {
+        // Arrange
+        let path = Path::new("/base/x/a/a.csproj");
+
+        // Act
+        let actual = display_path(path, Some(Path::new("/base")));
+
+        // Assert
+        assert_eq!("x/a/a.csproj", actual);
+    }
+
+    #[test]
+    fn stats_json_matches_statistic() {
+        // Arrange
+        let solution = solp::parse_str(SOLUTION_WITH_CYCLES).unwrap();
+        let mut validator = Validate::new(false);
+        validator.ok(&solution);
+        validator.err("bad.sln");
+
+        // Act
+        let actual = validator.stats_json();
+
+        // Assert
+        let stats: serde_json::Value = serde_json::from_str(&actual).unwrap();
+        let summary = validator.summary();
+        assert!(!actual.contains('\n'));
+        assert_eq!(1, stats["cycles"]);
+        assert_eq!(summary.cycles, stats["cycles"]);
+        assert_eq!(1, stats["parsed"]);
+        assert_eq!(1, stats["not_parsed"]);
+        assert_eq!(2, stats["total"]);
+    }
+
+    /// Creates temporary solution directory with `name/name.csproj` projects of the contents specified.
+    /// Returns the directory to keep it alive and the path of `s.sln` in it
+    fn solution_dir(projects: &[(&str, &str)]) -> (tempfile::TempDir, String) {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, content) in projects {
+            std::fs::create_dir(dir.path().join(name)).unwrap();
+            std::fs::write(
+                dir.path().join(name).join(format!("{name}.csproj")),
+                content,
+            )
+            .unwrap();
+        }
+        let sln = dir.path().join("s.sln").to_str().unwrap().to_owned();
+        (dir, sln)
+    }
+
+    fn parse_at<'a>(content: &'a str, path: &'a str) -> solp::api::Solution<'a> {
+        let mut solution = solp::parse_str(content).unwrap();
+        solution.path = path;
+        solution
+    }
+
+    const CORRECT_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 8.00
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest", "gtest.vcproj", "{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}"
+	ProjectSection(ProjectDependencies) = postProject
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_main", "gtest_main.vcproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+	ProjectSection(ProjectDependencies) = postProject
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_unittest", "gtest_unittest.vcproj", "{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}"
+	ProjectSection(ProjectDependencies) = postProject
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_prod_test", "gtest_prod_test.vcproj", "{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}"
+	ProjectSection(ProjectDependencies) = postProject
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfiguration) = preSolution
+		Debug = Debug
+		Release = Release
+	EndGlobalSection
+	GlobalSection(ProjectConfiguration) = postSolution
+		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Debug.ActiveCfg = Debug|Win32
+		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Debug.Build.0 = Debug|Win32
+		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Release.ActiveCfg = Release|Win32
+		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Release.Build.0 = Release|Win32
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug.ActiveCfg = Debug|Win32
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug.Build.0 = Debug|Win32
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Release.ActiveCfg = Release|Win32
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Release.Build.0 = Release|Win32
+		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Debug.ActiveCfg = Debug|Win32
+		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Debug.Build.0 = Debug|Win32
+		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Release.ActiveCfg = Release|Win32
+		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Release.Build.0 = Release|Win32
+		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Debug.ActiveCfg = Debug|Win32
+		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Debug.Build.0 = Debug|Win32
+		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Release.ActiveCfg = Release|Win32
+		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Release.Build.0 = Release|Win32
+	EndGlobalSection
+	GlobalSection(ExtensibilityGlobals) = postSolution
+	EndGlobalSection
+	GlobalSection(ExtensibilityAddIns) = postSolution
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const MALFORMED_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj"
+"#;
+
+    const SOLUTION_WITH_MISSING_PROJECT_CONFIGS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 11.00
+# Visual Studio 2010
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|x86.Build.0 = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|x86.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.Build.0 = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(SolutionProperties) = preSolution
+		HideSolutionNode = FALSE
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_DANGLINGS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 8.00
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest", "gtest.vcproj", "{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}"
+	ProjectSection(ProjectDependencies) = postProject
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_main", "gtest_main.vcproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+	ProjectSection(ProjectDependencies) = postProject
+	EndProjectSection
+EndProject
+Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_unittest", "gtest_unittest.vcproj", "{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}"
+	ProjectSection(ProjectDependencies) = postProject
+	EndProjectSection
+EndProject
+Global
+	GlobalSection(SolutionConfiguration) = preSolution
+		Debug = Debug
+		Release = Release
+	EndGlobalSection
+	GlobalSection(ProjectConfiguration) = postSolution
+		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Debug.ActiveCfg = Debug|Win32
+		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Debug.Build.0 = Debug|Win32
+		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Release.ActiveCfg = Release|Win32
+		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Release.Build.0 = Release|Win32
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug.ActiveCfg = Debug|Win32
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug.Build.0 = Debug|Win32
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Release.ActiveCfg = Release|Win32
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Release.Build.0 = Release|Win32
+		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Debug.ActiveCfg = Debug|Win32
+		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Debug.Build.0 = Debug|Win32
+		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Release.ActiveCfg = Release|Win32
+		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Release.Build.0 = Release|Win32
+		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Debug.ActiveCfg = Debug|Win32
+		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Debug.Build.0 = Debug|Win32
+		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Release.ActiveCfg = Release|Win32
+		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Release.Build.0 = Release|Win32
+	EndGlobalSection
+	GlobalSection(ExtensibilityGlobals) = postSolution
+	EndGlobalSection
+	GlobalSection(ExtensibilityAddIns) = postSolution
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_CYCLES: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 15
+VisualStudioVersion = 15.0.26403.0
+MinimumVisualStudioVersion = 10.0.40219.1
+Project("{930C7802-8A8C-48F9-8165-68863BCCD9DD}") = "logviewer.install", "logviewer.install\logviewer.install.wixproj", "{27060CA7-FB29-42BC-BA66-7FC80D498354}"
+	ProjectSection(ProjectDependencies) = postProject
+		{405827CB-84E1-46F3-82C9-D889892645AC} = {405827CB-84E1-46F3-82C9-D889892645AC}
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D} = {CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}
+	EndProjectSection
+EndProject
+Project("{930C7802-8A8C-48F9-8165-68863BCCD9DD}") = "logviewer.install.bootstrap", "logviewer.install.bootstrap\logviewer.install.bootstrap.wixproj", "{1C0ED62B-D506-4E72-BBC2-A50D3926466E}"
+	ProjectSection(ProjectDependencies) = postProject
+		{27060CA7-FB29-42BC-BA66-7FC80D498354} = {27060CA7-FB29-42BC-BA66-7FC80D498354}
+	EndProjectSection
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "solution items", "solution items", "{3B960F8F-AD5D-45E7-92C0-05B65E200AC4}"
+	ProjectSection(SolutionItems) = preProject
+		.editorconfig = .editorconfig
+		appveyor.yml = appveyor.yml
+		logviewer.xml = logviewer.xml
+		WiX.msbuild = WiX.msbuild
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.tests", "logviewer.tests\logviewer.tests.csproj", "{939DD379-CDC8-47EF-8D37-0E5E71D99D30}"
+	ProjectSection(ProjectDependencies) = postProject
+		{383C08FC-9CAC-42E5-9B02-471561479A74} = {383C08FC-9CAC-42E5-9B02-471561479A74}
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.logic", "logviewer.logic\logviewer.logic.csproj", "{383C08FC-9CAC-42E5-9B02-471561479A74}"
+	ProjectSection(ProjectDependencies) = postProject
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30} = {939DD379-CDC8-47EF-8D37-0E5E71D99D30}
+	EndProjectSection
+EndProject
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = ".nuget", ".nuget", "{B720ED85-58CF-4840-B1AE-55B0049212CC}"
+	ProjectSection(SolutionItems) = preProject
+		.nuget\NuGet.Config = .nuget\NuGet.Config
+	EndProjectSection
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.engine", "logviewer.engine\logviewer.engine.csproj", "{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.install.mca", "logviewer.install.mca\logviewer.install.mca.csproj", "{405827CB-84E1-46F3-82C9-D889892645AC}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.ui", "logviewer.ui\logviewer.ui.csproj", "{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.bench", "logviewer.bench\logviewer.bench.csproj", "{75E0C034-44C8-461B-A677-9A19566FE393}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Debug|Mixed Platforms = Debug|Mixed Platforms
+		Debug|x86 = Debug|x86
+		Release|Any CPU = Release|Any CPU
+		Release|Mixed Platforms = Release|Mixed Platforms
+		Release|x86 = Release|x86
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Any CPU.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Mixed Platforms.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|Mixed Platforms.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|x86.ActiveCfg = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Debug|x86.Build.0 = Debug|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Any CPU.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Any CPU.Build.0 = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Mixed Platforms.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|Mixed Platforms.Build.0 = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|x86.ActiveCfg = Release|x86
+		{27060CA7-FB29-42BC-BA66-7FC80D498354}.Release|x86.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Any CPU.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Mixed Platforms.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|Mixed Platforms.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|x86.ActiveCfg = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Debug|x86.Build.0 = Debug|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Any CPU.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Any CPU.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Mixed Platforms.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|Mixed Platforms.Build.0 = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|x86.ActiveCfg = Release|x86
+		{1C0ED62B-D506-4E72-BBC2-A50D3926466E}.Release|x86.Build.0 = Release|x86
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Any CPU.Build.0 = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{939DD379-CDC8-47EF-8D37-0E5E71D99D30}.Release|x86.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Any CPU.Build.0 = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{383C08FC-9CAC-42E5-9B02-471561479A74}.Release|x86.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Any CPU.Build.0 = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{90E3A68D-C96D-4764-A1D0-F73D9F474BE4}.Release|x86.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Any CPU.Build.0 = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{405827CB-84E1-46F3-82C9-D889892645AC}.Release|x86.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Any CPU.Build.0 = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{CFBAE2FB-6E3F-44CF-9FC9-372D6EA8DD3D}.Release|x86.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Mixed Platforms.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|Mixed Platforms.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|x86.ActiveCfg = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Debug|x86.Build.0 = Debug|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Any CPU.Build.0 = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Mixed Platforms.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|Mixed Platforms.Build.0 = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|x86.ActiveCfg = Release|Any CPU
+		{75E0C034-44C8-461B-A677-9A19566FE393}.Release|x86.Build.0 = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(SolutionProperties) = preSolution
+		HideSolutionNode = FALSE
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_SAME_PATH_DIFFERENT_GUIDS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", ".\A\a.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_FORMAT_PRODUCT_MISMATCH: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 2010
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_TFS_BINDINGS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio 2013
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(TeamFoundationVersionControl) = preSolution
+		SccNumberOfProjects = 3
+		SccEnterpriseProvider = {4CA58AB2-18FA-4F8D-95D4-32DDF27D184C}
+		SccTeamFoundationServer = http://tfs:8080/tfs/defaultcollection
+		SccLocalPath0 = .
+		SccProjectUniqueName1 = a\\a.csproj
+		SccProjectName1 = a
+		SccLocalPath1 = a
+		SccProjectUniqueName2 = b\\b.csproj
+		SccProjectName2 = b
+		SccLocalPath2 = b
+	EndGlobalSection
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
 
-        // Act
-        validator.validate(&mut statistic);
+    const SOLUTION_WITH_REFERENCING_PROJECTS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
 
-        // Assert
-        assert!(!validator.validation_result());
-        assert_eq!(1, statistic.dangings);
-    }
+    const PROJECT_WITH_PLATFORM_OUTPUTS: &str = r#"<Project ToolsVersion="15.0">
+    <PropertyGroup>
+      <OutputPath>..\out\</OutputPath>
+    </PropertyGroup>
+    <PropertyGroup Condition="'$(Configuration)|$(Platform)' == 'Debug|x64'">
+      <OutputPath>..\out\x64\</OutputPath>
+    </PropertyGroup>
+    <PropertyGroup Condition="'$(Configuration)|$(Platform)' == 'Debug|x86'">
+      <OutputPath>..\out\x86\</OutputPath>
+    </PropertyGroup>
+  </Project>"#;
 
-    #[test]
-    fn print_statistic_test() {
-        // Arrange
-        let s = Statistic::default();
+    const SOLUTION_WITH_PROJECTS_MAPPED_TO_PLATFORMS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|x64
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|x64
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|x86
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|x86
+	EndGlobalSection
+EndGlobal
+"#;
 
-        // Act
-        println!("{s}");
+    const PROJECT_IMPORTING_MISSING_PROPS: &str = r#"<Project ToolsVersion="15.0">
+    <Import Project="..\build\missing.props" />
+    <Import Project="$(MSBuildToolsPath)\Microsoft.CSharp.targets" />
+  </Project>"#;
 
-        // Assert
-    }
+    const PROJECT_REFERENCING_B: &str = r#"<Project Sdk="Microsoft.NET.Sdk">
+    <ItemGroup>
+      <ProjectReference Include="..\b\b.csproj" />
+    </ItemGroup>
+  </Project>"#;
 
-    const CORRECT_SOLUTION: &str = r#"
-Microsoft Visual Studio Solution File, Format Version 8.00
-Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest", "gtest.vcproj", "{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}"
-	ProjectSection(ProjectDependencies) = postProject
-	EndProjectSection
+    const SOLUTION_WITH_LOWERCASE_GUIDS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+VisualStudioVersion = 17.0.31903.59
+MinimumVisualStudioVersion = 10.0.40219.1
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-a6c2-4161-95b1-813b46610ea7}"
 EndProject
-Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_main", "gtest_main.vcproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
 	ProjectSection(ProjectDependencies) = postProject
+		{78965571-a6c2-4161-95b1-813b46610ea7} = {78965571-a6c2-4161-95b1-813b46610ea7}
 	EndProjectSection
 EndProject
-Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_unittest", "gtest_unittest.vcproj", "{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}"
-	ProjectSection(ProjectDependencies) = postProject
-	EndProjectSection
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-a6c2-4161-95b1-813b46610ea7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-a6c2-4161-95b1-813b46610ea7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_NAMES_DIFFERENT_BY_CASE: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "Lib", "Lib\Lib.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
 EndProject
-Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_prod_test", "gtest_prod_test.vcproj", "{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}"
-	ProjectSection(ProjectDependencies) = postProject
-	EndProjectSection
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "lib", "src\lib\lib.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "LIB", "tests\LIB\LIB.csproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "app", "app\app.csproj", "{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}"
 EndProject
 Global
-	GlobalSection(SolutionConfiguration) = preSolution
-		Debug = Debug
-		Release = Release
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
 	EndGlobalSection
-	GlobalSection(ProjectConfiguration) = postSolution
-		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Debug.ActiveCfg = Debug|Win32
-		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Debug.Build.0 = Debug|Win32
-		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Release.ActiveCfg = Release|Win32
-		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Release.Build.0 = Release|Win32
-		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug.ActiveCfg = Debug|Win32
-		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug.Build.0 = Debug|Win32
-		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Release.ActiveCfg = Release|Win32
-		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Release.Build.0 = Release|Win32
-		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Debug.ActiveCfg = Debug|Win32
-		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Debug.Build.0 = Debug|Win32
-		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Release.ActiveCfg = Release|Win32
-		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Release.Build.0 = Release|Win32
-		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Debug.ActiveCfg = Debug|Win32
-		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Debug.Build.0 = Debug|Win32
-		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Release.ActiveCfg = Release|Win32
-		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Release.Build.0 = Release|Win32
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_PROJECT_ONLY_PLATFORM: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Debug|x86 = Debug|x86
 	EndGlobalSection
-	GlobalSection(ExtensibilityGlobals) = postSolution
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|x86.ActiveCfg = Debug|x86
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|ARM.ActiveCfg = Debug|ARM
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|ARM.Build.0 = Debug|ARM
 	EndGlobalSection
-	GlobalSection(ExtensibilityAddIns) = postSolution
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_ABSOLUTE_PATH: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "b", "C:\src\b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
 	EndGlobalSection
 EndGlobal
 "#;
 
-    const SOLUTION_WITH_MISSING_PROJECT_CONFIGS: &str = r#"
-Microsoft Visual Studio Solution File, Format Version 11.00
-# Visual Studio 2010
-Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+    const SOLUTION_WITH_CONFLICTING_CONFIGURATIONS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
 EndProject
-Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
 EndProject
 Global
 	GlobalSection(SolutionConfigurationPlatforms) = preSolution
@@ -610,69 +5529,158 @@ Global
 	EndGlobalSection
 	GlobalSection(ProjectConfigurationPlatforms) = postSolution
 		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Release|Any CPU
 		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
-		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|x86.ActiveCfg = Debug|Any CPU
-		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|x86.Build.0 = Debug|Any CPU
 		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
-		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
 		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
 		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
-		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|x86.ActiveCfg = Debug|Any CPU
-		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|x86.Build.0 = Debug|Any CPU
 		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.ActiveCfg = Release|Any CPU
-		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Release|Any CPU.Build.0 = Release|Any CPU
-	EndGlobalSection
-	GlobalSection(SolutionProperties) = preSolution
-		HideSolutionNode = FALSE
 	EndGlobalSection
 EndGlobal
 "#;
 
-    const SOLUTION_WITH_DANGLINGS: &str = r#"
-Microsoft Visual Studio Solution File, Format Version 8.00
-Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest", "gtest.vcproj", "{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}"
-	ProjectSection(ProjectDependencies) = postProject
+    const SOLUTION_WITH_SOLUTION_ITEMS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "Solution Items", "Solution Items", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+	ProjectSection(SolutionItems) = preProject
+		.editorconfig = .editorconfig
 	EndProjectSection
 EndProject
-Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_main", "gtest_main.vcproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_DIVERGING_BUILD_CONFIGURATION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+		Release|Any CPU = Release|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.ActiveCfg = Release|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Release|Any CPU.Build.0 = Release|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_FOLDER_DEPENDENCY: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "folder", "folder", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
 	ProjectSection(ProjectDependencies) = postProject
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {78965571-A6C2-4161-95B1-813B46610EA7}
+		{f619a230-72a6-48b8-b4dc-4d0d3f9b4d3e} = {f619a230-72a6-48b8-b4dc-4d0d3f9b4d3e}
 	EndProjectSection
 EndProject
-Project("{8BC9CEB8-8B4A-11D0-8D11-00A0C91BC942}") = "gtest_unittest", "gtest_unittest.vcproj", "{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}"
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_DUPLICATE_DEPENDENCY: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+VisualStudioVersion = 17.0.31903.59
+MinimumVisualStudioVersion = 10.0.40219.1
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
 	ProjectSection(ProjectDependencies) = postProject
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {78965571-A6C2-4161-95B1-813B46610EA7}
+		{78965571-A6C2-4161-95B1-813B46610EA7} = {78965571-A6C2-4161-95B1-813B46610EA7}
 	EndProjectSection
 EndProject
 Global
-	GlobalSection(SolutionConfiguration) = preSolution
-		Debug = Debug
-		Release = Release
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
 	EndGlobalSection
-	GlobalSection(ProjectConfiguration) = postSolution
-		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Debug.ActiveCfg = Debug|Win32
-		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Debug.Build.0 = Debug|Win32
-		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Release.ActiveCfg = Release|Win32
-		{C8F6C172-56F2-4E76-B5FA-C3B423B31BE7}.Release.Build.0 = Release|Win32
-		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug.ActiveCfg = Debug|Win32
-		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug.Build.0 = Debug|Win32
-		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Release.ActiveCfg = Release|Win32
-		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Release.Build.0 = Release|Win32
-		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Debug.ActiveCfg = Debug|Win32
-		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Debug.Build.0 = Debug|Win32
-		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Release.ActiveCfg = Release|Win32
-		{4D9FDFB5-986A-4139-823C-F4EE0ED481A1}.Release.Build.0 = Release|Win32
-		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Debug.ActiveCfg = Debug|Win32
-		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Debug.Build.0 = Debug|Win32
-		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Release.ActiveCfg = Release|Win32
-		{24848551-EF4F-47E8-9A9D-EA4D49BC3ECA}.Release.Build.0 = Release|Win32
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.Build.0 = Debug|Any CPU
 	EndGlobalSection
-	GlobalSection(ExtensibilityGlobals) = postSolution
+EndGlobal
+"#;
+
+    const SOLUTION_WITH_EMPTY_PROJECT_NAME: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+VisualStudioVersion = 17.0.31903.59
+MinimumVisualStudioVersion = 10.0.40219.1
+Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = "folder", "folder", "{F619A230-72A6-48B8-B4DC-4D0D3F9B4D3E}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "", "", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
 	EndGlobalSection
-	GlobalSection(ExtensibilityAddIns) = postSolution
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
 	EndGlobalSection
 EndGlobal
 "#;
 
-    const SOLUTION_WITH_CYCLES: &str = r#"
+    const SOLUTION_WITH_UNBUILT_PROJECTS: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "b", "b\b.csproj", "{D9523F4D-6CB7-4431-85F6-8122F55EB144}"
+EndProject
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "c", "c\c.csproj", "{3AF54C8A-10BF-4332-9147-F68ED9862032}"
+EndProject
+Global
+	GlobalSection(SolutionConfigurationPlatforms) = preSolution
+		Debug|Any CPU = Debug|Any CPU
+	EndGlobalSection
+	GlobalSection(ProjectConfigurationPlatforms) = postSolution
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+		{D9523F4D-6CB7-4431-85F6-8122F55EB144}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+		{3AF54C8A-10BF-4332-9147-F68ED9862032}.Debug|Any CPU.Deploy.0 = Debug|Any CPU
+	EndGlobalSection
+EndGlobal
+"#;
+
+    const SPACE_INDENTED_SOLUTION: &str = r#"
+Microsoft Visual Studio Solution File, Format Version 12.00
+# Visual Studio Version 17
+Project("{9A19103F-16F7-4668-BE54-9A1E7A4F7556}") = "a", "a\a.csproj", "{78965571-A6C2-4161-95B1-813B46610EA7}"
+EndProject
+Global
+    GlobalSection(SolutionConfigurationPlatforms) = preSolution
+        Debug|Any CPU = Debug|Any CPU
+    EndGlobalSection
+    GlobalSection(ProjectConfigurationPlatforms) = postSolution
+        {78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.ActiveCfg = Debug|Any CPU
+        {78965571-A6C2-4161-95B1-813B46610EA7}.Debug|Any CPU.Build.0 = Debug|Any CPU
+    EndGlobalSection
+EndGlobal
+"#;
+
+    const REAL_SOLUTION: &str = r#"
 Microsoft Visual Studio Solution File, Format Version 12.00
 # Visual Studio 15
 VisualStudioVersion = 15.0.26403.0
@@ -702,9 +5710,6 @@ Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.tests", "logviewe
 	EndProjectSection
 EndProject
 Project("{FAE04EC0-301F-11D3-BF4B-00C04F79EFBC}") = "logviewer.logic", "logviewer.logic\logviewer.logic.csproj", "{383C08FC-9CAC-42E5-9B02-471561479A74}"
-	ProjectSection(ProjectDependencies) = postProject
-		{939DD379-CDC8-47EF-8D37-0E5E71D99D30} = {939DD379-CDC8-47EF-8D37-0E5E71D99D30}
-	EndProjectSection
 EndProject
 Project("{2150E333-8FDC-42A3-9474-1A3956D46DE8}") = ".nuget", ".nuget", "{B720ED85-58CF-4840-B1AE-55B0049212CC}"
 	ProjectSection(SolutionItems) = preProject
@@ -0,0 +1,174 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use color_eyre::eyre::{Context, Result};
+use notify::{RecursiveMode, Watcher};
+
+/// Time to wait for more file system events after the first one
+/// so that saving several files at once re-validates solutions only once
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Reverse index of solutions found in the watched directory
+/// used to find solutions affected by changed files
+pub struct SolutionIndex {
+    extension: String,
+    solutions: BTreeSet<PathBuf>,
+}
+
+impl SolutionIndex {
+    /// Finds all solutions with the extension specified inside directory.
+    /// Files aren't read so that the ones that cannot be parsed yet are indexed too
+    #[must_use]
+    pub fn scan(dir: &str, extension: &str, recursively: bool) -> Self {
+        let solutions = solp::find_solutions(dir, extension, recursively)
+            .map(|path| absolute(Path::new(&path)))
+            .collect();
+        Self::new(extension, solutions)
+    }
+
+    #[must_use]
+    pub fn new(extension: &str, solutions: BTreeSet<PathBuf>) -> Self {
+        Self {
+            extension: extension.trim_start_matches('.').to_owned(),
+            solutions,
+        }
+    }
+
+    /// Solutions indexed so far
+    #[must_use]
+    pub fn solutions(&self) -> &BTreeSet<PathBuf> {
+        &self.solutions
+    }
+
+    /// Finds solutions which directory subtree contains the changed path.
+    /// Changed solution files are added into index or removed from it if they don't exist anymore
+    pub fn affected(&mut self, changed: &Path) -> BTreeSet<PathBuf> {
+        let changed = absolute(changed);
+        if changed
+            .extension()
+            .is_some_and(|e| e == self.extension.as_str())
+        {
+            if changed.is_file() {
+                self.solutions.insert(changed.clone());
+            } else {
+                self.solutions.remove(&changed);
+            }
+        }
+        self.solutions
+            .iter()
+            .filter(|s| s.parent().is_some_and(|dir| changed.starts_with(dir)))
+            .cloned()
+            .collect()
+    }
+}
+
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Watches the directory specified and calls handler with solutions affected
+/// by every batch of file system changes. Blocks until watching fails
+pub fn watch_dir(
+    dir: &str,
+    mut index: SolutionIndex,
+    mut on_change: impl FnMut(&BTreeSet<PathBuf>),
+) -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(tx).wrap_err("Failed to create file system watcher")?;
+    watcher
+        .watch(Path::new(dir), RecursiveMode::Recursive)
+        .wrap_err_with(|| format!("Failed to watch directory: {dir}"))?;
+
+    while let Ok(event) = rx.recv() {
+        let mut events = vec![event];
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            events.push(event);
+        }
+        let affected = events
+            .into_iter()
+            .filter_map(std::result::Result::ok)
+            .filter(|e| !e.kind.is_access())
+            .flat_map(|e| e.paths)
+            .collect::<BTreeSet<PathBuf>>()
+            .iter()
+            .flat_map(|changed| index.affected(changed))
+            .filter(|s| s.is_file())
+            .collect::<BTreeSet<PathBuf>>();
+        if !affected.is_empty() {
+            on_change(&affected);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn affected_by_project_in_subtree() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("root.sln");
+        let nested = dir.path().join("a").join("a.sln");
+        let other = dir.path().join("b").join("b.sln");
+        let mut index =
+            SolutionIndex::new("sln", BTreeSet::from([root.clone(), nested.clone(), other]));
+
+        // Act
+        let actual = index.affected(&dir.path().join("a").join("p").join("p.csproj"));
+
+        // Assert
+        assert_eq!(BTreeSet::from([root, nested]), actual);
+    }
+
+    #[test]
+    fn new_solution_indexed() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("new.sln");
+        fs::write(&path, "").unwrap();
+        let mut index = SolutionIndex::new("sln", BTreeSet::new());
+
+        // Act
+        let actual = index.affected(&path);
+
+        // Assert
+        assert_eq!(BTreeSet::from([path.clone()]), actual);
+        assert!(index.solutions().contains(&path));
+    }
+
+    #[test]
+    fn removed_solution_unindexed() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("removed.sln");
+        let mut index = SolutionIndex::new("sln", BTreeSet::from([path.clone()]));
+
+        // Act
+        let actual = index.affected(&path);
+
+        // Assert
+        assert!(actual.is_empty());
+        assert!(index.solutions().is_empty());
+    }
+
+    #[test]
+    fn scan_finds_solutions() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("a")).unwrap();
+        fs::write(dir.path().join("a").join("a.sln"), "").unwrap();
+        fs::write(dir.path().join("a").join("a.csproj"), "").unwrap();
+
+        // Act
+        let index = SolutionIndex::scan(dir.path().to_str().unwrap(), "sln", true);
+
+        // Assert
+        assert_eq!(1, index.solutions().len());
+    }
+}